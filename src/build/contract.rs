@@ -7,7 +7,9 @@ use std::io::Write;
 use std::path::Path;
 
 use crate::solc::combined_json::contract::Contract as CombinedJsonContract;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
 use crate::solc::standard_json::output::contract::Contract as StandardJsonOutputContract;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 
 ///
 /// The Solidity contract build.
@@ -22,6 +24,12 @@ pub struct Contract {
     pub build: compiler_llvm_context::Build,
     /// The metadata.
     pub metadata: serde_json::Value,
+    /// The `solc` pipeline the contract has been compiled with, if any.
+    pub solc_pipeline: Option<SolcPipeline>,
+    /// The Yul AST, if requested.
+    pub yul_ast: Option<serde_json::Value>,
+    /// The warnings found during the compilation.
+    pub warnings: Vec<SolcStandardJsonOutputError>,
 }
 
 impl Contract {
@@ -33,12 +41,16 @@ impl Contract {
         identifier: String,
         build: compiler_llvm_context::Build,
         metadata: serde_json::Value,
+        solc_pipeline: Option<SolcPipeline>,
     ) -> Self {
         Self {
             path,
             identifier,
             build,
             metadata,
+            solc_pipeline,
+            yul_ast: None,
+            warnings: Vec::new(),
         }
     }
 
@@ -79,6 +91,27 @@ impl Contract {
             }
         }
 
+        if let Some(yul_ast) = self.yul_ast.as_ref() {
+            let file_name = format!("{}.{}", file_name, crate::r#const::EXTENSION_YUL_AST);
+            let mut file_path = path.to_owned();
+            file_path.push(file_name);
+
+            if file_path.exists() && !overwrite {
+                eprintln!(
+                    "Refusing to overwrite an existing file {file_path:?} (use --overwrite to force)."
+                );
+            } else {
+                File::create(&file_path)
+                    .map_err(|error| {
+                        anyhow::anyhow!("File {:?} creating error: {}", file_path, error)
+                    })?
+                    .write_all(yul_ast.to_string().as_bytes())
+                    .map_err(|error| {
+                        anyhow::anyhow!("File {:?} writing error: {}", file_path, error)
+                    })?;
+            }
+        }
+
         if output_binary {
             let file_name = format!("{}.{}", file_name, compiler_common::EXTENSION_ZKEVM_BINARY);
             let mut file_path = path.to_owned();
@@ -158,6 +191,7 @@ impl Contract {
 
         standard_json_contract.factory_dependencies = Some(self.build.factory_dependencies);
         standard_json_contract.hash = Some(self.build.bytecode_hash);
+        standard_json_contract.solc_pipeline = self.solc_pipeline;
 
         Ok(())
     }
@@ -8,6 +8,7 @@ use std::collections::BTreeMap;
 use std::path::Path;
 
 use crate::solc::combined_json::CombinedJson;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 use crate::solc::standard_json::output::Output as StandardJsonOutput;
 use crate::solc::version::Version as SolcVersion;
 
@@ -45,6 +46,25 @@ impl Build {
         Ok(())
     }
 
+    ///
+    /// Sets the Yul ASTs of the contracts.
+    ///
+    pub fn set_yul_asts(&mut self, mut yul_asts: BTreeMap<String, serde_json::Value>) {
+        for (path, contract) in self.contracts.iter_mut() {
+            contract.yul_ast = yul_asts.remove(path.as_str());
+        }
+    }
+
+    ///
+    /// Returns the warnings found during the compilation of all contracts.
+    ///
+    pub fn warnings(&self) -> Vec<&SolcStandardJsonOutputError> {
+        self.contracts
+            .values()
+            .flat_map(|contract| contract.warnings.iter())
+            .collect()
+    }
+
     ///
     /// Writes all contracts assembly and bytecode to the combined JSON.
     ///
@@ -83,6 +103,18 @@ impl Build {
         solc_version: &SolcVersion,
         zksolc_version: &semver::Version,
     ) -> anyhow::Result<()> {
+        let warnings: Vec<SolcStandardJsonOutputError> = self
+            .contracts
+            .values_mut()
+            .flat_map(|contract| contract.warnings.drain(..))
+            .collect();
+        if !warnings.is_empty() {
+            standard_json
+                .errors
+                .get_or_insert_with(Vec::new)
+                .extend(warnings);
+        }
+
         let contracts = match standard_json.contracts.as_mut() {
             Some(contracts) => contracts,
             None => return Ok(()),
@@ -15,3 +15,6 @@ pub const OFFSET_EMPTY_SLOT: usize = 3 * compiler_common::BYTE_LENGTH_FIELD;
 
 /// The non-reserved memory offset.
 pub const OFFSET_NON_RESERVED: usize = 4 * compiler_common::BYTE_LENGTH_FIELD;
+
+/// The Yul AST output file extension.
+pub const EXTENSION_YUL_AST: &str = "yul-ast.json";
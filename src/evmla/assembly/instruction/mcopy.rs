@@ -0,0 +1,216 @@
+//!
+//! Translates the MCOPY instruction.
+//!
+
+///
+/// Translates the heap memory copying.
+///
+/// The full words are copied at once, whereas the trailing bytes are copied one by one, so the
+/// memory after the destination range is not overwritten. The ranges may overlap, so the copying
+/// direction is chosen at runtime: forward if the destination is not above the source, and
+/// backward otherwise. Either way, no source byte is overwritten before it is read.
+///
+/// The translation is shared by the EVM legacy assembly and Yul pipelines.
+///
+pub fn translate<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let word_size = context.field_const(compiler_common::BYTE_LENGTH_FIELD as u64);
+    let remainder =
+        context
+            .builder()
+            .build_int_unsigned_rem(size, word_size, "mcopy_size_remainder");
+    let words_size = context
+        .builder()
+        .build_int_sub(size, remainder, "mcopy_words_size");
+    let index_pointer = context.build_alloca(context.field_type(), "mcopy_index_pointer");
+
+    let forward_block = context.append_basic_block("mcopy_forward");
+    let forward_words_condition_block = context.append_basic_block("mcopy_forward_words_condition");
+    let forward_words_body_block = context.append_basic_block("mcopy_forward_words_body");
+    let forward_bytes_condition_block = context.append_basic_block("mcopy_forward_bytes_condition");
+    let forward_bytes_body_block = context.append_basic_block("mcopy_forward_bytes_body");
+    let backward_block = context.append_basic_block("mcopy_backward");
+    let backward_bytes_condition_block =
+        context.append_basic_block("mcopy_backward_bytes_condition");
+    let backward_bytes_body_block = context.append_basic_block("mcopy_backward_bytes_body");
+    let backward_words_condition_block =
+        context.append_basic_block("mcopy_backward_words_condition");
+    let backward_words_body_block = context.append_basic_block("mcopy_backward_words_body");
+    let join_block = context.append_basic_block("mcopy_join");
+
+    let is_forward = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULE,
+        destination,
+        source,
+        "mcopy_is_forward",
+    );
+    context.build_conditional_branch(is_forward, forward_block, backward_block);
+
+    context.set_basic_block(forward_block);
+    context.build_store(index_pointer, context.field_const(0));
+    context.build_unconditional_branch(forward_words_condition_block);
+
+    context.set_basic_block(forward_words_condition_block);
+    let index = context
+        .build_load(index_pointer, "mcopy_forward_words_index")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        index,
+        words_size,
+        "mcopy_forward_words_condition",
+    );
+    context.build_conditional_branch(
+        condition,
+        forward_words_body_block,
+        forward_bytes_condition_block,
+    );
+
+    context.set_basic_block(forward_words_body_block);
+    copy_word(context, destination, source, index)?;
+    let index = context
+        .builder()
+        .build_int_add(index, word_size, "mcopy_forward_words_index_next");
+    context.build_store(index_pointer, index);
+    context.build_unconditional_branch(forward_words_condition_block);
+
+    context.set_basic_block(forward_bytes_condition_block);
+    let index = context
+        .build_load(index_pointer, "mcopy_forward_bytes_index")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::ULT,
+        index,
+        size,
+        "mcopy_forward_bytes_condition",
+    );
+    context.build_conditional_branch(condition, forward_bytes_body_block, join_block);
+
+    context.set_basic_block(forward_bytes_body_block);
+    copy_byte(context, destination, source, index)?;
+    let index = context.builder().build_int_add(
+        index,
+        context.field_const(1),
+        "mcopy_forward_bytes_index_next",
+    );
+    context.build_store(index_pointer, index);
+    context.build_unconditional_branch(forward_bytes_condition_block);
+
+    context.set_basic_block(backward_block);
+    context.build_store(index_pointer, size);
+    context.build_unconditional_branch(backward_bytes_condition_block);
+
+    context.set_basic_block(backward_bytes_condition_block);
+    let index = context
+        .build_load(index_pointer, "mcopy_backward_bytes_index")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::UGT,
+        index,
+        words_size,
+        "mcopy_backward_bytes_condition",
+    );
+    context.build_conditional_branch(
+        condition,
+        backward_bytes_body_block,
+        backward_words_condition_block,
+    );
+
+    context.set_basic_block(backward_bytes_body_block);
+    let index = context.builder().build_int_sub(
+        index,
+        context.field_const(1),
+        "mcopy_backward_bytes_index_next",
+    );
+    copy_byte(context, destination, source, index)?;
+    context.build_store(index_pointer, index);
+    context.build_unconditional_branch(backward_bytes_condition_block);
+
+    context.set_basic_block(backward_words_condition_block);
+    let index = context
+        .build_load(index_pointer, "mcopy_backward_words_index")
+        .into_int_value();
+    let condition = context.builder().build_int_compare(
+        inkwell::IntPredicate::UGT,
+        index,
+        context.field_const(0),
+        "mcopy_backward_words_condition",
+    );
+    context.build_conditional_branch(condition, backward_words_body_block, join_block);
+
+    context.set_basic_block(backward_words_body_block);
+    let index =
+        context
+            .builder()
+            .build_int_sub(index, word_size, "mcopy_backward_words_index_next");
+    copy_word(context, destination, source, index)?;
+    context.build_store(index_pointer, index);
+    context.build_unconditional_branch(backward_words_condition_block);
+
+    context.set_basic_block(join_block);
+
+    Ok(())
+}
+
+///
+/// Copies the word at `index` from the source to the destination.
+///
+fn copy_word<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    index: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let source = context
+        .builder()
+        .build_int_add(source, index, "mcopy_word_source");
+    let destination = context
+        .builder()
+        .build_int_add(destination, index, "mcopy_word_destination");
+
+    let value = compiler_llvm_context::memory::load(context, source)?;
+    compiler_llvm_context::memory::store(context, destination, value.into_int_value())?;
+
+    Ok(())
+}
+
+///
+/// Copies the byte at `index` from the source to the destination.
+///
+fn copy_byte<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: inkwell::values::IntValue<'ctx>,
+    index: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let source = context
+        .builder()
+        .build_int_add(source, index, "mcopy_byte_source");
+    let destination = context
+        .builder()
+        .build_int_add(destination, index, "mcopy_byte_destination");
+
+    let value = compiler_llvm_context::memory::load(context, source)?;
+    let value = context.builder().build_right_shift(
+        value.into_int_value(),
+        context.field_const(((compiler_common::BYTE_LENGTH_FIELD - 1) * u8::BITS as usize) as u64),
+        false,
+        "mcopy_byte_value",
+    );
+    compiler_llvm_context::memory::store_byte(context, destination, value)?;
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 
 pub mod codecopy;
 pub mod jump;
+pub mod mcopy;
 pub mod name;
 pub mod stack;
 
@@ -12,6 +13,8 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+
 use self::name::Name;
 
 ///
@@ -23,6 +26,16 @@ pub struct Instruction {
     pub name: Name,
     /// The optional value argument.
     pub value: Option<String>,
+
+    /// The source code identifier, which is `-1` for the compiler-generated code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<isize>,
+    /// The source code location start byte offset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub begin: Option<isize>,
+    /// The source code location end byte offset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<isize>,
 }
 
 impl Instruction {
@@ -35,6 +48,9 @@ impl Instruction {
 
             Name::JUMP => 1,
             Name::JUMPI => 2,
+            Name::InternalCall { input_size, .. } => input_size + 2,
+            Name::InternalReturn { output_size } => output_size + 1,
+            Name::DynamicJump => 1,
 
             Name::ADD => 2,
             Name::SUB => 2,
@@ -70,9 +86,12 @@ impl Instruction {
             Name::MLOAD => 1,
             Name::MSTORE => 2,
             Name::MSTORE8 => 2,
+            Name::MCOPY => 3,
 
             Name::SLOAD => 1,
             Name::SSTORE => 2,
+            Name::TLOAD => 1,
+            Name::TSTORE => 2,
             Name::PUSHIMMUTABLE => 0,
             Name::ASSIGNIMMUTABLE => {
                 if version.minor >= 8 {
@@ -113,6 +132,7 @@ impl Instruction {
             Name::BALANCE => 1,
 
             Name::BLOCKHASH => 1,
+            Name::BLOBHASH => 1,
 
             Name::EXTCODECOPY => 4,
 
@@ -133,6 +153,7 @@ impl Instruction {
             Name::PUSHLIB => 1,
             Name::PUSHDEPLOYADDRESS => 1,
 
+            Name::PUSH0 => 1,
             Name::PUSH1 => 1,
             Name::PUSH2 => 1,
             Name::PUSH3 => 1,
@@ -217,6 +238,7 @@ impl Instruction {
             Name::MLOAD => 1,
 
             Name::SLOAD => 1,
+            Name::TLOAD => 1,
             Name::PUSHIMMUTABLE => 1,
 
             Name::CALLDATALOAD => 1,
@@ -259,6 +281,8 @@ impl Instruction {
             Name::MSIZE => 1,
 
             Name::BASEFEE => 1,
+            Name::BLOBHASH => 1,
+            Name::BLOBBASEFEE => 1,
             Name::PC => 1,
 
             _ => 0,
@@ -277,6 +301,7 @@ impl Instruction {
                 Instruction {
                     name: Name::PUSH_ContractHash | Name::PUSH_ContractHashSize,
                     value: Some(value),
+                    ..
                 } => {
                     *value = mapping
                         .get(value.as_str())
@@ -286,6 +311,7 @@ impl Instruction {
                 Instruction {
                     name: Name::PUSH_Data,
                     value: Some(value),
+                    ..
                 } => {
                     let mut key_extended =
                         "0".repeat(compiler_common::BYTE_LENGTH_FIELD * 2 - value.len());
@@ -302,14 +328,28 @@ impl Instruction {
         Ok(())
     }
 
+    ///
+    /// Returns the source code location, resolving the source code identifier with `source_paths`.
+    ///
+    /// Returns `None` for the compiler-generated code and instructions without location data.
+    ///
+    pub fn source_location(
+        &self,
+        source_paths: &BTreeMap<isize, String>,
+    ) -> Option<SourceLocation> {
+        let file = source_paths.get(self.source.as_ref()?)?.to_owned();
+        Some(SourceLocation {
+            file,
+            start: self.begin?,
+            end: self.end?,
+        })
+    }
+
     ///
     /// Initializes an INVALID instruction to terminate an invalid unreachable block part.
     ///
     pub fn invalid() -> Self {
-        Self {
-            name: Name::INVALID,
-            value: None,
-        }
+        Self::from(Name::INVALID)
     }
 }
 
@@ -29,6 +29,8 @@ pub enum Name {
     #[serde(rename = "PUSH [$]")]
     PUSH_ContractHash,
 
+    /// The eponymous EVM instruction.
+    PUSH0,
     /// The eponymous EVM instruction.
     PUSH1,
     /// The eponymous EVM instruction.
@@ -237,12 +239,18 @@ pub enum Name {
     MSTORE,
     /// The eponymous EVM instruction.
     MSTORE8,
+    /// The eponymous EVM instruction.
+    MCOPY,
 
     /// The eponymous EVM instruction.
     SLOAD,
     /// The eponymous EVM instruction.
     SSTORE,
     /// The eponymous EVM instruction.
+    TLOAD,
+    /// The eponymous EVM instruction.
+    TSTORE,
+    /// The eponymous EVM instruction.
     PUSHIMMUTABLE,
     /// The eponymous EVM instruction.
     ASSIGNIMMUTABLE,
@@ -349,6 +357,10 @@ pub enum Name {
     /// The eponymous EVM instruction.
     BASEFEE,
     /// The eponymous EVM instruction.
+    BLOBHASH,
+    /// The eponymous EVM instruction.
+    BLOBBASEFEE,
+    /// The eponymous EVM instruction.
     MSIZE,
 
     /// The eponymous EVM instruction.
@@ -359,11 +371,42 @@ pub enum Name {
     EXTCODECOPY,
     /// The eponymous EVM instruction.
     SELFDESTRUCT,
+
+    /// The recovered internal function call, which replaces a `JUMP [in]`.
+    ///
+    /// Consumes the return address, the arguments, and the function entry tag.
+    #[serde(skip_deserializing)]
+    InternalCall {
+        /// The number of function arguments.
+        input_size: usize,
+        /// The number of function return values.
+        output_size: usize,
+    },
+    /// The recovered internal function return, which replaces a `JUMP [out]`.
+    ///
+    /// Consumes the return values and the return address.
+    #[serde(skip_deserializing)]
+    InternalReturn {
+        /// The number of function return values.
+        output_size: usize,
+    },
+    /// The dispatched jump to an internal function pointer, which replaces a `JUMP` to a runtime
+    /// value.
+    ///
+    /// Consumes the function pointer.
+    #[serde(skip_deserializing)]
+    DynamicJump,
 }
 
 impl From<Name> for Instruction {
     fn from(name: Name) -> Self {
-        Self { name, value: None }
+        Self {
+            name,
+            value: None,
+            source: None,
+            begin: None,
+            end: None,
+        }
     }
 }
 
@@ -371,6 +414,14 @@ impl std::fmt::Display for Name {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Tag => write!(f, "Tag ",),
+            Self::InternalCall {
+                input_size,
+                output_size,
+            } => write!(f, "{:16}", format!("CALL({input_size}->{output_size})")),
+            Self::InternalReturn { output_size } => {
+                write!(f, "{:16}", format!("RETURN({output_size})"))
+            }
+            Self::DynamicJump => write!(f, "{:16}", "JUMP(dynamic)"),
             _ => write!(
                 f,
                 "{:16}",
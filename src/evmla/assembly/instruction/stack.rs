@@ -4,6 +4,8 @@
 
 use inkwell::values::BasicValue;
 
+use crate::evmla::ethereal_ir::function::Function;
+
 ///
 /// Translates the ordinar value push.
 ///
@@ -28,6 +30,8 @@ where
 ///
 /// Translates the block tag label push.
 ///
+/// The value is the same the Ethereal IR analysis has used, so the dynamic jumps dispatch on it.
+///
 pub fn push_tag<'ctx, D>(
     context: &mut compiler_llvm_context::Context<'ctx, D>,
     value: String,
@@ -35,9 +39,15 @@ pub fn push_tag<'ctx, D>(
 where
     D: compiler_llvm_context::Dependency,
 {
+    let tag: num::BigUint = value
+        .parse()
+        .map_err(|error| anyhow::anyhow!("Invalid tag `{}`: {}", value, error))?;
     let result = context
         .field_type()
-        .const_int_from_string(value.as_str(), inkwell::types::StringRadix::Decimal)
+        .const_int_from_string(
+            Function::push_tag_value(&tag).to_string().as_str(),
+            inkwell::types::StringRadix::Decimal,
+        )
         .expect("Always valid");
     Ok(result.as_basic_value_enum())
 }
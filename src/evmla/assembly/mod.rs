@@ -8,11 +8,14 @@ pub mod instruction;
 use std::collections::BTreeMap;
 use std::collections::HashSet;
 
+use compiler_llvm_context::WriteLLVM;
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::evmla::ethereal_ir::entry_link::EntryLink;
+use crate::evmla::ethereal_ir::graph::Graph;
 use crate::evmla::ethereal_ir::EtherealIR;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 
 use self::data::Data;
 use self::instruction::name::Name as InstructionName;
@@ -39,15 +42,80 @@ pub struct Assembly {
     /// The factory dependency paths.
     #[serde(skip)]
     pub factory_dependencies: HashSet<String>,
+    /// The source code paths by their identifiers, used to resolve the instruction locations.
+    #[serde(skip)]
+    pub source_paths: BTreeMap<isize, String>,
+    /// The cached `keccak256` hash of the assembly contents.
+    #[serde(skip)]
+    pub hash: Option<String>,
+    /// Whether the dead blocks found by the Ethereal IR analysis are errors instead of warnings.
+    #[serde(skip)]
+    pub is_strict: bool,
+    /// The limit of visited blocks in the Ethereal IR analysis, if it differs from the default one.
+    #[serde(skip)]
+    pub block_visits_limit: Option<usize>,
 }
 
 impl Assembly {
     ///
     /// Gets the contract `keccak256` hash.
     ///
+    /// Returns the cached hash if it has been computed with `cache_keccak256`.
+    ///
     pub fn keccak256(&self) -> String {
-        let json = serde_json::to_vec(self).expect("Always valid");
-        compiler_llvm_context::keccak256(json.as_slice())
+        match self.hash.as_ref() {
+            Some(hash) => hash.to_owned(),
+            None => self.compute_keccak256(),
+        }
+    }
+
+    ///
+    /// Computes and caches the hashes of the assembly and all its sub-assemblies, bottom-up.
+    ///
+    /// Each assembly is only serialized without its sub-assemblies, which are represented by their
+    /// hashes, so the cost is linear in the size of the whole nested structure. The hashes must be
+    /// cached before the dependencies are replaced with paths, as they identify the original
+    /// `solc` output.
+    ///
+    pub fn cache_keccak256(&mut self) -> String {
+        if let Some(hash) = self.hash.as_ref() {
+            return hash.to_owned();
+        }
+
+        for data in self.data.iter_mut().flat_map(|data| data.values_mut()) {
+            if let Data::Assembly(assembly) = data {
+                assembly.cache_keccak256();
+            }
+        }
+        let hash = self.compute_keccak256();
+        self.hash = Some(hash.clone());
+        hash
+    }
+
+    ///
+    /// Computes the hash from the assembly code and the hashes of its sub-assemblies.
+    ///
+    fn compute_keccak256(&self) -> String {
+        let mut preimage = serde_json::to_vec(&self.auxdata).expect("Always valid");
+        preimage.extend(serde_json::to_vec(&self.code).expect("Always valid"));
+        for (index, data) in self.data.iter().flatten() {
+            preimage.extend(index.as_bytes());
+            match data {
+                Data::Assembly(assembly) => {
+                    preimage.extend(b"assembly:");
+                    preimage.extend(assembly.keccak256().as_bytes());
+                }
+                Data::Hash(hash) => {
+                    preimage.extend(b"hash:");
+                    preimage.extend(hash.as_bytes());
+                }
+                Data::Path(path) => {
+                    preimage.extend(b"path:");
+                    preimage.extend(path.as_bytes());
+                }
+            }
+        }
+        compiler_llvm_context::keccak256(preimage.as_slice())
     }
 
     ///
@@ -57,6 +125,27 @@ impl Assembly {
         self.full_path = Some(full_path);
     }
 
+    ///
+    /// Sets the source code paths by their identifiers.
+    ///
+    pub fn set_source_paths(&mut self, source_paths: BTreeMap<isize, String>) {
+        self.source_paths = source_paths;
+    }
+
+    ///
+    /// Sets whether the dead blocks are errors instead of warnings.
+    ///
+    pub fn set_strict(&mut self, is_strict: bool) {
+        self.is_strict = is_strict;
+    }
+
+    ///
+    /// Sets the limit of visited blocks in the Ethereal IR analysis.
+    ///
+    pub fn set_block_visits_limit(&mut self, block_visits_limit: Option<usize>) {
+        self.block_visits_limit = block_visits_limit;
+    }
+
     ///
     /// Returns the full contract path if it is set, or `<undefined>` otherwise.
     ///
@@ -172,38 +261,24 @@ impl Assembly {
 
         Ok(index_path_mapping)
     }
-}
-
-impl<D> compiler_llvm_context::WriteLLVM<D> for Assembly
-where
-    D: compiler_llvm_context::Dependency,
-{
-    fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        let mut entry = compiler_llvm_context::EntryFunction::default();
-        entry.declare(context)?;
-
-        let mut runtime =
-            compiler_llvm_context::Runtime::new(compiler_llvm_context::AddressSpace::Heap);
-        runtime.declare(context)?;
-
-        compiler_llvm_context::DeployCodeFunction::new(
-            compiler_llvm_context::DummyLLVMWritable::default(),
-        )
-        .declare(context)?;
-        compiler_llvm_context::RuntimeCodeFunction::new(
-            compiler_llvm_context::DummyLLVMWritable::default(),
-        )
-        .declare(context)?;
-
-        entry.into_llvm(context)?;
-
-        runtime.into_llvm(context)?;
-
-        Ok(())
-    }
 
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    ///
+    /// Translates the assembly into LLVM IR, returning the dead block warnings.
+    ///
+    /// In the strict mode, the dead blocks are an error instead.
+    ///
+    pub fn into_llvm_with_warnings<D>(
+        self,
+        context: &mut compiler_llvm_context::Context<D>,
+    ) -> anyhow::Result<Vec<SolcStandardJsonOutputError>>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
         let full_path = self.full_path().to_owned();
+        let is_strict = self.is_strict;
+        let block_visits_limit = self
+            .block_visits_limit
+            .unwrap_or(EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT);
 
         if let Some(debug_config) = context.debug_config() {
             debug_config.dump_evmla(full_path.as_str(), self.to_string().as_str())?;
@@ -214,6 +289,7 @@ where
             self.code
                 .as_deref()
                 .ok_or_else(|| anyhow::anyhow!("Deploy code instructions not found"))?,
+            &self.source_paths,
         )?;
 
         let data = self
@@ -239,13 +315,38 @@ where
             context.evmla().version.to_owned(),
             compiler_llvm_context::CodeType::Runtime,
             runtime_code_instructions.as_slice(),
+            &self.source_paths,
         )?;
 
         let mut blocks = deploy_code_blocks;
         blocks.extend(runtime_code_blocks);
-        let mut ethereal_ir = EtherealIR::new(context.evmla().version.to_owned(), blocks)?;
+        let mut ethereal_ir = EtherealIR::new(
+            context.evmla().version.to_owned(),
+            blocks,
+            block_visits_limit,
+        )?;
         if let Some(debug_config) = context.debug_config() {
             debug_config.dump_ethir(full_path.as_str(), ethereal_ir.to_string().as_str())?;
+            ethereal_ir.dump_json(debug_config, full_path.as_str())?;
+            Graph::new(&ethereal_ir).dump(debug_config, full_path.as_str())?;
+        }
+        let mut warnings = Vec::new();
+        let dead_blocks = ethereal_ir.dead_blocks();
+        if !dead_blocks.is_empty() {
+            let message = format!(
+                "Found {} dead block(s) in the contract `{}`:\n{}",
+                dead_blocks.len(),
+                full_path,
+                dead_blocks
+                    .iter()
+                    .map(|dead_block| dead_block.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+            if is_strict {
+                anyhow::bail!(message);
+            }
+            warnings.push(SolcStandardJsonOutputError::new_warning(message));
         }
         ethereal_ir.declare(context)?;
         ethereal_ir.into_llvm(context)?;
@@ -259,8 +360,41 @@ where
         ))
         .into_llvm(context)?;
 
+        Ok(warnings)
+    }
+}
+
+impl<D> compiler_llvm_context::WriteLLVM<D> for Assembly
+where
+    D: compiler_llvm_context::Dependency,
+{
+    fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        let mut entry = compiler_llvm_context::EntryFunction::default();
+        entry.declare(context)?;
+
+        let mut runtime =
+            compiler_llvm_context::Runtime::new(compiler_llvm_context::AddressSpace::Heap);
+        runtime.declare(context)?;
+
+        compiler_llvm_context::DeployCodeFunction::new(
+            compiler_llvm_context::DummyLLVMWritable::default(),
+        )
+        .declare(context)?;
+        compiler_llvm_context::RuntimeCodeFunction::new(
+            compiler_llvm_context::DummyLLVMWritable::default(),
+        )
+        .declare(context)?;
+
+        entry.into_llvm(context)?;
+
+        runtime.into_llvm(context)?;
+
         Ok(())
     }
+
+    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        self.into_llvm_with_warnings(context).map(|_warnings| ())
+    }
 }
 
 impl std::fmt::Display for Assembly {
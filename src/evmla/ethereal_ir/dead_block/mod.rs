@@ -0,0 +1,66 @@
+//!
+//! The Ethereal IR dead block.
+//!
+
+pub mod reason;
+
+use serde::Serialize;
+
+use crate::evmla::ethereal_ir::function::Function;
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+
+use self::reason::Reason;
+
+///
+/// The Ethereal IR dead block.
+///
+/// The `solc` output is not expected to contain dead code, so each such block may indicate
+/// an imprecise stack analysis, and must not be dropped silently.
+///
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadBlock {
+    /// The block key.
+    #[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::block_key")]
+    pub key: compiler_llvm_context::FunctionBlockKey,
+    /// The source code location of the block, if known.
+    pub location: Option<SourceLocation>,
+    /// The reason why the block or its part is dead.
+    pub reason: Reason,
+}
+
+impl DeadBlock {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        key: compiler_llvm_context::FunctionBlockKey,
+        location: Option<SourceLocation>,
+        reason: Reason,
+    ) -> Self {
+        Self {
+            key,
+            location,
+            reason,
+        }
+    }
+
+    ///
+    /// Whether the block is truncated at a dynamic jump without any known destinations.
+    ///
+    pub fn is_unknown_dynamic_jump(&self) -> bool {
+        matches!(
+            self.reason,
+            Reason::Truncated { ref error, .. } if error.as_str() == Function::DYNAMIC_JUMP_UNKNOWN_ERROR
+        )
+    }
+}
+
+impl std::fmt::Display for DeadBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "block `{}` {}", self.key, self.reason)?;
+        if let Some(location) = self.location.as_ref() {
+            write!(f, " ({location})")?;
+        }
+        Ok(())
+    }
+}
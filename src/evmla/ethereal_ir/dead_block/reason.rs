@@ -0,0 +1,43 @@
+//!
+//! The Ethereal IR dead block reason.
+//!
+
+use serde::Serialize;
+
+///
+/// The Ethereal IR dead block reason.
+///
+#[derive(Debug, Clone, Serialize)]
+pub enum Reason {
+    /// The block copy has reached an invalid stack state, so the rest of it is replaced with
+    /// the `INVALID` instruction.
+    Truncated {
+        /// The name of the function the block copy belongs to.
+        function: String,
+        /// The index of the instruction with the invalid stack state.
+        index: usize,
+        /// The instruction with the invalid stack state.
+        instruction: String,
+        /// The stack analysis error.
+        error: String,
+    },
+    /// The block is never reached from the deploy or runtime code entry.
+    Unreachable,
+}
+
+impl std::fmt::Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Truncated {
+                function,
+                index,
+                instruction,
+                error,
+            } => write!(
+                f,
+                "in function `{function}` is truncated at instruction #{index} `{instruction}`: {error}"
+            ),
+            Self::Unreachable => write!(f, "is unreachable"),
+        }
+    }
+}
@@ -5,11 +5,17 @@
 pub mod stack;
 
 use inkwell::values::BasicValue;
+use serde::ser::SerializeStruct;
+use serde::Serialize;
 
 use crate::evmla::assembly::instruction::codecopy;
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
+use crate::evmla::ethereal_ir::function::r#type::Type as FunctionType;
+use crate::evmla::ethereal_ir::function::Function;
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
 
+use self::stack::element::Element as StackElement;
 use self::stack::Stack;
 
 ///
@@ -21,19 +27,29 @@ pub struct Element {
     pub solc_version: semver::Version,
     /// The instruction.
     pub instruction: Instruction,
+    /// The instruction source code location.
+    pub location: Option<SourceLocation>,
     /// The stack data.
     pub stack: Stack,
+    /// The dynamic jump destination tags, that is, the internal function pointers.
+    pub destinations: Vec<num::BigUint>,
 }
 
 impl Element {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(solc_version: semver::Version, instruction: Instruction) -> Self {
+    pub fn new(
+        solc_version: semver::Version,
+        instruction: Instruction,
+        location: Option<SourceLocation>,
+    ) -> Self {
         Self {
             solc_version,
             instruction,
+            location,
             stack: Stack::new(),
+            destinations: vec![],
         }
     }
 
@@ -83,6 +99,29 @@ impl Element {
         }
         arguments
     }
+
+    ///
+    /// Splits the stack data into the untouched part, the instruction input, and its output.
+    ///
+    fn split_stack(&self) -> (Stack, Stack, Stack) {
+        let input_size = self.instruction.input_size(&self.solc_version);
+        let output_size = self.instruction.output_size();
+
+        let mut stack = self.stack.to_owned();
+        let output = Stack::new_with_elements(
+            stack
+                .elements
+                .drain(stack.elements.len() - output_size..)
+                .collect(),
+        );
+        let input = Stack::new_with_elements(
+            stack
+                .elements
+                .drain(stack.elements.len() - input_size..)
+                .collect(),
+        );
+        (stack, input, output)
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Element
@@ -136,6 +175,7 @@ where
                     .ok_or_else(|| anyhow::anyhow!("Instruction value missing"))?,
             )
             .map(Some),
+            InstructionName::PUSH0 => Ok(Some(context.field_const(0).as_basic_value_enum())),
             InstructionName::PUSH_Tag => crate::evmla::assembly::instruction::stack::push_tag(
                 context,
                 self.instruction
@@ -438,6 +478,169 @@ where
                 .map(|_| None)
             }
             InstructionName::JUMPDEST => Ok(None),
+            InstructionName::InternalCall {
+                input_size,
+                output_size,
+            } => {
+                let code_type = context
+                    .code_type()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?;
+                let entry = self.stack.pop_tag()?;
+                let entry_key = Function::destination_block_key(code_type, entry);
+                let name = FunctionType::internal_name(&entry_key, input_size);
+                let function = context
+                    .get_function(name.as_str())
+                    .ok_or_else(|| anyhow::anyhow!("Undeclared function `{}`", name))?;
+
+                let stack_height = self.stack.elements.len();
+                let mut arguments = Vec::with_capacity(input_size);
+                for index in stack_height - input_size..stack_height {
+                    let pointer = context.evmla().stack[index].to_llvm().into_pointer_value();
+                    let value = context.build_load(
+                        compiler_llvm_context::Pointer::new_stack_field(context, pointer),
+                        format!("internal_call_argument_{index}").as_str(),
+                    );
+                    arguments.push(value);
+                }
+                let return_value = context.build_invoke(
+                    function.borrow().declaration(),
+                    arguments.as_slice(),
+                    format!("{name}_call").as_str(),
+                );
+
+                self.stack.elements.truncate(stack_height - input_size);
+                let return_address = self.stack.pop_tag()?;
+                let frame_start = self.stack.elements.len();
+                let results = match (output_size, return_value) {
+                    (0, _) | (_, None) => vec![],
+                    (1, Some(value)) => vec![value],
+                    (_, Some(value)) => {
+                        let tuple_pointer =
+                            context.build_alloca(value.get_type(), "internal_call_results");
+                        context.build_store(tuple_pointer, value);
+                        let mut results = Vec::with_capacity(output_size);
+                        for index in 0..output_size {
+                            let pointer = context.build_gep(
+                                tuple_pointer,
+                                &[
+                                    context.field_const(0),
+                                    context
+                                        .integer_type(compiler_common::BIT_LENGTH_X32)
+                                        .const_int(index as u64, false),
+                                ],
+                                context.field_type(),
+                                format!("internal_call_result_{index}_gep_pointer").as_str(),
+                            );
+                            let value = context.build_load(
+                                pointer,
+                                format!("internal_call_result_{index}").as_str(),
+                            );
+                            results.push(value);
+                        }
+                        results
+                    }
+                };
+                for (index, value) in results.into_iter().enumerate() {
+                    let pointer = context.evmla().stack[frame_start + index]
+                        .to_llvm()
+                        .into_pointer_value();
+                    context.build_store(
+                        compiler_llvm_context::Pointer::new_stack_field(context, pointer),
+                        value,
+                    );
+                    context.evmla_mut().stack[frame_start + index].original = None;
+                }
+                for _ in 0..output_size {
+                    self.stack.push(StackElement::Value);
+                }
+
+                crate::evmla::assembly::instruction::jump::unconditional(
+                    context,
+                    return_address,
+                    self.stack.hash(),
+                )
+                .map(|_| None)
+            }
+            InstructionName::DynamicJump => {
+                let code_type = context
+                    .code_type()
+                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?;
+                let pointer = context.evmla().stack[self.stack.elements.len() - 1]
+                    .to_llvm()
+                    .into_pointer_value();
+                let destination = context.build_load(
+                    compiler_llvm_context::Pointer::new_stack_field(context, pointer),
+                    "dynamic_jump_destination",
+                );
+                self.stack.pop()?;
+                let stack_hash = self.stack.hash();
+
+                let mut branches = Vec::with_capacity(self.destinations.len());
+                for tag in self.destinations.into_iter() {
+                    let constant = context
+                        .field_type()
+                        .const_int_from_string(
+                            tag.to_string().as_str(),
+                            inkwell::types::StringRadix::Decimal,
+                        )
+                        .expect("Always valid");
+                    let block = context.current_function().borrow().evmla().find_block(
+                        &compiler_llvm_context::FunctionBlockKey::new(code_type, tag),
+                        &stack_hash,
+                    )?;
+                    branches.push((constant, block.inner()));
+                }
+
+                let current_block = context.basic_block();
+                let invalid_block = context.append_basic_block("dynamic_jump_invalid_block");
+                context.set_basic_block(invalid_block);
+                compiler_llvm_context::r#return::invalid(context)?;
+
+                context.set_basic_block(current_block);
+                context.builder().build_switch(
+                    destination.into_int_value(),
+                    invalid_block,
+                    branches.as_slice(),
+                );
+                Ok(None)
+            }
+            InstructionName::InternalReturn { output_size } => {
+                let mut results = Vec::with_capacity(output_size);
+                for index in 0..output_size {
+                    let pointer = context.evmla().stack[index].to_llvm().into_pointer_value();
+                    let value = context.build_load(
+                        compiler_llvm_context::Pointer::new_stack_field(context, pointer),
+                        format!("internal_return_value_{index}").as_str(),
+                    );
+                    results.push(value);
+                }
+                let r#return = context.current_function().borrow().r#return();
+                match r#return {
+                    compiler_llvm_context::FunctionReturn::None => {}
+                    compiler_llvm_context::FunctionReturn::Primitive { pointer } => {
+                        context.build_store(pointer, results.remove(0));
+                    }
+                    compiler_llvm_context::FunctionReturn::Compound { pointer, .. } => {
+                        for (index, value) in results.into_iter().enumerate() {
+                            let pointer = context.build_gep(
+                                pointer,
+                                &[
+                                    context.field_const(0),
+                                    context
+                                        .integer_type(compiler_common::BIT_LENGTH_X32)
+                                        .const_int(index as u64, false),
+                                ],
+                                context.field_type(),
+                                format!("internal_return_{index}_gep_pointer").as_str(),
+                            );
+                            context.build_store(pointer, value);
+                        }
+                    }
+                }
+                context
+                    .build_unconditional_branch(context.current_function().borrow().return_block());
+                Ok(None)
+            }
 
             InstructionName::ADD => {
                 let arguments = self.pop_arguments_llvm(context);
@@ -730,6 +933,16 @@ where
                 )
                 .map(|_| None)
             }
+            InstructionName::MCOPY => {
+                let arguments = self.pop_arguments_llvm(context);
+                crate::evmla::assembly::instruction::mcopy::translate(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
+                .map(|_| None)
+            }
 
             InstructionName::SLOAD => {
                 let arguments = self.pop_arguments_llvm(context);
@@ -1216,6 +1429,22 @@ where
                 let _arguments = self.pop_arguments_llvm(context);
                 anyhow::bail!("The `SELFDESTRUCT` instruction is not supported");
             }
+            // See the Yul `tload` builtin on why the transient storage cannot be emulated.
+            InstructionName::TLOAD => {
+                let _arguments = self.pop_arguments_llvm(context);
+                anyhow::bail!("The `TLOAD` instruction is not supported");
+            }
+            InstructionName::TSTORE => {
+                let _arguments = self.pop_arguments_llvm(context);
+                anyhow::bail!("The `TSTORE` instruction is not supported");
+            }
+            InstructionName::BLOBHASH => {
+                let _arguments = self.pop_arguments_llvm(context);
+                anyhow::bail!("The `BLOBHASH` instruction is not supported");
+            }
+            InstructionName::BLOBBASEFEE => {
+                anyhow::bail!("The `BLOBBASEFEE` instruction is not supported");
+            }
         }?;
 
         if let Some(value) = value {
@@ -1234,30 +1463,44 @@ where
     }
 }
 
+impl Serialize for Element {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let (stack, input, output) = self.split_stack();
+        let mut stack_before = stack.clone();
+        stack_before.elements.extend(input.elements);
+        let mut stack_after = stack;
+        stack_after.elements.extend(output.elements);
+
+        let mut element = serializer.serialize_struct("Element", 6)?;
+        element.serialize_field("instruction", &self.instruction.name)?;
+        element.serialize_field("value", &self.instruction.value)?;
+        element.serialize_field("location", &self.location)?;
+        element.serialize_field("stack_before", &stack_before)?;
+        element.serialize_field("stack_after", &stack_after)?;
+        element.serialize_field(
+            "destinations",
+            &self
+                .destinations
+                .iter()
+                .map(|destination| destination.to_string())
+                .collect::<Vec<String>>(),
+        )?;
+        element.end()
+    }
+}
+
 impl std::fmt::Display for Element {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let input_size = self.instruction.input_size(&self.solc_version);
-        let output_size = self.instruction.output_size();
-
-        let mut stack = self.stack.to_owned();
-        let output = Stack::new_with_elements(
-            stack
-                .elements
-                .drain(stack.elements.len() - output_size..)
-                .collect(),
-        );
-        let input = Stack::new_with_elements(
-            stack
-                .elements
-                .drain(stack.elements.len() - input_size..)
-                .collect(),
-        );
+        let (stack, input, output) = self.split_stack();
 
         write!(f, "{:88}{}", self.instruction.to_string(), stack)?;
-        if input_size != 0 {
+        if !input.elements.is_empty() {
             write!(f, " - {input}")?;
         }
-        if output_size != 0 {
+        if !output.elements.is_empty() {
             write!(f, " + {output}")?;
         }
         writeln!(f)?;
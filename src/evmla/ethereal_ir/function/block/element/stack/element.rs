@@ -2,19 +2,25 @@
 //! The Ethereal IR block element stack element.
 //!
 
+use serde::Serialize;
+
 ///
 /// The Ethereal IR block element stack element.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Element {
     /// The unknown runtime value.
     Value,
     /// The known compile-time value.
-    Constant(num::BigUint),
+    Constant(
+        #[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::big_uint")] num::BigUint,
+    ),
     /// The known compile-time destination tag.
-    Tag(num::BigUint),
+    Tag(#[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::big_uint")] num::BigUint),
     /// The known compile-time path.
     Path(String),
+    /// The return address of the recovered internal function, which is unknown at compile time.
+    ReturnAddress,
 }
 
 impl std::fmt::Display for Element {
@@ -24,6 +30,7 @@ impl std::fmt::Display for Element {
             Self::Constant(value) => write!(f, "{value}"),
             Self::Tag(tag) => write!(f, "TAG_{tag}"),
             Self::Path(path) => write!(f, "{path}"),
+            Self::ReturnAddress => write!(f, "RETURN_ADDRESS"),
         }
     }
 }
@@ -4,12 +4,15 @@
 
 pub mod element;
 
+use serde::Serialize;
+
 use self::element::Element;
 
 ///
 /// The Ethereal IR block element stack.
 ///
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(transparent)]
 pub struct Stack {
     /// The stack elements.
     pub elements: Vec<Element>,
@@ -19,6 +22,12 @@ impl Stack {
     /// The default stack size.
     pub const DEFAULT_STACK_SIZE: usize = 16;
 
+    /// The maximum stack size, limiting the inlined recursion depth.
+    pub const MAX_SIZE: usize = 1024;
+
+    /// The return address hash marker, which is longer than any 64-bit tag encoding.
+    pub const RETURN_ADDRESS_HASH_MARKER: &'static [u8] = b"return_address";
+
     ///
     /// A shortcut constructor.
     ///
@@ -45,6 +54,7 @@ impl Stack {
         for element in self.elements.iter() {
             match element {
                 Element::Tag(tag) => hash_context.consume(tag.to_bytes_be()),
+                Element::ReturnAddress => hash_context.consume(Self::RETURN_ADDRESS_HASH_MARKER),
                 _ => hash_context.consume([0]),
             }
         }
@@ -4,9 +4,11 @@
 
 pub mod element;
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use num::Zero;
+use serde::Serialize;
 
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
@@ -17,22 +19,29 @@ use self::element::Element;
 ///
 /// The Ethereal IR block.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Block {
     /// The Solidity compiler version.
+    #[serde(skip)]
     pub solc_version: semver::Version,
     /// The block key.
+    #[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::block_key")]
     pub key: compiler_llvm_context::FunctionBlockKey,
     /// The block elements relevant to the stack consistency.
     pub elements: Vec<Element>,
     /// The block predecessors.
+    #[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::block_keys")]
     pub predecessors: HashSet<compiler_llvm_context::FunctionBlockKey>,
     /// The initial stack state.
     pub initial_stack: ElementStack,
     /// The stack.
     pub stack: ElementStack,
     /// The extra block hashes for alternative routes.
+    #[serde(skip)]
     pub extra_hashes: Vec<md5::Digest>,
+    /// The key of the block following this one in the instruction sequence, if any.
+    #[serde(skip)]
+    pub next_key: Option<compiler_llvm_context::FunctionBlockKey>,
 }
 
 impl Block {
@@ -44,10 +53,13 @@ impl Block {
     ///
     /// Assembles a block from the sequence of instructions.
     ///
+    /// The `source_paths` are used to resolve the instruction source code locations.
+    ///
     pub fn try_from_instructions(
         solc_version: semver::Version,
         code_type: compiler_llvm_context::CodeType,
         slice: &[Instruction],
+        source_paths: &BTreeMap<isize, String>,
     ) -> anyhow::Result<(Self, usize)> {
         let mut cursor = 0;
 
@@ -73,10 +85,15 @@ impl Block {
             initial_stack: ElementStack::new(),
             stack: ElementStack::new(),
             extra_hashes: vec![],
+            next_key: None,
         };
 
         while cursor < slice.len() {
-            let element: Element = Element::new(solc_version.clone(), slice[cursor].to_owned());
+            let element: Element = Element::new(
+                solc_version.clone(),
+                slice[cursor].to_owned(),
+                slice[cursor].source_location(source_paths),
+            );
             block.elements.push(element);
 
             match slice[cursor].name {
@@ -116,7 +133,11 @@ where
         context.set_code_type(self.key.code_type);
 
         for element in self.elements.into_iter() {
-            element.into_llvm(context)?;
+            let location = element.location.clone();
+            element.into_llvm(context).map_err(|error| match location {
+                Some(location) => anyhow::anyhow!("{}: {}", location, error),
+                None => error,
+            })?;
         }
 
         Ok(())
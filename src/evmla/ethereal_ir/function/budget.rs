@@ -0,0 +1,108 @@
+//!
+//! The Ethereal IR block duplication budget.
+//!
+
+use std::collections::BTreeMap;
+
+use crate::evmla::ethereal_ir::function::block::Block;
+
+///
+/// The Ethereal IR block duplication budget.
+///
+/// Each distinct initial stack state reaching a block produces a copy of the block, so some
+/// contracts may explode into an enormous number of blocks. The budget is shared by all functions
+/// of a contract, and stops the analysis once the limit of visited blocks is exceeded.
+///
+/// The visits of the internal function recovery passes which are discarded are refunded, so only
+/// the blocks of the resulting functions are charged.
+///
+#[derive(Debug, Clone)]
+pub struct Budget {
+    /// The maximum number of visited blocks.
+    pub limit: usize,
+    /// The number of visited blocks.
+    pub visited: usize,
+    /// The number of visits of each block.
+    pub visits: BTreeMap<compiler_llvm_context::FunctionBlockKey, usize>,
+    /// Whether a visit has been refused due to the exhausted budget.
+    pub is_exceeded: bool,
+}
+
+impl Budget {
+    /// The number of the most visited blocks named in the error message.
+    pub const HOT_BLOCKS_TO_REPORT: usize = 8;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit,
+            visited: 0,
+            visits: BTreeMap::new(),
+            is_exceeded: false,
+        }
+    }
+
+    ///
+    /// Records a block visit.
+    ///
+    /// Returns `false` if the budget is exhausted and the block must not be visited.
+    ///
+    pub fn consume(&mut self, block_key: &compiler_llvm_context::FunctionBlockKey) -> bool {
+        if self.visited >= self.limit {
+            self.is_exceeded = true;
+            return false;
+        }
+
+        self.visited += 1;
+        *self.visits.entry(block_key.to_owned()).or_default() += 1;
+        true
+    }
+
+    ///
+    /// Refunds the visits of the blocks of a discarded internal function analysis pass.
+    ///
+    /// The blocks of the nested functions recovered during the pass are kept, so they remain
+    /// charged.
+    ///
+    pub fn refund(
+        &mut self,
+        blocks: &BTreeMap<compiler_llvm_context::FunctionBlockKey, Vec<Block>>,
+    ) {
+        for (key, copies) in blocks.iter() {
+            self.visited -= copies.len();
+            if let Some(visits) = self.visits.get_mut(key) {
+                *visits -= copies.len();
+                if *visits == 0 {
+                    self.visits.remove(key);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns an error naming the most visited blocks, if the budget is exceeded.
+    ///
+    pub fn check(&self) -> anyhow::Result<()> {
+        if !self.is_exceeded {
+            return Ok(());
+        }
+
+        let mut visits: Vec<(&compiler_llvm_context::FunctionBlockKey, &usize)> =
+            self.visits.iter().collect();
+        visits.sort_by(|(_, a), (_, b)| b.cmp(a));
+        let hot_blocks = visits
+            .into_iter()
+            .take(Self::HOT_BLOCKS_TO_REPORT)
+            .map(|(key, count)| format!("{key} ({count} copies)"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        anyhow::bail!(
+            "The Ethereal IR block duplication limit of {} is exceeded, as too many stack states reach the same blocks. The most duplicated blocks: {}",
+            self.limit,
+            hot_blocks,
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The Ethereal IR internal function pointers.
+//!
+
+use std::collections::BTreeSet;
+
+///
+/// The Ethereal IR internal function pointers.
+///
+/// The internal function pointers are tags used as values, e.g. stored to memory or storage, so
+/// they can only be called with a jump to a runtime value. Such dynamic jumps are lowered to a
+/// dispatch switch over all such tags, which are only known after the whole contract has been
+/// walked through. Therefore, the analysis is repeated until the set of tags stops growing.
+///
+#[derive(Debug, Default, Clone)]
+pub struct FunctionPointers {
+    /// The tags known to be used as values at the previous pass, which are dynamic jump candidates.
+    pub candidates: BTreeSet<compiler_llvm_context::FunctionBlockKey>,
+    /// The tags found to be used as values at the current pass.
+    pub escaped: BTreeSet<compiler_llvm_context::FunctionBlockKey>,
+    /// Whether a dynamic jump has been found at the current pass.
+    pub is_dynamic_jump_found: bool,
+}
+
+impl FunctionPointers {
+    ///
+    /// Records the tag used as a value.
+    ///
+    pub fn insert_escaped(&mut self, key: compiler_llvm_context::FunctionBlockKey) {
+        self.escaped.insert(key);
+    }
+
+    ///
+    /// Returns the dynamic jump candidate tags in the specified code part.
+    ///
+    pub fn candidates(&self, code_type: compiler_llvm_context::CodeType) -> Vec<num::BigUint> {
+        self.candidates
+            .iter()
+            .filter(|key| key.code_type == code_type)
+            .map(|key| key.tag.to_owned())
+            .collect()
+    }
+
+    ///
+    /// Prepares the next pass, if there are dynamic jumps and new candidates.
+    ///
+    /// Returns `false` if the current pass is final.
+    ///
+    pub fn next_pass(&mut self) -> bool {
+        let is_final = !self.is_dynamic_jump_found || self.escaped.is_subset(&self.candidates);
+        if is_final {
+            return false;
+        }
+
+        self.candidates.append(&mut self.escaped);
+        self.is_dynamic_jump_found = false;
+        true
+    }
+}
@@ -3,10 +3,15 @@
 //!
 
 pub mod block;
+pub mod budget;
+pub mod function_pointers;
 pub mod queue_element;
+pub mod recovery;
+pub mod r#type;
 pub mod visited_element;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::ops::BitAnd;
@@ -21,9 +26,12 @@ use num::One;
 use num::ToPrimitive;
 use num::Zero;
 use num::{BigUint, CheckedAdd};
+use serde::Serialize;
 
 use crate::evmla::assembly::instruction::name::Name as InstructionName;
 use crate::evmla::assembly::instruction::Instruction;
+use crate::evmla::ethereal_ir::dead_block::reason::Reason as DeadBlockReason;
+use crate::evmla::ethereal_ir::dead_block::DeadBlock;
 use crate::evmla::ethereal_ir::function::block::element::stack::element::Element;
 use crate::evmla::ethereal_ir::function::block::element::stack::Stack;
 use crate::evmla::ethereal_ir::EtherealIR;
@@ -31,39 +39,69 @@ use crate::evmla::ethereal_ir::EtherealIR;
 use self::block::element::stack::element::Element as StackElement;
 use self::block::element::Element as BlockElement;
 use self::block::Block;
+use self::budget::Budget;
+use self::function_pointers::FunctionPointers;
 use self::queue_element::QueueElement;
+use self::r#type::Type;
+use self::recovery::Recovery;
 use self::visited_element::VisitedElement;
 
+/// The internal functions by their entry block keys and numbers of arguments.
+pub type InternalFunctions = BTreeMap<(compiler_llvm_context::FunctionBlockKey, usize), Recovery>;
+
 ///
 /// The Ethereal IR function.
 ///
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Function {
     /// The Solidity compiler version.
+    #[serde(skip)]
     pub solc_version: semver::Version,
+    /// The function type.
+    pub r#type: Type,
     /// The separately labelled blocks.
+    #[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::blocks")]
     pub blocks: BTreeMap<compiler_llvm_context::FunctionBlockKey, Vec<Block>>,
+    /// The blocks truncated due to an invalid stack state.
+    pub truncated_blocks: Vec<DeadBlock>,
     /// The function stack size.
     pub stack_size: usize,
 }
 
 impl Function {
+    /// The `JUMP` instruction value, which marks a jump into an internal function.
+    pub const JUMP_IN_VALUE: &'static str = "[in]";
+
+    /// The stack analysis error of a dynamic jump without any function pointer candidates.
+    pub const DYNAMIC_JUMP_UNKNOWN_ERROR: &'static str =
+        "The dynamic jump destinations are unknown";
+
     ///
-    /// A shortcut constructor.
+    /// A shortcut constructor of the initial function.
+    ///
+    /// The internal functions recovered on the way are added to `functions`.
     ///
     pub fn new(
         solc_version: semver::Version,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
         visited: &mut HashSet<VisitedElement>,
+        functions: &mut InternalFunctions,
+        budget: &mut Budget,
+        pointers: &mut FunctionPointers,
     ) -> anyhow::Result<Self> {
         let mut function = Self {
             solc_version,
+            r#type: Type::new_initial(),
             blocks: BTreeMap::new(),
+            truncated_blocks: vec![],
             stack_size: 0,
         };
         function.consume_block(
             blocks,
             visited,
+            functions,
+            budget,
+            pointers,
             QueueElement::new(
                 compiler_llvm_context::FunctionBlockKey::new(
                     compiler_llvm_context::CodeType::Deploy,
@@ -76,6 +114,9 @@ impl Function {
         function.consume_block(
             blocks,
             visited,
+            functions,
+            budget,
+            pointers,
             QueueElement::new(
                 compiler_llvm_context::FunctionBlockKey::new(
                     compiler_llvm_context::CodeType::Runtime,
@@ -88,6 +129,208 @@ impl Function {
         Ok(function.finalize())
     }
 
+    ///
+    /// Tries to recover the internal function with the specified entry block and number of
+    /// arguments.
+    ///
+    /// The function starts with the return address and arguments on its stack, and returns by
+    /// jumping to the return address with only the return values below it.
+    ///
+    /// Returns `None` if the function is ambiguous, that is, it accesses the stack below its
+    /// frame, uses the return address as a value, or has no consistent number of return values.
+    ///
+    /// The recursive calls are truncated at the first pass, as the number of return values is
+    /// not known yet. If the other paths agree on it, the second pass assumes it for the
+    /// recursive calls, and the function is accepted if the assumption holds.
+    ///
+    /// The block visits of the discarded passes are refunded to the budget.
+    ///
+    pub fn try_new_internal(
+        solc_version: semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        functions: &mut InternalFunctions,
+        budget: &mut Budget,
+        pointers: &mut FunctionPointers,
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+    ) -> anyhow::Result<Option<Self>> {
+        let function = Self::analyze_internal(
+            solc_version.clone(),
+            blocks,
+            functions,
+            budget,
+            pointers,
+            entry_key.clone(),
+            input_size,
+        )?;
+        let output_size = match function.internal_output_size() {
+            Some(output_size) if function.truncated_blocks.is_empty() => {
+                return Ok(Some(function.into_internal(
+                    entry_key,
+                    input_size,
+                    output_size,
+                )));
+            }
+            Some(output_size) => output_size,
+            None => {
+                budget.refund(&function.blocks);
+                return Ok(None);
+            }
+        };
+        budget.refund(&function.blocks);
+
+        let functions_snapshot = functions.clone();
+        let budget_snapshot = budget.clone();
+        functions.insert(
+            (entry_key.clone(), input_size),
+            Recovery::InProgress {
+                output_size: Some(output_size),
+            },
+        );
+        let function = Self::analyze_internal(
+            solc_version,
+            blocks,
+            functions,
+            budget,
+            pointers,
+            entry_key.clone(),
+            input_size,
+        )?;
+        if function.truncated_blocks.is_empty()
+            && function.internal_output_size() == Some(output_size)
+        {
+            return Ok(Some(function.into_internal(
+                entry_key,
+                input_size,
+                output_size,
+            )));
+        }
+        *functions = functions_snapshot;
+        let is_budget_exceeded = budget.is_exceeded;
+        *budget = budget_snapshot;
+        budget.is_exceeded |= is_budget_exceeded;
+
+        Ok(None)
+    }
+
+    ///
+    /// Walks the internal function blocks, starting with the return address and arguments.
+    ///
+    fn analyze_internal(
+        solc_version: semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        functions: &mut InternalFunctions,
+        budget: &mut Budget,
+        pointers: &mut FunctionPointers,
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+    ) -> anyhow::Result<Self> {
+        let mut function = Self {
+            solc_version,
+            r#type: Type::new_internal(entry_key.clone(), input_size, 0),
+            blocks: BTreeMap::new(),
+            truncated_blocks: vec![],
+            stack_size: 0,
+        };
+        let mut visited = HashSet::new();
+        function.consume_block(
+            blocks,
+            &mut visited,
+            functions,
+            budget,
+            pointers,
+            QueueElement::new(
+                entry_key.clone(),
+                None,
+                Self::internal_initial_stack(input_size),
+            ),
+        )?;
+        Ok(function)
+    }
+
+    ///
+    /// Returns the number of the internal function return values, if all returns agree on it.
+    ///
+    fn internal_output_size(&self) -> Option<usize> {
+        let output_sizes: BTreeSet<usize> = self
+            .blocks
+            .values()
+            .flatten()
+            .filter_map(|block| match block.elements.last()?.instruction.name {
+                InstructionName::InternalReturn { output_size } => Some(output_size),
+                _ => None,
+            })
+            .collect();
+        match output_sizes.into_iter().collect::<Vec<usize>>().as_slice() {
+            [output_size] => Some(*output_size),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Finalizes the internal function with its signature.
+    ///
+    fn into_internal(
+        mut self,
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+        output_size: usize,
+    ) -> Self {
+        self.r#type = Type::new_internal(entry_key, input_size, output_size);
+        self.finalize()
+    }
+
+    ///
+    /// Returns the initial stack state of the internal function.
+    ///
+    pub fn internal_initial_stack(input_size: usize) -> Stack {
+        let mut stack = Stack::new();
+        stack.push(Element::ReturnAddress);
+        for _ in 0..input_size {
+            stack.push(Element::Value);
+        }
+        stack
+    }
+
+    ///
+    /// Returns the value pushed by `PUSH [tag]`, both at the analysis and in the generated code.
+    ///
+    /// The sub-assembly index stored above the lower 64 bits is dropped. The deploy code only
+    /// refers to its runtime code sub-assembly, e.g. for the constructor-assigned function
+    /// pointers, which `solc` combines with the deploy code tag as `deploy_tag << 32 | tag`.
+    /// Thus, the constructor calls shift the deploy code tag out, and the runtime code calls mask
+    /// the runtime code tag out, and both must match the dispatched tags exactly.
+    ///
+    pub fn push_tag_value(tag: &num::BigUint) -> num::BigUint {
+        tag.bitand(num::BigUint::from(u64::MAX))
+    }
+
+    ///
+    /// Whether the `PUSH [tag]` value refers to a sub-assembly block.
+    ///
+    pub fn is_sub_assembly_tag(tag: &num::BigUint) -> bool {
+        tag > &num::BigUint::from(u64::MAX)
+    }
+
+    ///
+    /// Returns the key of the jump destination block.
+    ///
+    /// The tags above `u32::MAX` in the deploy code refer to the runtime code blocks.
+    ///
+    pub fn destination_block_key(
+        code_type: compiler_llvm_context::CodeType,
+        destination: num::BigUint,
+    ) -> compiler_llvm_context::FunctionBlockKey {
+        if destination > num::BigUint::from(u32::MAX) {
+            compiler_llvm_context::FunctionBlockKey::new(
+                compiler_llvm_context::CodeType::Runtime,
+                destination - num::BigUint::from(1u64 << 32),
+            )
+        } else {
+            compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
+        }
+    }
+
     ///
     /// Consumes the entry or a conditional block attached to another one.
     ///
@@ -95,6 +338,9 @@ impl Function {
         &mut self,
         blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
         visited: &mut HashSet<VisitedElement>,
+        functions: &mut InternalFunctions,
+        budget: &mut Budget,
+        pointers: &mut FunctionPointers,
         mut queue_element: QueueElement,
     ) -> anyhow::Result<()> {
         let version = self.solc_version.to_owned();
@@ -106,6 +352,9 @@ impl Function {
         if visited.contains(&visited_element) {
             return Ok(());
         }
+        if !budget.consume(&queue_element.block_key) {
+            return Ok(());
+        }
         visited.insert(visited_element);
 
         let mut block = blocks
@@ -122,28 +371,56 @@ impl Function {
         }
 
         let mut block_size = 0;
-        for block_element in block.elements.iter_mut() {
+        let mut truncation = None;
+        for (index, block_element) in block.elements.iter_mut().enumerate() {
             block_size += 1;
 
-            if Self::handle_instruction(
+            if let Err(error) = Self::handle_instruction(
+                blocks,
+                functions,
+                budget,
+                pointers,
                 block.key.code_type,
                 &mut block.stack,
                 block_element,
                 &version,
                 &mut queue,
                 &mut queue_element,
-            )
-            .is_err()
-            {
+            ) {
+                truncation = Some((
+                    index,
+                    block_element.location.clone(),
+                    block_element
+                        .instruction
+                        .to_string()
+                        .split_whitespace()
+                        .collect::<Vec<&str>>()
+                        .join(" "),
+                    error,
+                ));
+
                 block_element.stack = block.stack.clone();
                 block_element.instruction = Instruction::invalid();
                 break;
             }
         }
         block.elements.truncate(block_size);
+        if let Some((index, location, instruction, error)) = truncation {
+            let block_key = block.key.clone();
+            self.truncated_blocks.push(DeadBlock::new(
+                block_key,
+                location,
+                DeadBlockReason::Truncated {
+                    function: self.r#type.name().to_owned(),
+                    index,
+                    instruction,
+                    error: error.to_string(),
+                },
+            ));
+        }
 
         for element in queue.into_iter() {
-            self.consume_block(blocks, visited, element)?;
+            self.consume_block(blocks, visited, functions, budget, pointers, element)?;
         }
 
         Ok(())
@@ -155,7 +432,12 @@ impl Function {
     /// The blocks with an invalid stack state are considered being partially unreachable, and
     /// the invalid part is truncated after terminating with an `INVALID` instruction.
     ///
+    #[allow(clippy::too_many_arguments)]
     fn handle_instruction(
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        functions: &mut InternalFunctions,
+        budget: &mut Budget,
+        pointers: &mut FunctionPointers,
         code_type: compiler_llvm_context::CodeType,
         block_stack: &mut Stack,
         block_element: &mut BlockElement,
@@ -163,13 +445,61 @@ impl Function {
         queue: &mut Vec<QueueElement>,
         queue_element: &mut QueueElement,
     ) -> anyhow::Result<()> {
+        if block_stack.elements.len() > Stack::MAX_SIZE {
+            anyhow::bail!("Stack overflow");
+        }
+        if !Self::is_return_address_movable(&block_element.instruction.name)
+            && block_stack
+                .elements
+                .iter()
+                .rev()
+                .take(block_element.instruction.input_size(version))
+                .any(|element| element == &Element::ReturnAddress)
+        {
+            anyhow::bail!("The internal function return address cannot be used as a value");
+        }
+        if !Self::is_return_address_movable(&block_element.instruction.name)
+            && block_element.instruction.name != InstructionName::JUMPI
+        {
+            for element in block_stack
+                .elements
+                .iter()
+                .rev()
+                .take(block_element.instruction.input_size(version))
+            {
+                if let Element::Tag(tag) = element {
+                    // The tag arithmetic, e.g. combining the deploy and runtime code tags of a
+                    // constructor-assigned function pointer, also produces non-tag values.
+                    let block_key =
+                        compiler_llvm_context::FunctionBlockKey::new(code_type, tag.to_owned());
+                    if blocks.contains_key(&block_key) {
+                        pointers.insert_escaped(block_key);
+                    }
+                }
+            }
+        }
+
         match block_element.instruction {
             Instruction {
                 name: InstructionName::PUSH_Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 let tag: num::BigUint = tag.parse().expect("Always valid");
-                block_stack.push(Element::Tag(tag.bitand(num::BigUint::from(u64::MAX))));
+                let value = Self::push_tag_value(&tag);
+                match code_type {
+                    compiler_llvm_context::CodeType::Deploy if Self::is_sub_assembly_tag(&tag) => {
+                        let block_key = compiler_llvm_context::FunctionBlockKey::new(
+                            compiler_llvm_context::CodeType::Runtime,
+                            value.clone(),
+                        );
+                        if blocks.contains_key(&block_key) {
+                            pointers.insert_escaped(block_key);
+                        }
+                        block_stack.push(Element::Constant(value));
+                    }
+                    _ => block_stack.push(Element::Tag(value)),
+                }
 
                 block_element.stack = block_stack.clone();
             }
@@ -180,15 +510,70 @@ impl Function {
                 queue_element.predecessor = Some(queue_element.block_key.clone());
 
                 block_element.stack = block_stack.clone();
+                if let Some(Element::ReturnAddress) = block_stack.elements.last() {
+                    block_stack.pop()?;
+                    if block_stack.elements.contains(&Element::ReturnAddress) {
+                        anyhow::bail!("The internal function return address is duplicated");
+                    }
+                    block_element.instruction.name = InstructionName::InternalReturn {
+                        output_size: block_stack.elements.len(),
+                    };
+                    return Ok(());
+                }
+                if let Some(Element::Value) = block_stack.elements.last() {
+                    pointers.is_dynamic_jump_found = true;
+                    let destinations = pointers.candidates(code_type);
+                    if destinations.is_empty() {
+                        anyhow::bail!(Self::DYNAMIC_JUMP_UNKNOWN_ERROR);
+                    }
+
+                    block_stack.pop()?;
+                    for destination in destinations.iter() {
+                        queue.push(QueueElement::new(
+                            compiler_llvm_context::FunctionBlockKey::new(
+                                code_type,
+                                destination.to_owned(),
+                            ),
+                            queue_element.predecessor.clone(),
+                            block_stack.to_owned(),
+                        ));
+                    }
+                    block_element.instruction.name = InstructionName::DynamicJump;
+                    block_element.destinations = destinations;
+                    return Ok(());
+                }
+
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    compiler_llvm_context::FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::destination_block_key(code_type, destination);
+                if block_element.instruction.value.as_deref() == Some(Self::JUMP_IN_VALUE) {
+                    if let Some((input_size, output_size, return_address)) = Self::try_recover_call(
+                        version,
+                        blocks,
+                        functions,
+                        budget,
+                        pointers,
+                        &queue_element.block_key,
+                        &block_key,
+                        block_stack,
+                    )? {
+                        block_element.instruction.name = InstructionName::InternalCall {
+                            input_size,
+                            output_size,
+                        };
+
+                        let frame_start = block_stack.elements.len() - input_size - 1;
+                        block_stack.elements.truncate(frame_start);
+                        for _ in 0..output_size {
+                            block_stack.push(Element::Value);
+                        }
+                        queue.push(QueueElement::new(
+                            Self::destination_block_key(code_type, return_address),
+                            queue_element.predecessor.clone(),
+                            block_stack.to_owned(),
+                        ));
+                        return Ok(());
+                    }
+                }
                 queue.push(QueueElement::new(
                     block_key,
                     queue_element.predecessor.clone(),
@@ -203,14 +588,7 @@ impl Function {
 
                 block_element.stack = block_stack.clone();
                 let destination = block_stack.pop_tag()?;
-                let block_key = if destination > num::BigUint::from(u32::MAX) {
-                    compiler_llvm_context::FunctionBlockKey::new(
-                        compiler_llvm_context::CodeType::Runtime,
-                        destination - num::BigUint::from(1u64 << 32),
-                    )
-                } else {
-                    compiler_llvm_context::FunctionBlockKey::new(code_type, destination)
-                };
+                let block_key = Self::destination_block_key(code_type, destination);
                 block_stack.pop()?;
                 queue.push(QueueElement::new(
                     block_key,
@@ -221,6 +599,7 @@ impl Function {
             Instruction {
                 name: InstructionName::Tag,
                 value: Some(ref tag),
+                ..
             } => {
                 block_element.stack = block_stack.clone();
 
@@ -462,6 +841,13 @@ impl Function {
                 block_element.stack = block_stack.clone();
             }
 
+            Instruction {
+                name: InstructionName::PUSH0,
+                ..
+            } => {
+                block_stack.push(StackElement::Constant(BigUint::zero()));
+                block_element.stack = block_stack.clone();
+            }
             Instruction {
                 name:
                     InstructionName::PUSH
@@ -503,6 +889,7 @@ impl Function {
                     | InstructionName::PUSHLIB
                     | InstructionName::PUSHDEPLOYADDRESS,
                 value: Some(ref constant),
+                ..
             } => {
                 let element = match num::BigUint::from_str_radix(
                     constant.as_str(),
@@ -937,6 +1324,146 @@ impl Function {
         Ok(())
     }
 
+    ///
+    /// Tries to recover the internal function call from the `JUMP [in]` instruction.
+    ///
+    /// The topmost tag below the destination is the return address, and the elements above it
+    /// are the call arguments. Returns the numbers of arguments and return values, and the return
+    /// address, if the call can be compiled as a real function call.
+    ///
+    /// The return address block must follow the call site block, as `solc` places it right after
+    /// the call. Otherwise, the tag may be a function pointer passed as an argument, so the call
+    /// is inlined.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    fn try_recover_call(
+        version: &semver::Version,
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        functions: &mut InternalFunctions,
+        budget: &mut Budget,
+        pointers: &mut FunctionPointers,
+        call_site_key: &compiler_llvm_context::FunctionBlockKey,
+        entry_key: &compiler_llvm_context::FunctionBlockKey,
+        block_stack: &Stack,
+    ) -> anyhow::Result<Option<(usize, usize, num::BigUint)>> {
+        let (input_size, return_address) = match block_stack
+            .elements
+            .iter()
+            .rev()
+            .enumerate()
+            .find_map(|(index, element)| match element {
+                Element::Tag(tag) => Some((index, tag.to_owned())),
+                _ => None,
+            }) {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+
+        let return_key =
+            Self::destination_block_key(call_site_key.code_type, return_address.clone());
+        let is_return_address_following = blocks
+            .get(call_site_key)
+            .and_then(|block| block.next_key.as_ref())
+            == Some(&return_key);
+        if !is_return_address_following {
+            return Ok(None);
+        }
+
+        let are_arguments_values = block_stack
+            .elements
+            .iter()
+            .rev()
+            .take(input_size)
+            .all(|element| matches!(element, Element::Value | Element::Constant(_)));
+        if !are_arguments_values {
+            return Ok(None);
+        }
+
+        let function_key = (entry_key.to_owned(), input_size);
+        let output_size = match functions.get(&function_key) {
+            Some(Recovery::Recovered(function)) => function.r#type.output_size(),
+            Some(Recovery::InProgress {
+                output_size: Some(output_size),
+            }) => *output_size,
+            Some(Recovery::InProgress { output_size: None }) => {
+                anyhow::bail!("The recursive call return values number is unknown yet")
+            }
+            Some(Recovery::Inlined) => return Ok(None),
+            None => {
+                functions.insert(
+                    function_key.clone(),
+                    Recovery::InProgress { output_size: None },
+                );
+                let function = Self::try_new_internal(
+                    version.to_owned(),
+                    blocks,
+                    functions,
+                    budget,
+                    pointers,
+                    entry_key.to_owned(),
+                    input_size,
+                )?;
+                match function {
+                    Some(function) => {
+                        let output_size = function.r#type.output_size();
+                        functions.insert(function_key, Recovery::Recovered(function));
+                        output_size
+                    }
+                    None => {
+                        functions.insert(function_key, Recovery::Inlined);
+                        return Ok(None);
+                    }
+                }
+            }
+        };
+
+        Ok(Some((input_size, output_size, return_address)))
+    }
+
+    ///
+    /// Checks whether the instruction only moves the stack elements, so it is allowed to move
+    /// the internal function return address.
+    ///
+    fn is_return_address_movable(name: &InstructionName) -> bool {
+        matches!(
+            name,
+            InstructionName::JUMP
+                | InstructionName::POP
+                | InstructionName::DUP1
+                | InstructionName::DUP2
+                | InstructionName::DUP3
+                | InstructionName::DUP4
+                | InstructionName::DUP5
+                | InstructionName::DUP6
+                | InstructionName::DUP7
+                | InstructionName::DUP8
+                | InstructionName::DUP9
+                | InstructionName::DUP10
+                | InstructionName::DUP11
+                | InstructionName::DUP12
+                | InstructionName::DUP13
+                | InstructionName::DUP14
+                | InstructionName::DUP15
+                | InstructionName::DUP16
+                | InstructionName::SWAP1
+                | InstructionName::SWAP2
+                | InstructionName::SWAP3
+                | InstructionName::SWAP4
+                | InstructionName::SWAP5
+                | InstructionName::SWAP6
+                | InstructionName::SWAP7
+                | InstructionName::SWAP8
+                | InstructionName::SWAP9
+                | InstructionName::SWAP10
+                | InstructionName::SWAP11
+                | InstructionName::SWAP12
+                | InstructionName::SWAP13
+                | InstructionName::SWAP14
+                | InstructionName::SWAP15
+                | InstructionName::SWAP16
+        )
+    }
+
     ///
     /// Pushes a block into the function.
     ///
@@ -966,6 +1493,9 @@ impl Function {
     fn finalize(mut self) -> Self {
         for (_tag, blocks) in self.blocks.iter() {
             for block in blocks.iter() {
+                if block.initial_stack.elements.len() > self.stack_size {
+                    self.stack_size = block.initial_stack.elements.len();
+                }
                 for block_element in block.elements.iter() {
                     if block_element.stack.elements.len() > self.stack_size {
                         self.stack_size = block_element.stack.elements.len();
@@ -983,18 +1513,35 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        let function = context.add_function(
-            EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
-            context.function_type(
-                vec![context
-                    .integer_type(compiler_common::BIT_LENGTH_BOOLEAN)
-                    .as_basic_type_enum()],
+        let function = match self.r#type {
+            Type::Initial => context.add_function(
+                EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
+                context.function_type(
+                    vec![context
+                        .integer_type(compiler_common::BIT_LENGTH_BOOLEAN)
+                        .as_basic_type_enum()],
+                    0,
+                    false,
+                ),
                 0,
-                false,
-            ),
-            0,
-            Some(inkwell::module::Linkage::Private),
-        )?;
+                Some(inkwell::module::Linkage::Private),
+            )?,
+            Type::Internal {
+                ref name,
+                input_size,
+                output_size,
+                ..
+            } => context.add_function(
+                name.as_str(),
+                context.function_type(
+                    vec![context.field_type().as_basic_type_enum(); input_size],
+                    output_size,
+                    false,
+                ),
+                output_size,
+                Some(inkwell::module::Linkage::Private),
+            )?,
+        };
         function
             .borrow_mut()
             .set_evmla_data(compiler_llvm_context::FunctionEVMLAData::new(
@@ -1005,12 +1552,7 @@ where
     }
 
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        context.set_current_function(EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME)?;
-        let is_deploy_code_flag = context
-            .current_function()
-            .borrow()
-            .get_nth_param(0)
-            .into_int_value();
+        context.set_current_function(self.r#type.name())?;
 
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
@@ -1041,25 +1583,56 @@ where
         }
         context.evmla_mut().stack = stack_variables;
 
-        let deploy_code_block = context.current_function().borrow().evmla().find_block(
-            &compiler_llvm_context::FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Deploy,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        let runtime_code_block = context.current_function().borrow().evmla().find_block(
-            &compiler_llvm_context::FunctionBlockKey::new(
-                compiler_llvm_context::CodeType::Runtime,
-                num::BigUint::zero(),
-            ),
-            &Stack::default().hash(),
-        )?;
-        context.build_conditional_branch(
-            is_deploy_code_flag,
-            deploy_code_block.inner(),
-            runtime_code_block.inner(),
-        );
+        match self.r#type {
+            Type::Initial => {
+                let is_deploy_code_flag = context
+                    .current_function()
+                    .borrow()
+                    .get_nth_param(0)
+                    .into_int_value();
+                let deploy_code_block = context.current_function().borrow().evmla().find_block(
+                    &compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Deploy,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                let runtime_code_block = context.current_function().borrow().evmla().find_block(
+                    &compiler_llvm_context::FunctionBlockKey::new(
+                        compiler_llvm_context::CodeType::Runtime,
+                        num::BigUint::zero(),
+                    ),
+                    &Stack::default().hash(),
+                )?;
+                context.build_conditional_branch(
+                    is_deploy_code_flag,
+                    deploy_code_block.inner(),
+                    runtime_code_block.inner(),
+                );
+            }
+            Type::Internal {
+                ref entry_key,
+                input_size,
+                ..
+            } => {
+                for index in 0..input_size {
+                    let pointer = context.evmla().stack[index + 1]
+                        .to_llvm()
+                        .into_pointer_value();
+                    let argument = context.current_function().borrow().get_nth_param(index);
+                    context.build_store(
+                        compiler_llvm_context::Pointer::new_stack_field(context, pointer),
+                        argument,
+                    );
+                }
+                let entry_block = context
+                    .current_function()
+                    .borrow()
+                    .evmla()
+                    .find_block(entry_key, &Self::internal_initial_stack(input_size).hash())?;
+                context.build_unconditional_branch(entry_block.inner());
+            }
+        }
 
         for (key, blocks) in self.blocks.into_iter() {
             for (llvm_block, ir_block) in context
@@ -1080,7 +1653,17 @@ where
         }
 
         context.set_basic_block(context.current_function().borrow().return_block());
-        context.build_return(None);
+        let r#return = context.current_function().borrow().r#return();
+        match r#return {
+            compiler_llvm_context::FunctionReturn::None => {
+                context.build_return(None);
+            }
+            compiler_llvm_context::FunctionReturn::Primitive { pointer }
+            | compiler_llvm_context::FunctionReturn::Compound { pointer, .. } => {
+                let return_value = context.build_load(pointer, "return_value");
+                context.build_return(Some(&return_value));
+            }
+        }
 
         Ok(())
     }
@@ -1088,7 +1671,12 @@ where
 
 impl std::fmt::Display for Function {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "function main (max_sp = {}) {{", self.stack_size,)?;
+        writeln!(
+            f,
+            "function {} (max_sp = {}) {{",
+            self.r#type.name(),
+            self.stack_size,
+        )?;
         for (key, blocks) in self.blocks.iter() {
             for (index, block) in blocks.iter().enumerate() {
                 writeln!(
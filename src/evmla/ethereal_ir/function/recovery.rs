@@ -0,0 +1,23 @@
+//!
+//! The Ethereal IR internal function recovery state.
+//!
+
+use crate::evmla::ethereal_ir::function::Function;
+
+///
+/// The Ethereal IR internal function recovery state.
+///
+#[derive(Debug, Clone)]
+pub enum Recovery {
+    /// The function is being analyzed.
+    ///
+    /// The number of return values is only known for the second pass over a recursive function.
+    InProgress {
+        /// The assumed number of return values.
+        output_size: Option<usize>,
+    },
+    /// The function has been recovered.
+    Recovered(Function),
+    /// The function is ambiguous, so its calls are inlined.
+    Inlined,
+}
@@ -0,0 +1,86 @@
+//!
+//! The Ethereal IR function type.
+//!
+
+use serde::Serialize;
+
+use crate::evmla::ethereal_ir::EtherealIR;
+
+///
+/// The Ethereal IR function type.
+///
+#[derive(Debug, Clone, Serialize)]
+pub enum Type {
+    /// The initial function, combining the deploy and runtime code.
+    Initial,
+    /// The internal function, recovered from the `JUMP [in]` and `JUMP [out]` patterns.
+    Internal {
+        /// The function name.
+        name: String,
+        /// The function entry block key.
+        #[serde(serialize_with = "crate::evmla::ethereal_ir::serializer::block_key")]
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        /// The number of function arguments.
+        input_size: usize,
+        /// The number of function return values.
+        output_size: usize,
+    },
+}
+
+impl Type {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_initial() -> Self {
+        Self::Initial
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_internal(
+        entry_key: compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+        output_size: usize,
+    ) -> Self {
+        Self::Internal {
+            name: Self::internal_name(&entry_key, input_size),
+            entry_key,
+            input_size,
+            output_size,
+        }
+    }
+
+    ///
+    /// Returns the internal function name.
+    ///
+    /// The same entry block may be called with different numbers of arguments, so the number is
+    /// a part of the name.
+    ///
+    pub fn internal_name(
+        entry_key: &compiler_llvm_context::FunctionBlockKey,
+        input_size: usize,
+    ) -> String {
+        format!("function_{entry_key}_{input_size}")
+    }
+
+    ///
+    /// Returns the LLVM function name.
+    ///
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Initial => EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME,
+            Self::Internal { name, .. } => name.as_str(),
+        }
+    }
+
+    ///
+    /// Returns the number of function return values.
+    ///
+    pub fn output_size(&self) -> usize {
+        match self {
+            Self::Initial => 0,
+            Self::Internal { output_size, .. } => *output_size,
+        }
+    }
+}
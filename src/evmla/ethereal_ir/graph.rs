@@ -0,0 +1,266 @@
+//!
+//! The Ethereal IR control-flow graph in the Graphviz DOT format.
+//!
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::ethereal_ir::function::block::element::stack::element::Element as StackElement;
+use crate::evmla::ethereal_ir::function::block::element::stack::Stack;
+use crate::evmla::ethereal_ir::function::block::Block;
+use crate::evmla::ethereal_ir::function::r#type::Type as FunctionType;
+use crate::evmla::ethereal_ir::function::Function;
+use crate::evmla::ethereal_ir::EtherealIR;
+
+///
+/// The Ethereal IR control-flow graph in the Graphviz DOT format.
+///
+/// Each function is a cluster, and each block copy is a node identified by its key and initial
+/// stack state hash. The edges are the jumps, fallthroughs and internal function calls.
+///
+#[derive(Debug)]
+pub struct Graph<'a> {
+    /// The Ethereal IR to visualize.
+    ethereal_ir: &'a EtherealIR,
+}
+
+impl<'a> Graph<'a> {
+    /// The dump file extension.
+    pub const FILE_EXTENSION: &'static str = "ethir.dot";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(ethereal_ir: &'a EtherealIR) -> Self {
+        Self { ethereal_ir }
+    }
+
+    ///
+    /// Writes the graph to the debug output directory, next to the other IR dumps.
+    ///
+    pub fn dump(
+        &self,
+        debug_config: &compiler_llvm_context::DebugConfig,
+        contract_path: &str,
+    ) -> anyhow::Result<()> {
+        let file_path =
+            EtherealIR::dump_file_path(debug_config, contract_path, Self::FILE_EXTENSION);
+        std::fs::write(file_path.as_path(), self.to_string()).map_err(|error| {
+            anyhow::anyhow!(
+                "Ethereal IR graph file {:?} writing error: {}",
+                file_path,
+                error
+            )
+        })
+    }
+
+    ///
+    /// Writes the function cluster with its blocks, and then the edges.
+    ///
+    /// The edges are written outside of the cluster, as otherwise the blocks of other functions
+    /// they refer to would be drawn within it.
+    ///
+    fn write_function(f: &mut std::fmt::Formatter<'_>, function: &Function) -> std::fmt::Result {
+        let name = function.r#type.name();
+        writeln!(f, "    subgraph \"cluster_{name}\" {{")?;
+        writeln!(
+            f,
+            "        label=\"{}\";",
+            Self::escape(format!("{name} (max_sp = {})", function.stack_size).as_str())
+        )?;
+
+        for (key, blocks) in function.blocks.iter() {
+            for (index, block) in blocks.iter().enumerate() {
+                let mut label = format!(
+                    "block_{key}/{index}\\lhash: {:x}\\linitial stack: {}\\l",
+                    block.initial_stack.hash(),
+                    Self::escape(block.initial_stack.to_string().as_str()),
+                );
+                if !block.predecessors.is_empty() {
+                    let mut predecessors: Vec<String> = block
+                        .predecessors
+                        .iter()
+                        .map(|predecessor| predecessor.to_string())
+                        .collect();
+                    predecessors.sort();
+                    label
+                        .push_str(format!("predecessors: {}\\l", predecessors.join(", ")).as_str());
+                }
+                writeln!(
+                    f,
+                    "        \"{}\" [label=\"{}\"];",
+                    Self::node(name, key, index),
+                    label
+                )?;
+            }
+        }
+
+        writeln!(f, "    }}")?;
+
+        for (key, blocks) in function.blocks.iter() {
+            for (index, block) in blocks.iter().enumerate() {
+                Self::write_edges(f, function, Self::node(name, key, index).as_str(), block)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the outgoing edges of the block.
+    ///
+    fn write_edges(
+        f: &mut std::fmt::Formatter<'_>,
+        function: &Function,
+        source: &str,
+        block: &Block,
+    ) -> std::fmt::Result {
+        let name = function.r#type.name();
+        let code_type = block.key.code_type;
+
+        for element in block.elements.iter() {
+            let mut stack = element.stack.to_owned();
+            match element.instruction.name {
+                InstructionName::JUMP => {
+                    if let Ok(destination) = stack.pop_tag() {
+                        let key = Function::destination_block_key(code_type, destination);
+                        Self::write_edge(f, function, source, &key, &stack, None)?;
+                    }
+                }
+                InstructionName::JUMPI => {
+                    if let Ok(destination) = stack.pop_tag() {
+                        let _condition = stack.pop();
+                        let key = Function::destination_block_key(code_type, destination);
+                        Self::write_edge(f, function, source, &key, &stack, Some("true"))?;
+                    }
+                }
+                InstructionName::Tag => {
+                    if let Some(Ok(tag)) = element
+                        .instruction
+                        .value
+                        .as_deref()
+                        .map(|tag| tag.parse::<num::BigUint>())
+                    {
+                        let key = compiler_llvm_context::FunctionBlockKey::new(code_type, tag);
+                        Self::write_edge(f, function, source, &key, &stack, Some("fallthrough"))?;
+                    }
+                }
+                InstructionName::InternalCall {
+                    input_size,
+                    output_size,
+                } => {
+                    let entry = match stack.pop_tag() {
+                        Ok(entry) => entry,
+                        Err(_) => continue,
+                    };
+                    let entry_key = Function::destination_block_key(code_type, entry);
+                    writeln!(
+                        f,
+                        "    \"{source}\" -> \"{}\" [label=\"call\", style=dashed];",
+                        Self::node(
+                            FunctionType::internal_name(&entry_key, input_size).as_str(),
+                            &entry_key,
+                            0
+                        ),
+                    )?;
+
+                    stack
+                        .elements
+                        .truncate(stack.elements.len().saturating_sub(input_size));
+                    if let Ok(return_address) = stack.pop_tag() {
+                        for _ in 0..output_size {
+                            stack.push(StackElement::Value);
+                        }
+                        let key = Function::destination_block_key(code_type, return_address);
+                        Self::write_edge(f, function, source, &key, &stack, Some("return"))?;
+                    }
+                }
+                InstructionName::DynamicJump => {
+                    let _pointer = stack.pop();
+                    for destination in element.destinations.iter() {
+                        let key = compiler_llvm_context::FunctionBlockKey::new(
+                            code_type,
+                            destination.to_owned(),
+                        );
+                        Self::write_edge(f, function, source, &key, &stack, Some("dynamic"))?;
+                    }
+                }
+                InstructionName::InternalReturn { .. } => {
+                    writeln!(
+                        f,
+                        "    \"{source}\" -> \"{name}/return\" [label=\"return\"];"
+                    )?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Writes the edge to the destination block copy, which is found by the stack state hash.
+    ///
+    fn write_edge(
+        f: &mut std::fmt::Formatter<'_>,
+        function: &Function,
+        source: &str,
+        key: &compiler_llvm_context::FunctionBlockKey,
+        stack: &Stack,
+        label: Option<&str>,
+    ) -> std::fmt::Result {
+        let hash = stack.hash();
+        let index = function.blocks.get(key).and_then(|blocks| {
+            blocks.iter().position(|block| {
+                block.initial_stack.hash() == hash || block.extra_hashes.contains(&hash)
+            })
+        });
+        let destination = match index {
+            Some(index) => Self::node(function.r#type.name(), key, index),
+            None => format!("{}/{key}/unknown", function.r#type.name()),
+        };
+
+        match label {
+            Some(label) => writeln!(
+                f,
+                "    \"{source}\" -> \"{destination}\" [label=\"{label}\"];"
+            ),
+            None => writeln!(f, "    \"{source}\" -> \"{destination}\";"),
+        }
+    }
+
+    ///
+    /// Returns the node identifier of the block copy.
+    ///
+    fn node(
+        function_name: &str,
+        key: &compiler_llvm_context::FunctionBlockKey,
+        index: usize,
+    ) -> String {
+        format!("{function_name}/{key}/{index}")
+    }
+
+    ///
+    /// Escapes the DOT string literal.
+    ///
+    fn escape(string: &str) -> String {
+        string.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+}
+
+impl std::fmt::Display for Graph<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "digraph \"ethir\" {{")?;
+        writeln!(f, "    node [shape=box, fontname=\"monospace\"];")?;
+        writeln!(
+            f,
+            "    label=\"{}\";",
+            Self::escape(self.ethereal_ir.statistics().to_string().as_str())
+        )?;
+        Self::write_function(f, &self.ethereal_ir.entry_function)?;
+        for function in self.ethereal_ir.functions.values() {
+            Self::write_function(f, function)?;
+        }
+        writeln!(f, "}}")?;
+
+        Ok(())
+    }
+}
@@ -2,16 +2,30 @@
 //! The Ethereal IR of the EVM bytecode.
 //!
 
+pub mod dead_block;
 pub mod entry_link;
 pub mod function;
+pub mod graph;
+pub mod serializer;
+pub mod statistics;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::path::PathBuf;
+
+use serde::Serialize;
 
 use crate::evmla::assembly::instruction::Instruction;
 
+use self::dead_block::reason::Reason as DeadBlockReason;
+use self::dead_block::DeadBlock;
 use self::function::block::Block;
+use self::function::budget::Budget;
+use self::function::function_pointers::FunctionPointers;
+use self::function::recovery::Recovery;
 use self::function::Function;
+use self::statistics::Statistics;
 
 ///
 /// The Ethereal IR of the EVM bytecode.
@@ -25,12 +39,20 @@ use self::function::Function;
 /// each of initial states of the stack. The LLVM IR supports only static control flow, so the
 /// stack state must be known all the way throughout the program.
 ///
-#[derive(Debug)]
+/// The internal functions called with the `JUMP [in]` pattern are recovered into separate LLVM
+/// functions where their stack frames are unambiguous, and inlined otherwise.
+///
+#[derive(Debug, Serialize)]
 pub struct EtherealIR {
     /// The Solidity compiler version.
     pub solc_version: semver::Version,
-    /// The all-inlined function.
-    pub function: Function,
+    /// The entry function, combining the deploy and runtime code.
+    pub entry_function: Function,
+    /// The recovered internal functions.
+    #[serde(serialize_with = "self::serializer::functions")]
+    pub functions: BTreeMap<(compiler_llvm_context::FunctionBlockKey, usize), Function>,
+    /// The blocks never reached from the deploy or runtime code entry.
+    pub unreachable_blocks: Vec<DeadBlock>,
 }
 
 impl EtherealIR {
@@ -40,22 +62,163 @@ impl EtherealIR {
     /// The blocks hashmap initial capacity.
     pub const BLOCKS_HASHMAP_DEFAULT_CAPACITY: usize = 64;
 
+    /// The default limit of visited blocks, including copies, per contract.
+    pub const BLOCK_VISITS_DEFAULT_LIMIT: usize = 1 << 16;
+
+    /// The JSON dump file extension.
+    pub const JSON_FILE_EXTENSION: &'static str = "ethir.json";
+
     ///
     /// Assembles a sequence of functions from the sequence of instructions.
     ///
+    /// Fails if the blocks are duplicated beyond `block_visits_limit`, naming the most
+    /// duplicated ones.
+    ///
+    /// If there are jumps to internal function pointers, the analysis is repeated until all tags
+    /// used as function pointers are known. Fails if such a jump has no destinations at all, as
+    /// it would always trap at runtime.
+    ///
     pub fn new(
         solc_version: semver::Version,
         blocks: HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        block_visits_limit: usize,
     ) -> anyhow::Result<Self> {
-        let mut visited = HashSet::with_capacity(blocks.len());
-        let function = Function::new(solc_version.clone(), &blocks, &mut visited)?;
+        let mut pointers = FunctionPointers::default();
+        let (entry_function, functions) = loop {
+            let mut visited = HashSet::with_capacity(blocks.len());
+            let mut functions = BTreeMap::new();
+            let mut budget = Budget::new(block_visits_limit);
+            let entry_function = Function::new(
+                solc_version.clone(),
+                &blocks,
+                &mut visited,
+                &mut functions,
+                &mut budget,
+                &mut pointers,
+            )?;
+            budget.check()?;
+            if !pointers.next_pass() {
+                break (entry_function, functions);
+            }
+        };
+        let functions = functions
+            .into_iter()
+            .filter_map(|(key, function)| match function {
+                Recovery::Recovered(function) => Some((key, function)),
+                Recovery::InProgress { .. } | Recovery::Inlined => None,
+            })
+            .collect();
+        if let Some(dead_block) = std::iter::once(&entry_function)
+            .chain(functions.values())
+            .flat_map(|function| function.truncated_blocks.iter())
+            .find(|dead_block| dead_block.is_unknown_dynamic_jump())
+        {
+            anyhow::bail!(
+                "The internal function pointer call in {} cannot be compiled, as no function pointer values are found in the code",
+                dead_block
+            );
+        }
+        let unreachable_blocks = Self::unreachable_blocks(&blocks, &entry_function, &functions);
 
         Ok(Self {
             solc_version,
-            function,
+            entry_function,
+            functions,
+            unreachable_blocks,
+        })
+    }
+
+    ///
+    /// Returns the blocks truncated due to an invalid stack state, and the unreachable ones.
+    ///
+    pub fn dead_blocks(&self) -> Vec<&DeadBlock> {
+        std::iter::once(&self.entry_function)
+            .chain(self.functions.values())
+            .flat_map(|function| function.truncated_blocks.iter())
+            .chain(self.unreachable_blocks.iter())
+            .collect()
+    }
+
+    ///
+    /// Returns the statistics of all functions.
+    ///
+    pub fn statistics(&self) -> Statistics {
+        Statistics::new(std::iter::once(&self.entry_function).chain(self.functions.values()))
+    }
+
+    ///
+    /// Writes the JSON representation to the debug output directory, next to the other IR dumps.
+    ///
+    /// Unlike the text dump, the JSON one is meant to be compared by tools, e.g. to see how
+    /// a `solc` upgrade changes the control flow.
+    ///
+    pub fn dump_json(
+        &self,
+        debug_config: &compiler_llvm_context::DebugConfig,
+        contract_path: &str,
+    ) -> anyhow::Result<()> {
+        let file_path =
+            Self::dump_file_path(debug_config, contract_path, Self::JSON_FILE_EXTENSION);
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|error| anyhow::anyhow!("Ethereal IR JSON serialization error: {}", error))?;
+        std::fs::write(file_path.as_path(), json).map_err(|error| {
+            anyhow::anyhow!(
+                "Ethereal IR JSON file {:?} writing error: {}",
+                file_path,
+                error
+            )
         })
     }
 
+    ///
+    /// Returns the path of the dump file in the debug output directory.
+    ///
+    /// The contract path is flattened into the file name the same way as for the other IR dumps.
+    ///
+    pub fn dump_file_path(
+        debug_config: &compiler_llvm_context::DebugConfig,
+        contract_path: &str,
+        extension: &str,
+    ) -> PathBuf {
+        let mut file_path = debug_config.output_directory.to_owned();
+        file_path.push(format!(
+            "{}.{}",
+            contract_path.replace('/', "_").replace(':', "."),
+            extension
+        ));
+        file_path
+    }
+
+    ///
+    /// Finds the blocks which are not visited by any of the functions.
+    ///
+    fn unreachable_blocks(
+        blocks: &HashMap<compiler_llvm_context::FunctionBlockKey, Block>,
+        entry_function: &Function,
+        functions: &BTreeMap<(compiler_llvm_context::FunctionBlockKey, usize), Function>,
+    ) -> Vec<DeadBlock> {
+        let mut unreachable_blocks: Vec<DeadBlock> = blocks
+            .values()
+            .filter(|block| {
+                std::iter::once(entry_function)
+                    .chain(functions.values())
+                    .all(|function| !function.blocks.contains_key(&block.key))
+            })
+            .map(|block| {
+                DeadBlock::new(
+                    block.key.clone(),
+                    block
+                        .elements
+                        .iter()
+                        .find_map(|element| element.location.clone()),
+                    DeadBlockReason::Unreachable,
+                )
+            })
+            .collect();
+        unreachable_blocks.sort_by(|a, b| a.key.cmp(&b.key));
+        unreachable_blocks
+    }
+
     ///
     /// Gets blocks for the specified type of the contract code.
     ///
@@ -63,8 +226,11 @@ impl EtherealIR {
         solc_version: semver::Version,
         code_type: compiler_llvm_context::CodeType,
         instructions: &[Instruction],
+        source_paths: &BTreeMap<isize, String>,
     ) -> anyhow::Result<HashMap<compiler_llvm_context::FunctionBlockKey, Block>> {
-        let mut blocks = HashMap::with_capacity(Self::BLOCKS_HASHMAP_DEFAULT_CAPACITY);
+        let mut blocks: HashMap<compiler_llvm_context::FunctionBlockKey, Block> =
+            HashMap::with_capacity(Self::BLOCKS_HASHMAP_DEFAULT_CAPACITY);
+        let mut previous_key: Option<compiler_llvm_context::FunctionBlockKey> = None;
         let mut offset = 0;
 
         while offset < instructions.len() {
@@ -72,11 +238,18 @@ impl EtherealIR {
                 solc_version.clone(),
                 code_type,
                 &instructions[offset..],
+                source_paths,
             )?;
-            blocks.insert(
-                compiler_llvm_context::FunctionBlockKey::new(code_type, block.key.tag.clone()),
-                block,
-            );
+            let key =
+                compiler_llvm_context::FunctionBlockKey::new(code_type, block.key.tag.clone());
+            if let Some(previous_block) = previous_key
+                .as_ref()
+                .and_then(|previous_key| blocks.get_mut(previous_key))
+            {
+                previous_block.next_key = Some(key.clone());
+            }
+            blocks.insert(key.clone(), block);
+            previous_key = Some(key);
             offset += size;
         }
 
@@ -89,7 +262,10 @@ where
     D: compiler_llvm_context::Dependency,
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
-        self.function.declare(context)?;
+        self.entry_function.declare(context)?;
+        for function in self.functions.values_mut() {
+            function.declare(context)?;
+        }
 
         Ok(())
     }
@@ -97,7 +273,10 @@ where
     fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         context.evmla_mut().stack = vec![];
 
-        self.function.into_llvm(context)?;
+        self.entry_function.into_llvm(context)?;
+        for function in self.functions.into_values() {
+            function.into_llvm(context)?;
+        }
 
         Ok(())
     }
@@ -105,8 +284,426 @@ where
 
 impl std::fmt::Display for EtherealIR {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "{}", self.function)?;
+        writeln!(f, "// {}", self.statistics())?;
+        writeln!(f, "{}", self.entry_function)?;
+        for function in self.functions.values() {
+            writeln!(f, "{function}")?;
+        }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::evmla::assembly::instruction::name::Name as InstructionName;
+    use crate::evmla::assembly::instruction::Instruction;
+
+    use super::function::r#type::Type;
+    use super::function::Function;
+    use super::graph::Graph;
+    use super::EtherealIR;
+
+    const RECOVERED_FUNCTION_DEPLOY_CODE: &str = r#"[
+        { "name": "PUSH [tag]", "value": "1" },
+        { "name": "PUSH", "value": "5" },
+        { "name": "PUSH [tag]", "value": "2" },
+        { "name": "JUMP", "value": "[in]" },
+        { "name": "tag", "value": "1" },
+        { "name": "JUMPDEST" },
+        { "name": "STOP" },
+        { "name": "tag", "value": "2" },
+        { "name": "JUMPDEST" },
+        { "name": "POP" },
+        { "name": "JUMP", "value": "[out]" }
+    ]"#;
+
+    const AMBIGUOUS_FUNCTION_DEPLOY_CODE: &str = r#"[
+        { "name": "PUSH", "value": "7" },
+        { "name": "PUSH [tag]", "value": "1" },
+        { "name": "PUSH", "value": "5" },
+        { "name": "PUSH [tag]", "value": "2" },
+        { "name": "JUMP", "value": "[in]" },
+        { "name": "tag", "value": "1" },
+        { "name": "JUMPDEST" },
+        { "name": "POP" },
+        { "name": "STOP" },
+        { "name": "tag", "value": "2" },
+        { "name": "JUMPDEST" },
+        { "name": "DUP3" },
+        { "name": "POP" },
+        { "name": "POP" },
+        { "name": "JUMP", "value": "[out]" }
+    ]"#;
+
+    const FUNCTION_POINTER_ARGUMENT_DEPLOY_CODE: &str = r#"[
+        { "name": "PUSH [tag]", "value": "1" },
+        { "name": "PUSH [tag]", "value": "3" },
+        { "name": "PUSH [tag]", "value": "2" },
+        { "name": "JUMP", "value": "[in]" },
+        { "name": "tag", "value": "1" },
+        { "name": "JUMPDEST" },
+        { "name": "STOP" },
+        { "name": "tag", "value": "2" },
+        { "name": "JUMPDEST" },
+        { "name": "PUSH [tag]", "value": "4" },
+        { "name": "SWAP1" },
+        { "name": "JUMP" },
+        { "name": "tag", "value": "4" },
+        { "name": "JUMPDEST" },
+        { "name": "JUMP", "value": "[out]" },
+        { "name": "tag", "value": "3" },
+        { "name": "JUMPDEST" },
+        { "name": "JUMP", "value": "[out]" }
+    ]"#;
+
+    const STOP_CODE: &str = r#"[{ "name": "STOP" }]"#;
+
+    fn ethereal_ir(deploy_code: &str, block_visits_limit: usize) -> anyhow::Result<EtherealIR> {
+        ethereal_ir_with_runtime(
+            semver::Version::new(0, 8, 24),
+            deploy_code,
+            STOP_CODE,
+            block_visits_limit,
+        )
+    }
+
+    fn ethereal_ir_with_runtime(
+        version: semver::Version,
+        deploy_code: &str,
+        runtime_code: &str,
+        block_visits_limit: usize,
+    ) -> anyhow::Result<EtherealIR> {
+        let deploy_code: Vec<Instruction> =
+            serde_json::from_str(deploy_code).expect("Always valid");
+        let runtime_code: Vec<Instruction> =
+            serde_json::from_str(runtime_code).expect("Always valid");
+
+        let mut blocks = EtherealIR::get_blocks(
+            version.clone(),
+            compiler_llvm_context::CodeType::Deploy,
+            deploy_code.as_slice(),
+            &BTreeMap::new(),
+        )
+        .expect("Always valid");
+        blocks.extend(
+            EtherealIR::get_blocks(
+                version.clone(),
+                compiler_llvm_context::CodeType::Runtime,
+                runtime_code.as_slice(),
+                &BTreeMap::new(),
+            )
+            .expect("Always valid"),
+        );
+        EtherealIR::new(version, blocks, block_visits_limit)
+    }
+
+    fn dynamic_jump_destinations(
+        ethereal_ir: &EtherealIR,
+        code_type: compiler_llvm_context::CodeType,
+    ) -> Vec<Vec<num::BigUint>> {
+        ethereal_ir
+            .entry_function
+            .blocks
+            .iter()
+            .filter(|(key, _)| key.code_type == code_type)
+            .flat_map(|(_, copies)| copies.iter())
+            .flat_map(|block| block.elements.iter())
+            .filter(|element| element.instruction.name == InstructionName::DynamicJump)
+            .map(|element| element.destinations.clone())
+            .collect()
+    }
+
+    #[test]
+    fn ok_function_recovered() {
+        let ethereal_ir = ethereal_ir(
+            RECOVERED_FUNCTION_DEPLOY_CODE,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        assert_eq!(ethereal_ir.functions.len(), 1);
+        let function = ethereal_ir
+            .functions
+            .values()
+            .next()
+            .expect("Always exists");
+        assert!(matches!(
+            function.r#type,
+            Type::Internal {
+                input_size: 1,
+                output_size: 0,
+                ..
+            }
+        ));
+        assert!(ethereal_ir.dead_blocks().is_empty());
+    }
+
+    #[test]
+    fn ok_ambiguous_function_inlined() {
+        let ethereal_ir = ethereal_ir(
+            AMBIGUOUS_FUNCTION_DEPLOY_CODE,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        assert!(ethereal_ir.functions.is_empty());
+        assert!(ethereal_ir.entry_function.blocks.contains_key(
+            &compiler_llvm_context::FunctionBlockKey::new(
+                compiler_llvm_context::CodeType::Deploy,
+                num::BigUint::from(2u32),
+            )
+        ));
+        assert!(ethereal_ir.dead_blocks().is_empty());
+    }
+
+    #[test]
+    fn ok_function_pointer_argument_inlined() {
+        let ethereal_ir = ethereal_ir(
+            FUNCTION_POINTER_ARGUMENT_DEPLOY_CODE,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        assert!(ethereal_ir.functions.is_empty());
+        for tag in [1u32, 2, 3, 4] {
+            assert!(ethereal_ir.entry_function.blocks.contains_key(
+                &compiler_llvm_context::FunctionBlockKey::new(
+                    compiler_llvm_context::CodeType::Deploy,
+                    num::BigUint::from(tag),
+                )
+            ));
+        }
+        assert!(ethereal_ir.dead_blocks().is_empty());
+    }
+
+    #[test]
+    fn ok_discarded_recovery_not_charged() {
+        let ethereal_ir = ethereal_ir(AMBIGUOUS_FUNCTION_DEPLOY_CODE, 4).expect("Always valid");
+
+        assert_eq!(ethereal_ir.statistics().blocks, 4);
+    }
+
+    #[test]
+    fn error_block_visits_limit_exceeded() {
+        let error = ethereal_ir(RECOVERED_FUNCTION_DEPLOY_CODE, 3)
+            .expect_err("Always invalid")
+            .to_string();
+
+        assert!(error.contains("The Ethereal IR block duplication limit of 3 is exceeded"));
+        assert!(error.contains("The most duplicated blocks: "));
+    }
+
+    #[test]
+    fn ok_dumps() {
+        let ethereal_ir = ethereal_ir(
+            RECOVERED_FUNCTION_DEPLOY_CODE,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        let output_directory =
+            std::env::temp_dir().join(format!("zksolc-ethir-dumps-{}", std::process::id()));
+        std::fs::create_dir_all(output_directory.as_path()).expect("Always valid");
+        let debug_config = compiler_llvm_context::DebugConfig::new(output_directory.clone());
+
+        ethereal_ir
+            .dump_json(&debug_config, "contracts/test.sol:Test")
+            .expect("Always valid");
+        Graph::new(&ethereal_ir)
+            .dump(&debug_config, "contracts/test.sol:Test")
+            .expect("Always valid");
+
+        let json = std::fs::read(output_directory.join("contracts_test.sol.Test.ethir.json"))
+            .expect("Always exists");
+        let dot =
+            std::fs::read_to_string(output_directory.join("contracts_test.sol.Test.ethir.dot"))
+                .expect("Always exists");
+        std::fs::remove_dir_all(output_directory.as_path()).expect("Always valid");
+
+        let json: serde_json::Value =
+            serde_json::from_slice(json.as_slice()).expect("Always valid");
+        assert_eq!(
+            json["functions"].as_object().expect("Always exists").len(),
+            1
+        );
+        assert!(dot.starts_with("digraph \"ethir\" {"));
+        assert!(dot.contains(
+            ethereal_ir
+                .functions
+                .values()
+                .next()
+                .expect("Always exists")
+                .r#type
+                .name()
+        ));
+    }
+
+    const FUNCTION_POINTER_DEPLOY_CODE: &str = r#"[
+        { "name": "PUSH [tag]", "value": "2" },
+        { "name": "PUSH [tag]", "value": "1" },
+        { "name": "PUSH", "value": "0" },
+        { "name": "MSTORE" },
+        { "name": "PUSH", "value": "0" },
+        { "name": "MLOAD" },
+        { "name": "JUMP" },
+        { "name": "tag", "value": "1" },
+        { "name": "JUMPDEST" },
+        { "name": "JUMP" },
+        { "name": "tag", "value": "2" },
+        { "name": "JUMPDEST" },
+        { "name": "STOP" }
+    ]"#;
+
+    #[test]
+    fn ok_function_pointer_dispatch() {
+        let ethereal_ir = ethereal_ir(
+            FUNCTION_POINTER_DEPLOY_CODE,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            dynamic_jump_destinations(&ethereal_ir, compiler_llvm_context::CodeType::Deploy),
+            vec![vec![num::BigUint::from(1u32)]]
+        );
+        assert!(ethereal_ir.dead_blocks().is_empty());
+    }
+
+    #[test]
+    fn ok_function_pointer_dispatch_pre_0_8() {
+        let ethereal_ir = ethereal_ir_with_runtime(
+            semver::Version::new(0, 7, 6),
+            FUNCTION_POINTER_DEPLOY_CODE,
+            STOP_CODE,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            dynamic_jump_destinations(&ethereal_ir, compiler_llvm_context::CodeType::Deploy),
+            vec![vec![num::BigUint::from(1u32)]]
+        );
+        assert!(ethereal_ir.dead_blocks().is_empty());
+    }
+
+    #[test]
+    fn ok_function_pointer_assigned_in_constructor() {
+        let deploy_code = r#"[
+            { "name": "PUSH [tag]", "value": "18446744073709551619" },
+            { "name": "PUSH", "value": "0" },
+            { "name": "SSTORE" },
+            { "name": "STOP" }
+        ]"#;
+        let runtime_code = r#"[
+            { "name": "PUSH [tag]", "value": "2" },
+            { "name": "PUSH", "value": "0" },
+            { "name": "SLOAD" },
+            { "name": "PUSH", "value": "FFFFFFFF" },
+            { "name": "AND" },
+            { "name": "JUMP" },
+            { "name": "tag", "value": "3" },
+            { "name": "JUMPDEST" },
+            { "name": "JUMP" },
+            { "name": "tag", "value": "2" },
+            { "name": "JUMPDEST" },
+            { "name": "STOP" }
+        ]"#;
+
+        let ethereal_ir = ethereal_ir_with_runtime(
+            semver::Version::new(0, 8, 24),
+            deploy_code,
+            runtime_code,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            dynamic_jump_destinations(&ethereal_ir, compiler_llvm_context::CodeType::Runtime),
+            vec![vec![num::BigUint::from(3u32)]]
+        );
+        assert!(ethereal_ir.dead_blocks().is_empty());
+    }
+
+    #[test]
+    fn ok_function_pointer_called_in_constructor() {
+        let deploy_code = r#"[
+            { "name": "PUSH [tag]", "value": "1" },
+            { "name": "PUSH", "value": "100000000" },
+            { "name": "MUL" },
+            { "name": "PUSH [tag]", "value": "18446744073709551619" },
+            { "name": "OR" },
+            { "name": "PUSH", "value": "0" },
+            { "name": "SSTORE" },
+            { "name": "PUSH [tag]", "value": "2" },
+            { "name": "PUSH", "value": "0" },
+            { "name": "SLOAD" },
+            { "name": "PUSH", "value": "20" },
+            { "name": "SHR" },
+            { "name": "JUMP" },
+            { "name": "tag", "value": "1" },
+            { "name": "JUMPDEST" },
+            { "name": "JUMP" },
+            { "name": "tag", "value": "2" },
+            { "name": "JUMPDEST" },
+            { "name": "STOP" }
+        ]"#;
+        let runtime_code = r#"[
+            { "name": "PUSH [tag]", "value": "2" },
+            { "name": "PUSH", "value": "0" },
+            { "name": "SLOAD" },
+            { "name": "PUSH", "value": "FFFFFFFF" },
+            { "name": "AND" },
+            { "name": "JUMP" },
+            { "name": "tag", "value": "3" },
+            { "name": "JUMPDEST" },
+            { "name": "JUMP" },
+            { "name": "tag", "value": "2" },
+            { "name": "JUMPDEST" },
+            { "name": "STOP" }
+        ]"#;
+
+        let ethereal_ir = ethereal_ir_with_runtime(
+            semver::Version::new(0, 8, 24),
+            deploy_code,
+            runtime_code,
+            EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT,
+        )
+        .expect("Always valid");
+
+        let deploy_destinations =
+            dynamic_jump_destinations(&ethereal_ir, compiler_llvm_context::CodeType::Deploy);
+        let runtime_destinations =
+            dynamic_jump_destinations(&ethereal_ir, compiler_llvm_context::CodeType::Runtime);
+        assert_eq!(deploy_destinations, vec![vec![num::BigUint::from(1u32)]]);
+        assert_eq!(runtime_destinations, vec![vec![num::BigUint::from(3u32)]]);
+        assert!(ethereal_ir.dead_blocks().is_empty());
+
+        // The pointer stored by the constructor, as it is combined from the generated code
+        // constants, must match the switch cases of both code parts.
+        let deploy_tag = Function::push_tag_value(&num::BigUint::from(1u32));
+        let runtime_tag =
+            Function::push_tag_value(&"18446744073709551619".parse().expect("Always valid"));
+        let pointer = (deploy_tag << 32u32) | runtime_tag;
+        assert!(deploy_destinations[0].contains(&(&pointer >> 32u32)));
+        assert!(runtime_destinations[0].contains(&(&pointer & num::BigUint::from(u32::MAX))));
+    }
+
+    #[test]
+    fn error_dynamic_jump_destinations_unknown() {
+        let deploy_code = r#"[
+            { "name": "PUSH", "value": "0" },
+            { "name": "MLOAD" },
+            { "name": "JUMP" }
+        ]"#;
+
+        let error = ethereal_ir(deploy_code, EtherealIR::BLOCK_VISITS_DEFAULT_LIMIT)
+            .expect_err("Always invalid")
+            .to_string();
+
+        assert!(error.contains("cannot be compiled, as no function pointer values are found"));
+        assert!(error.contains(Function::DYNAMIC_JUMP_UNKNOWN_ERROR));
+    }
+}
@@ -0,0 +1,77 @@
+//!
+//! The Ethereal IR JSON serialization helpers.
+//!
+
+use std::collections::BTreeMap;
+
+use serde::Serializer;
+
+use crate::evmla::ethereal_ir::function::block::Block;
+use crate::evmla::ethereal_ir::function::Function;
+
+///
+/// Serializes the block key with its textual representation, as the dependency type does not
+/// implement `serde` traits.
+///
+pub fn block_key<S>(
+    key: &compiler_llvm_context::FunctionBlockKey,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(key)
+}
+
+///
+/// Serializes the block keys sorted, so the output does not depend on the hashing order.
+///
+pub fn block_keys<'a, T, S>(keys: &'a T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a T: IntoIterator<Item = &'a compiler_llvm_context::FunctionBlockKey>,
+    S: Serializer,
+{
+    let mut keys: Vec<&compiler_llvm_context::FunctionBlockKey> = keys.into_iter().collect();
+    keys.sort();
+    serializer.collect_seq(keys.into_iter().map(|key| key.to_string()))
+}
+
+///
+/// Serializes the function blocks as a map from the block key to its copies.
+///
+pub fn blocks<S>(
+    blocks: &BTreeMap<compiler_llvm_context::FunctionBlockKey, Vec<Block>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map(blocks.iter().map(|(key, blocks)| (key.to_string(), blocks)))
+}
+
+///
+/// Serializes the recovered functions as a map from the function name to the function.
+///
+pub fn functions<S>(
+    functions: &BTreeMap<(compiler_llvm_context::FunctionBlockKey, usize), Function>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_map(
+        functions
+            .values()
+            .map(|function| (function.r#type.name(), function)),
+    )
+}
+
+///
+/// Serializes the big integer as a decimal string, as JSON numbers cannot hold 256-bit values.
+///
+pub fn big_uint<S>(value: &num::BigUint, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
@@ -0,0 +1,51 @@
+//!
+//! The Ethereal IR statistics.
+//!
+
+use crate::evmla::ethereal_ir::function::Function;
+
+///
+/// The Ethereal IR statistics.
+///
+/// Helps to find the contracts whose blocks are duplicated excessively.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Statistics {
+    /// The number of functions.
+    pub functions: usize,
+    /// The number of distinct blocks.
+    pub blocks: usize,
+    /// The number of block copies produced by different initial stack states.
+    pub duplicates: usize,
+    /// The maximum stack height.
+    pub max_stack_height: usize,
+}
+
+impl Statistics {
+    ///
+    /// Collects the statistics of the functions.
+    ///
+    pub fn new<'a>(functions: impl Iterator<Item = &'a Function>) -> Self {
+        let mut statistics = Self::default();
+        for function in functions {
+            statistics.functions += 1;
+            for blocks in function.blocks.values() {
+                statistics.blocks += 1;
+                statistics.duplicates += blocks.len() - 1;
+            }
+            statistics.max_stack_height =
+                std::cmp::max(statistics.max_stack_height, function.stack_size);
+        }
+        statistics
+    }
+}
+
+impl std::fmt::Display for Statistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "functions: {}, blocks: {}, duplicates: {}, max stack height: {}",
+            self.functions, self.blocks, self.duplicates, self.max_stack_height,
+        )
+    }
+}
@@ -18,6 +18,7 @@ pub use self::solc::combined_json::contract::Contract as SolcCombinedJsonContrac
 pub use self::solc::combined_json::CombinedJson as SolcCombinedJson;
 pub use self::solc::pipeline::Pipeline as SolcPipeline;
 pub use self::solc::standard_json::input::language::Language as SolcStandardJsonInputLanguage;
+pub use self::solc::standard_json::input::settings::evm_version::EVMVersion as SolcStandardJsonInputSettingsEVMVersion;
 pub use self::solc::standard_json::input::settings::metadata::Metadata as SolcStandardJsonInputSettingsMetadata;
 pub use self::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
 pub use self::solc::standard_json::input::settings::selection::file::flag::Flag as SolcStandardJsonInputSettingsSelectionFileFlag;
@@ -29,12 +30,17 @@ pub use self::solc::standard_json::input::Input as SolcStandardJsonInput;
 pub use self::solc::standard_json::output::contract::evm::bytecode::Bytecode as SolcStandardJsonOutputContractEVMBytecode;
 pub use self::solc::standard_json::output::contract::evm::EVM as SolcStandardJsonOutputContractEVM;
 pub use self::solc::standard_json::output::contract::Contract as SolcStandardJsonOutputContract;
+pub use self::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 pub use self::solc::standard_json::output::Output as SolcStandardJsonOutput;
 pub use self::solc::version::Version as SolcVersion;
 pub use self::solc::Compiler as SolcCompiler;
+pub use self::yul::optimizer::Optimizer as YulOptimizer;
+pub use self::yul::parser::statement::expression::function_call::builtin::Builtin as YulBuiltin;
+pub use self::yul::parser::statement::expression::function_call::builtin::BUILTINS as YUL_BUILTINS;
 
 mod tests;
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 ///
@@ -45,6 +51,8 @@ pub fn yul(
     optimizer_settings: compiler_llvm_context::OptimizerSettings,
     is_system_mode: bool,
     include_metadata_hash: bool,
+    emit_yul_ast: bool,
+    yul_optimizer: Option<YulOptimizer>,
     debug_config: Option<compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<Build> {
     let path = match input_files.len() {
@@ -58,16 +66,28 @@ pub fn yul(
 
     eprintln!("WARNING! Yul is not validated as long as we are using the upstream solc compiler that doesn't provide the Yul validation feature.");
 
-    let project = Project::try_from_yul_path(path)?;
+    let mut project = Project::try_from_yul_path(path)?;
+    for msize_usage in project.msize_usages() {
+        eprintln!("{}", msize_usage.to_warning());
+    }
+    if let Some(yul_optimizer) = yul_optimizer {
+        project.optimize_yul(&yul_optimizer, debug_config.as_ref())?;
+    }
+    let yul_asts = if emit_yul_ast {
+        project.yul_asts()?
+    } else {
+        BTreeMap::new()
+    };
 
     let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
-    let build = project.compile_all(
+    let mut build = project.compile_all(
         target_machine,
         optimizer_settings,
         is_system_mode,
         include_metadata_hash,
         debug_config,
     )?;
+    build.set_yul_asts(yul_asts);
 
     Ok(build)
 }
@@ -147,9 +167,14 @@ pub fn standard_output(
     solc: &mut SolcCompiler,
     solc_optimizer_enabled: bool,
     optimizer_settings: compiler_llvm_context::OptimizerSettings,
+    evm_version: Option<SolcStandardJsonInputSettingsEVMVersion>,
     force_evmla: bool,
+    is_strict_evmla: bool,
+    evmla_block_limit: Option<usize>,
     is_system_mode: bool,
     include_metadata_hash: bool,
+    emit_yul_ast: bool,
+    yul_optimizer: Option<YulOptimizer>,
     base_path: Option<String>,
     include_paths: Vec<String>,
     allow_paths: Option<String>,
@@ -157,6 +182,13 @@ pub fn standard_output(
 ) -> anyhow::Result<Build> {
     let solc_version = solc.version()?;
     let solc_pipeline = SolcPipeline::new(&solc_version, force_evmla);
+    if emit_yul_ast && solc_pipeline == SolcPipeline::EVMLA {
+        anyhow::bail!(
+            "The Yul AST output requires the Yul pipeline, which is only available since solc v{}, but found v{}",
+            SolcCompiler::FIRST_YUL_VERSION,
+            solc_version.default,
+        );
+    }
 
     let solc_input = SolcStandardJsonInput::try_from_paths(
         SolcStandardJsonInputLanguage::Solidity,
@@ -166,6 +198,7 @@ pub fn standard_output(
         SolcStandardJsonInputSettingsOptimizer::new(solc_optimizer_enabled, None),
         None,
         solc_version.default >= SolcCompiler::FIRST_YUL_VERSION && !force_evmla,
+        evm_version,
     )?;
     let source_code_files = solc_input
         .sources
@@ -175,45 +208,69 @@ pub fn standard_output(
 
     let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
     let mut solc_output = solc.standard_json(
-        solc_input,
-        solc_pipeline,
-        base_path,
-        include_paths,
-        allow_paths,
+        &solc_input,
+        base_path.clone(),
+        include_paths.clone(),
+        allow_paths.clone(),
     )?;
 
     if let Some(errors) = solc_output.errors.as_deref() {
-        let mut has_errors = false;
-
         for error in errors.iter() {
-            if error.severity.as_str() == "error" {
-                has_errors = true;
+            if solc_pipeline == SolcPipeline::Yul && error.is_code_generation_error() {
+                continue;
             }
 
             eprintln!("{error}");
         }
 
-        if has_errors {
+        if !solc_output.compilation_errors(solc_pipeline).is_empty() {
             anyhow::bail!("Error(s) found. Compilation aborted");
         }
     }
 
-    let project = solc_output.try_to_project(
+    let mut project = solc_output.try_to_project(
         source_code_files,
         libraries,
         solc_pipeline,
+        is_strict_evmla,
+        evmla_block_limit,
         &solc_version.default,
         debug_config.as_ref(),
     )?;
+    for warning in solc_output.msize_warnings(project.msize_usages()) {
+        eprintln!("{warning}");
+    }
+    if let Some(yul_optimizer) = yul_optimizer {
+        project.optimize_yul(&yul_optimizer, debug_config.as_ref())?;
+    }
+    let yul_asts = if emit_yul_ast {
+        project.yul_asts()?
+    } else {
+        BTreeMap::new()
+    };
 
     let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
-    let build = project.compile_all(
+    let mut build = compile_with_evmla_fallback(
+        project,
+        solc,
+        solc_input,
+        solc_pipeline,
+        is_strict_evmla,
+        evmla_block_limit,
+        &solc_version.default,
+        base_path,
+        include_paths,
+        allow_paths,
         target_machine,
         optimizer_settings,
         is_system_mode,
         include_metadata_hash,
         debug_config,
     )?;
+    build.set_yul_asts(yul_asts);
+    for warning in build.warnings() {
+        eprintln!("{warning}");
+    }
 
     Ok(build)
 }
@@ -225,6 +282,8 @@ pub fn standard_output(
 pub fn standard_json(
     solc: &mut SolcCompiler,
     force_evmla: bool,
+    is_strict_evmla: bool,
+    evmla_block_limit: Option<usize>,
     is_system_mode: bool,
     base_path: Option<String>,
     include_paths: Vec<String>,
@@ -258,32 +317,44 @@ pub fn standard_json(
 
     let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
     let mut solc_output = solc.standard_json(
-        solc_input,
-        solc_pipeline,
-        base_path,
-        include_paths,
-        allow_paths,
+        &solc_input,
+        base_path.clone(),
+        include_paths.clone(),
+        allow_paths.clone(),
     )?;
 
-    if let Some(errors) = solc_output.errors.as_deref() {
-        for error in errors.iter() {
-            if error.severity.as_str() == "error" {
-                serde_json::to_writer(std::io::stdout(), &solc_output)?;
-                std::process::exit(0);
-            }
-        }
+    if !solc_output.compilation_errors(solc_pipeline).is_empty() {
+        serde_json::to_writer(std::io::stdout(), &solc_output)?;
+        std::process::exit(0);
     }
 
     let project = solc_output.try_to_project(
         source_code_files,
         libraries,
         solc_pipeline,
+        is_strict_evmla,
+        evmla_block_limit,
         &solc_version.default,
         debug_config.as_ref(),
     )?;
+    let msize_warnings = solc_output.msize_warnings(project.msize_usages());
+    solc_output
+        .errors
+        .get_or_insert_with(Vec::new)
+        .extend(msize_warnings);
 
     let target_machine = compiler_llvm_context::TargetMachine::new(&optimizer_settings)?;
-    let build = project.compile_all(
+    let build = compile_with_evmla_fallback(
+        project,
+        solc,
+        solc_input,
+        solc_pipeline,
+        is_strict_evmla,
+        evmla_block_limit,
+        &solc_version.default,
+        base_path,
+        include_paths,
+        allow_paths,
         target_machine,
         optimizer_settings,
         is_system_mode,
@@ -291,6 +362,7 @@ pub fn standard_json(
         debug_config,
     )?;
 
+    solc_output.remove_code_generation_errors();
     build.write_to_standard_json(&mut solc_output, &solc_version, &zksolc_version)?;
     serde_json::to_writer(std::io::stdout(), &solc_output)?;
     std::process::exit(0);
@@ -307,9 +379,13 @@ pub fn combined_json(
     solc: &mut SolcCompiler,
     solc_optimizer_enabled: bool,
     optimizer_settings: compiler_llvm_context::OptimizerSettings,
+    evm_version: Option<SolcStandardJsonInputSettingsEVMVersion>,
     force_evmla: bool,
+    is_strict_evmla: bool,
+    evmla_block_limit: Option<usize>,
     is_system_mode: bool,
     include_metadata_hash: bool,
+    yul_optimizer: Option<YulOptimizer>,
     base_path: Option<String>,
     include_paths: Vec<String>,
     allow_paths: Option<String>,
@@ -323,9 +399,14 @@ pub fn combined_json(
         solc,
         solc_optimizer_enabled,
         optimizer_settings,
+        evm_version,
         force_evmla,
+        is_strict_evmla,
+        evmla_block_limit,
         is_system_mode,
         include_metadata_hash,
+        false,
+        yul_optimizer,
         base_path,
         include_paths,
         allow_paths,
@@ -334,7 +415,7 @@ pub fn combined_json(
 
     let zksolc_version = semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid");
 
-    let mut combined_json = solc.combined_json(input_files, format.as_str())?;
+    let mut combined_json = solc.combined_json(input_files, format.as_str(), evm_version)?;
     build.write_to_combined_json(&mut combined_json, &zksolc_version)?;
 
     match output_directory {
@@ -352,3 +433,94 @@ pub fn combined_json(
     }
     std::process::exit(0);
 }
+
+///
+/// Compiles the project, recompiling the contracts the Yul pipeline has failed for with the
+/// EVM legacy assembly pipeline.
+///
+/// The contracts built successfully are kept as they are, and are only used as dependencies.
+/// Each recompiled contract gets a warning with the original Yul pipeline error.
+///
+#[allow(clippy::too_many_arguments)]
+fn compile_with_evmla_fallback(
+    project: Project,
+    solc: &SolcCompiler,
+    mut solc_input: SolcStandardJsonInput,
+    solc_pipeline: SolcPipeline,
+    is_strict_evmla: bool,
+    evmla_block_limit: Option<usize>,
+    solc_version: &semver::Version,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    target_machine: compiler_llvm_context::TargetMachine,
+    optimizer_settings: compiler_llvm_context::OptimizerSettings,
+    is_system_mode: bool,
+    include_metadata_hash: bool,
+    debug_config: Option<compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<Build> {
+    let (build, errors) = project.try_compile_all(
+        target_machine.clone(),
+        optimizer_settings.clone(),
+        is_system_mode,
+        include_metadata_hash,
+        debug_config.clone(),
+    )?;
+    if errors.is_empty() {
+        return Ok(build);
+    }
+    if let SolcPipeline::EVMLA = solc_pipeline {
+        return Err(errors.into_values().next().expect("Always exists"));
+    }
+
+    solc_input.switch_to_evmla();
+    let source_code_files = solc_input
+        .sources
+        .iter()
+        .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+        .collect();
+    let libraries = solc_input.settings.libraries.clone().unwrap_or_default();
+    let mut solc_output = solc.standard_json(&solc_input, base_path, include_paths, allow_paths)?;
+    if let Some(errors) = solc_output.errors.as_deref() {
+        let errors: Vec<String> = errors
+            .iter()
+            .filter(|error| error.severity.as_str() == "error")
+            .map(|error| error.to_string())
+            .collect();
+        if !errors.is_empty() {
+            anyhow::bail!(
+                "The EVM legacy assembly pipeline fallback error(s):\n{}",
+                errors.join("\n")
+            );
+        }
+    }
+
+    let mut project = solc_output.try_to_project(
+        source_code_files,
+        libraries,
+        SolcPipeline::EVMLA,
+        is_strict_evmla,
+        evmla_block_limit,
+        solc_version,
+        debug_config.as_ref(),
+    )?;
+    project.set_builds(build);
+    let mut build = project.compile_all(
+        target_machine,
+        optimizer_settings,
+        is_system_mode,
+        include_metadata_hash,
+        debug_config,
+    )?;
+    for (path, error) in errors.into_iter() {
+        if let Some(contract) = build.contracts.get_mut(path.as_str()) {
+            contract.warnings.insert(
+                0,
+                SolcStandardJsonOutputError::new_warning(format!(
+                    "The contract `{path}` is recompiled with the EVM legacy assembly pipeline due to the Yul pipeline error: {error}"
+                )),
+            );
+        }
+    }
+    Ok(build)
+}
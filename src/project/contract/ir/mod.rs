@@ -7,7 +7,11 @@ pub mod llvm_ir;
 pub mod yul;
 pub mod zkasm;
 
+use compiler_llvm_context::WriteLLVM;
+
 use crate::evmla::assembly::Assembly;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 use crate::yul::parser::statement::object::Object;
 
 use self::evmla::EVMLA;
@@ -60,6 +64,36 @@ impl IR {
     pub fn new_zkasm(path: String, source: String) -> Self {
         Self::ZKASM(ZKASM::new(path, source))
     }
+
+    ///
+    /// Returns the `solc` pipeline the IR has been produced with, if any.
+    ///
+    pub fn solc_pipeline(&self) -> Option<SolcPipeline> {
+        match self {
+            Self::Yul(_) => Some(SolcPipeline::Yul),
+            Self::EVMLA(_) => Some(SolcPipeline::EVMLA),
+            Self::LLVMIR(_) | Self::ZKASM(_) => None,
+        }
+    }
+
+    ///
+    /// Translates the IR into LLVM IR, returning the warnings found during the translation.
+    ///
+    /// Only the EVM legacy assembly translation reports warnings, which are the dead blocks
+    /// found by the Ethereal IR analysis.
+    ///
+    pub fn into_llvm_with_warnings<D>(
+        self,
+        context: &mut compiler_llvm_context::Context<D>,
+    ) -> anyhow::Result<Vec<SolcStandardJsonOutputError>>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        match self {
+            Self::EVMLA(inner) => inner.assembly.into_llvm_with_warnings(context),
+            ir => ir.into_llvm(context).map(|()| vec![]),
+        }
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for IR
@@ -98,6 +98,7 @@ impl Contract {
         let llvm = inkwell::context::Context::create();
         let optimizer = compiler_llvm_context::Optimizer::new(target_machine, optimizer_settings);
 
+        let solc_pipeline = self.ir.solc_pipeline();
         let metadata = Metadata::new(
             self.metadata.take(),
             semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("Always valid"),
@@ -136,6 +137,7 @@ impl Contract {
                     identifier,
                     build,
                     metadata_json,
+                    solc_pipeline,
                 ));
             }
             _ => llvm.create_module(self.path.as_str()),
@@ -172,13 +174,16 @@ impl Contract {
                 error
             )
         })?;
-        self.ir.into_llvm(&mut context).map_err(|error| {
-            anyhow::anyhow!(
-                "The contract `{}` LLVM IR generator definition pass error: {}",
-                self.path,
-                error
-            )
-        })?;
+        let warnings = self
+            .ir
+            .into_llvm_with_warnings(&mut context)
+            .map_err(|error| {
+                anyhow::anyhow!(
+                    "The contract `{}` LLVM IR generator definition pass error: {}",
+                    self.path,
+                    error
+                )
+            })?;
 
         let mut build = context.build(self.path.as_str(), metadata_hash)?;
 
@@ -208,12 +213,10 @@ impl Contract {
             build.factory_dependencies.insert(hash, full_path);
         }
 
-        Ok(ContractBuild::new(
-            self.path,
-            identifier,
-            build,
-            metadata_json,
-        ))
+        let mut contract_build =
+            ContractBuild::new(self.path, identifier, build, metadata_json, solc_pipeline);
+        contract_build.warnings = warnings;
+        Ok(contract_build)
     }
 }
 
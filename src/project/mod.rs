@@ -3,8 +3,11 @@
 //!
 
 pub mod contract;
+pub mod msize_usage;
+pub mod unsupported_instruction;
 
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::RwLock;
@@ -18,10 +21,13 @@ use crate::project::contract::ir::IR;
 use crate::project::contract::state::State;
 use crate::solc::Compiler as SolcCompiler;
 use crate::yul::lexer::Lexer;
+use crate::yul::optimizer::Optimizer as YulOptimizer;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::state::State as ContractState;
 use self::contract::Contract;
+use self::msize_usage::MSizeUsage;
+use self::unsupported_instruction::UnsupportedInstruction;
 
 ///
 /// The processes input data.
@@ -140,7 +146,8 @@ impl Project {
     ///
     /// Compiles all contracts, returning their build artifacts.
     ///
-    #[allow(clippy::needless_collect)]
+    /// Fails with the first contract error, if any.
+    ///
     pub fn compile_all(
         self,
         target_machine: compiler_llvm_context::TargetMachine,
@@ -149,6 +156,59 @@ impl Project {
         include_metadata_hash: bool,
         debug_config: Option<compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Build> {
+        let (build, errors) = self.try_compile_all(
+            target_machine,
+            optimizer_settings,
+            is_system_mode,
+            include_metadata_hash,
+            debug_config,
+        )?;
+        match errors.into_values().next() {
+            Some(error) => Err(error),
+            None => Ok(build),
+        }
+    }
+
+    ///
+    /// Compiles all contracts, returning the build artifacts of the successful ones, and the
+    /// errors of the failed ones.
+    ///
+    /// The failed contracts may be compiled again with another pipeline.
+    ///
+    /// The contracts with unsupported instructions fail before the LLVM IR generation, with all
+    /// their unsupported instructions reported at once.
+    ///
+    #[allow(clippy::needless_collect)]
+    pub fn try_compile_all(
+        mut self,
+        target_machine: compiler_llvm_context::TargetMachine,
+        optimizer_settings: compiler_llvm_context::OptimizerSettings,
+        is_system_mode: bool,
+        include_metadata_hash: bool,
+        debug_config: Option<compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<(Build, BTreeMap<String, anyhow::Error>)> {
+        let mut unsupported_instructions: BTreeMap<String, Vec<UnsupportedInstruction>> =
+            BTreeMap::new();
+        for instruction in self.unsupported_instructions().into_iter() {
+            unsupported_instructions
+                .entry(instruction.path.clone())
+                .or_default()
+                .push(instruction);
+        }
+        for (path, instructions) in unsupported_instructions.into_iter() {
+            let error = anyhow::anyhow!(
+                "Found {} unsupported instruction(s):\n{}",
+                instructions.len(),
+                instructions
+                    .iter()
+                    .map(|instruction| instruction.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            );
+            self.contract_states
+                .insert(path, ContractState::Error(error));
+        }
+
         let project = Arc::new(RwLock::new(self));
 
         let contract_paths: Vec<String> = project
@@ -178,16 +238,64 @@ impl Project {
             .into_inner()
             .expect("Sync");
         let mut build = Build::default();
+        let mut errors = BTreeMap::new();
         for (path, state) in project.contract_states.into_iter() {
             match state {
                 State::Build(contract_build) => {
                     build.contracts.insert(path, contract_build);
                 }
-                State::Error(error) => return Err(error),
+                State::Error(error) => {
+                    errors.insert(path, error);
+                }
                 _ => panic!("Contract `{path}` must be built at this point"),
             }
         }
-        Ok(build)
+        Ok((build, errors))
+    }
+
+    ///
+    /// Replaces the contracts with their existing build artifacts, e.g. from another pipeline.
+    ///
+    /// Such contracts are not compiled again, but can still be used as dependencies.
+    ///
+    pub fn set_builds(&mut self, build: Build) {
+        for (path, contract_build) in build.contracts.into_iter() {
+            self.contract_states
+                .insert(path, ContractState::Build(contract_build));
+        }
+    }
+
+    ///
+    /// Finds the unsupported instructions in all contracts, so they are reported at once before
+    /// the LLVM IR generation.
+    ///
+    pub fn unsupported_instructions(&self) -> Vec<UnsupportedInstruction> {
+        self.contract_states
+            .iter()
+            .filter_map(|(path, state)| match state {
+                ContractState::Source(contract) => {
+                    Some(UnsupportedInstruction::scan(path.as_str(), &contract.ir))
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    ///
+    /// Returns all `msize` usages in the source contracts IR.
+    ///
+    pub fn msize_usages(&self) -> Vec<MSizeUsage> {
+        self.contract_states
+            .iter()
+            .filter_map(|(path, state)| match state {
+                ContractState::Source(contract) => {
+                    Some(MSizeUsage::scan(path.as_str(), &contract.ir))
+                }
+                _ => None,
+            })
+            .flatten()
+            .collect()
     }
 
     ///
@@ -196,24 +304,14 @@ impl Project {
     pub fn try_from_yul_path(path: &Path) -> anyhow::Result<Self> {
         let source_code = std::fs::read_to_string(path)
             .map_err(|error| anyhow::anyhow!("Yul file {:?} reading error: {}", path, error))?;
-        let source_hash = sha3::Keccak256::digest(source_code.as_bytes()).into();
 
         let mut lexer = Lexer::new(source_code.clone());
         let path = path.to_string_lossy().to_string();
         let object = Object::parse(&mut lexer, None)
             .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error,))?;
 
-        let mut project_contracts = BTreeMap::new();
-        project_contracts.insert(
-            path.clone(),
-            Contract::new(
-                path,
-                source_hash,
-                SolcCompiler::LAST_SUPPORTED_VERSION,
-                IR::new_yul(source_code, object),
-                None,
-            ),
-        );
+        let project_contracts =
+            Self::yul_object_contracts(path.as_str(), source_code.as_str(), object)?;
 
         Ok(Self::new(
             SolcCompiler::LAST_SUPPORTED_VERSION,
@@ -228,23 +326,11 @@ impl Project {
     /// Only for integration testing purposes.
     ///
     pub fn try_from_yul_string(path: &str, source_code: &str) -> anyhow::Result<Self> {
-        let source_hash = sha3::Keccak256::digest(source_code.as_bytes()).into();
-
         let mut lexer = Lexer::new(source_code.to_owned());
         let object = Object::parse(&mut lexer, None)
             .map_err(|error| anyhow::anyhow!("Yul object `{}` parsing error: {}", path, error))?;
 
-        let mut project_contracts = BTreeMap::new();
-        project_contracts.insert(
-            path.to_owned(),
-            Contract::new(
-                path.to_owned(),
-                source_hash,
-                SolcCompiler::LAST_SUPPORTED_VERSION,
-                IR::new_yul(source_code.to_owned(), object),
-                None,
-            ),
-        );
+        let project_contracts = Self::yul_object_contracts(path, source_code, object)?;
 
         Ok(Self::new(
             SolcCompiler::LAST_SUPPORTED_VERSION,
@@ -336,6 +422,95 @@ impl Clone for Project {
             libraries: self.libraries.clone(),
         }
     }
+
+    ///
+    /// Serializes the Yul ASTs of the contracts which have not been compiled yet.
+    ///
+    pub fn yul_asts(&self) -> anyhow::Result<BTreeMap<String, serde_json::Value>> {
+        let mut yul_asts = BTreeMap::new();
+        for (path, state) in self.contract_states.iter() {
+            if let ContractState::Source(Contract {
+                ir: IR::Yul(ref yul),
+                ..
+            }) = state
+            {
+                let yul_ast = serde_json::to_value(&yul.object).map_err(|error| {
+                    anyhow::anyhow!("Contract `{}` Yul AST serialization error: {}", path, error)
+                })?;
+                yul_asts.insert(path.to_owned(), yul_ast);
+            }
+        }
+        Ok(yul_asts)
+    }
+
+    ///
+    /// Runs the Yul pre-optimizer over the Yul contracts.
+    ///
+    pub fn optimize_yul(
+        &mut self,
+        optimizer: &YulOptimizer,
+        debug_config: Option<&compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<()> {
+        for (path, state) in self.contract_states.iter_mut() {
+            if let ContractState::Source(Contract {
+                ir: IR::Yul(ref mut yul),
+                ..
+            }) = state
+            {
+                optimizer.optimize(&mut yul.object, path.as_str(), debug_config)?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Splits the Yul object tree into contracts.
+    ///
+    /// Each nested child object is compiled as a separate contract, which is addressed by its
+    /// identifier via the factory dependencies. Identical duplicate objects are only compiled
+    /// once, whereas different objects with the same identifier are reported as an error.
+    ///
+    fn yul_object_contracts(
+        path: &str,
+        source_code: &str,
+        object: Object,
+    ) -> anyhow::Result<BTreeMap<String, Contract>> {
+        let source_hash = sha3::Keccak256::digest(source_code.as_bytes()).into();
+
+        let mut identifiers = HashMap::new();
+        identifiers.insert(object.identifier.clone(), object.to_string());
+
+        let mut contracts = BTreeMap::new();
+        let mut objects = vec![(path.to_owned(), object)];
+        while let Some((contract_path, mut object)) = objects.pop() {
+            for child in object.take_children().into_iter() {
+                let printed = child.to_string();
+                match identifiers.get(child.identifier.as_str()) {
+                    Some(existing) if existing == &printed => continue,
+                    Some(_) => anyhow::bail!(
+                        "{} Yul object `{}` is already declared with a different body",
+                        child.location,
+                        child.identifier,
+                    ),
+                    None => {}
+                }
+                identifiers.insert(child.identifier.clone(), printed);
+                objects.push((format!("{path}:{}", child.identifier), child));
+            }
+
+            contracts.insert(
+                contract_path.clone(),
+                Contract::new(
+                    contract_path,
+                    source_hash,
+                    SolcCompiler::LAST_SUPPORTED_VERSION,
+                    IR::new_yul(source_code.to_owned(), object),
+                    None,
+                ),
+            );
+        }
+        Ok(contracts)
+    }
 }
 
 impl compiler_llvm_context::Dependency for Project {
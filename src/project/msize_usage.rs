@@ -0,0 +1,129 @@
+//!
+//! The `msize` instruction usage analysis.
+//!
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::assembly::Assembly;
+use crate::project::contract::ir::IR;
+use crate::solc::standard_json::output::error::source_location::SourceLocation;
+use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::statement::expression::function_call::name::Name as YulName;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor::walk_function_call;
+use crate::yul::parser::visitor::Visitor;
+
+///
+/// The `msize` instruction usage.
+///
+/// Used where the Solidity AST is not available, e.g. for Yul sources, so the usage can only be
+/// located in the contract IR.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MSizeUsage {
+    /// The full contract path.
+    pub path: String,
+    /// The location in the contract IR.
+    pub location: String,
+    /// The location in the Solidity source code, if the IR maps the usage to it.
+    pub source_location: Option<SourceLocation>,
+}
+
+impl MSizeUsage {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, location: String, source_location: Option<SourceLocation>) -> Self {
+        Self {
+            path,
+            location,
+            source_location,
+        }
+    }
+
+    ///
+    /// Finds all `msize` usages in the contract IR.
+    ///
+    /// The dependencies are not scanned, as they are separate contracts of the project.
+    ///
+    pub fn scan(path: &str, ir: &IR) -> Vec<Self> {
+        match ir {
+            IR::Yul(yul) => {
+                let mut scanner = YulScanner::default();
+                scanner.visit_object(&yul.object);
+                scanner
+                    .found
+                    .into_iter()
+                    .map(|location| Self::new(path.to_owned(), format!("Yul {location}"), None))
+                    .collect()
+            }
+            IR::EVMLA(evmla) => Self::scan_evmla(path, &evmla.assembly),
+            IR::LLVMIR(_) | IR::ZKASM(_) => vec![],
+        }
+    }
+
+    ///
+    /// Converts the usage into the `solc`-compatible warning with the usage guidance.
+    ///
+    pub fn to_warning(&self) -> SolcStandardJsonOutputError {
+        let mut warning = SolcStandardJsonOutputError::message_msize(None);
+        warning.push_contract_path(format!("{} ({})", self.path, self.location).as_str());
+        warning
+    }
+
+    ///
+    /// Finds all `MSIZE` instructions in the deploy and runtime EVM legacy assembly code.
+    ///
+    fn scan_evmla(path: &str, assembly: &Assembly) -> Vec<Self> {
+        let runtime_code = assembly
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .and_then(|data| data.get_assembly())
+            .and_then(|assembly| assembly.code.as_ref());
+
+        let mut found = Vec::new();
+        for (code_type, code) in [
+            ("deploy", assembly.code.as_ref()),
+            ("runtime", runtime_code),
+        ] {
+            for (index, instruction) in code.into_iter().flatten().enumerate() {
+                if instruction.name == InstructionName::MSIZE {
+                    let mut location = format!("{code_type} code instruction #{index}");
+                    let source_location = instruction.source_location(&assembly.source_paths);
+                    if let Some(source_location) = source_location.as_ref() {
+                        location = format!("{location}, {source_location}");
+                    }
+                    found.push(Self::new(path.to_owned(), location, source_location));
+                }
+            }
+        }
+        found
+    }
+}
+
+///
+/// Collects the `msize` calls from the Yul object and its runtime code object.
+///
+#[derive(Debug, Default)]
+struct YulScanner {
+    /// The `msize` call locations.
+    found: Vec<Location>,
+}
+
+impl Visitor for YulScanner {
+    fn visit_object(&mut self, object: &Object) {
+        self.visit_code(&object.code);
+        if let Some(inner_object) = object.inner_object.as_ref() {
+            self.visit_object(inner_object);
+        }
+    }
+
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        if function_call.name == YulName::MSize {
+            self.found.push(function_call.location);
+        }
+        walk_function_call(self, function_call);
+    }
+}
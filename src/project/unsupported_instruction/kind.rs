@@ -0,0 +1,101 @@
+//!
+//! The unsupported instruction kind.
+//!
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::yul::parser::statement::expression::function_call::name::Name as YulName;
+
+///
+/// The unsupported instruction kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Kind {
+    /// The `PC` instruction.
+    Pc,
+    /// The `CALLCODE` instruction.
+    CallCode,
+    /// The `EXTCODECOPY` instruction.
+    ExtCodeCopy,
+    /// The `SELFDESTRUCT` instruction.
+    SelfDestruct,
+    /// The `TLOAD` instruction.
+    TLoad,
+    /// The `TSTORE` instruction.
+    TStore,
+    /// The `BLOBHASH` instruction.
+    BlobHash,
+    /// The `BLOBBASEFEE` instruction.
+    BlobBaseFee,
+}
+
+impl Kind {
+    ///
+    /// Returns the kind of the Yul builtin if it is unsupported.
+    ///
+    pub fn from_yul(name: &YulName) -> Option<Self> {
+        match name {
+            YulName::Pc => Some(Self::Pc),
+            YulName::CallCode => Some(Self::CallCode),
+            YulName::ExtCodeCopy => Some(Self::ExtCodeCopy),
+            YulName::SelfDestruct => Some(Self::SelfDestruct),
+            YulName::TLoad => Some(Self::TLoad),
+            YulName::TStore => Some(Self::TStore),
+            YulName::BlobHash => Some(Self::BlobHash),
+            YulName::BlobBaseFee => Some(Self::BlobBaseFee),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the kind of the EVM legacy assembly instruction if it is unsupported.
+    ///
+    pub fn from_evmla(name: InstructionName) -> Option<Self> {
+        match name {
+            InstructionName::PC => Some(Self::Pc),
+            InstructionName::CALLCODE => Some(Self::CallCode),
+            InstructionName::EXTCODECOPY => Some(Self::ExtCodeCopy),
+            InstructionName::SELFDESTRUCT => Some(Self::SelfDestruct),
+            InstructionName::TLOAD => Some(Self::TLoad),
+            InstructionName::TSTORE => Some(Self::TStore),
+            InstructionName::BLOBHASH => Some(Self::BlobHash),
+            InstructionName::BLOBBASEFEE => Some(Self::BlobBaseFee),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the EVM instruction name.
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Pc => "PC",
+            Self::CallCode => "CALLCODE",
+            Self::ExtCodeCopy => "EXTCODECOPY",
+            Self::SelfDestruct => "SELFDESTRUCT",
+            Self::TLoad => "TLOAD",
+            Self::TStore => "TSTORE",
+            Self::BlobHash => "BLOBHASH",
+            Self::BlobBaseFee => "BLOBBASEFEE",
+        }
+    }
+
+    ///
+    /// Returns the recommended zkSync alternative.
+    ///
+    pub fn alternative(&self) -> &'static str {
+        match self {
+            Self::Pc => "The program counter is not observable on zkEVM, so the code must not depend on it.",
+            Self::CallCode => "Use `delegatecall` instead. Unlike `callcode`, it keeps `msg.sender` and `msg.value` of the current call, so the callee must not rely on seeing the caller contract as the sender.",
+            Self::ExtCodeCopy => "Contract bytecode is not accessible on zkEVM. Use `extcodehash` to verify the code of a contract.",
+            Self::SelfDestruct => "Contracts cannot be destroyed on zkEVM. Transfer the balance with a `call` and disable the contract with a storage flag instead.",
+            Self::TLoad | Self::TStore => "Transient storage is not available on zkEVM yet. Use `sload` and `sstore`, resetting the value before the call returns, e.g. for reentrancy locks.",
+            Self::BlobHash | Self::BlobBaseFee => "There are no blob-carrying transactions on zkEVM, so the code must not depend on them.",
+        }
+    }
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,135 @@
+//!
+//! The unsupported instruction analysis.
+//!
+
+pub mod kind;
+
+use crate::evmla::assembly::Assembly;
+use crate::project::contract::ir::IR;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::visitor::walk_function_call;
+use crate::yul::parser::visitor::Visitor;
+
+use self::kind::Kind;
+
+///
+/// The unsupported instruction occurrence.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedInstruction {
+    /// The full contract path.
+    pub path: String,
+    /// The instruction kind.
+    pub kind: Kind,
+    /// The location in the contract IR.
+    pub location: String,
+}
+
+impl UnsupportedInstruction {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: String, kind: Kind, location: String) -> Self {
+        Self {
+            path,
+            kind,
+            location,
+        }
+    }
+
+    ///
+    /// Finds all unsupported instructions in the contract IR.
+    ///
+    /// The dependencies are not scanned, as they are separate contracts of the project.
+    ///
+    pub fn scan(path: &str, ir: &IR) -> Vec<Self> {
+        match ir {
+            IR::Yul(yul) => {
+                let mut scanner = YulScanner::default();
+                scanner.visit_object(&yul.object);
+                scanner
+                    .found
+                    .into_iter()
+                    .map(|(location, kind)| {
+                        Self::new(path.to_owned(), kind, format!("Yul {location}"))
+                    })
+                    .collect()
+            }
+            IR::EVMLA(evmla) => Self::scan_evmla(path, &evmla.assembly),
+            IR::LLVMIR(_) | IR::ZKASM(_) => vec![],
+        }
+    }
+
+    ///
+    /// Finds all unsupported instructions in the deploy and runtime EVM legacy assembly code.
+    ///
+    /// The source code location is appended to the instruction index if it is available.
+    ///
+    fn scan_evmla(path: &str, assembly: &Assembly) -> Vec<Self> {
+        let runtime_code = assembly
+            .data
+            .as_ref()
+            .and_then(|data| data.get("0"))
+            .and_then(|data| data.get_assembly())
+            .and_then(|assembly| assembly.code.as_ref());
+
+        let mut found = Vec::new();
+        for (code_type, code) in [
+            ("deploy", assembly.code.as_ref()),
+            ("runtime", runtime_code),
+        ] {
+            for (index, instruction) in code.into_iter().flatten().enumerate() {
+                if let Some(kind) = Kind::from_evmla(instruction.name) {
+                    let mut location = format!("{code_type} code instruction #{index}");
+                    if let Some(source_location) =
+                        instruction.source_location(&assembly.source_paths)
+                    {
+                        location = format!("{location}, {source_location}");
+                    }
+                    found.push(Self::new(path.to_owned(), kind, location));
+                }
+            }
+        }
+        found
+    }
+}
+
+impl std::fmt::Display for UnsupportedInstruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}): The `{}` instruction is not supported. {}",
+            self.path,
+            self.location,
+            self.kind,
+            self.kind.alternative()
+        )
+    }
+}
+
+///
+/// Collects the unsupported builtin calls from the Yul object and its runtime code object.
+///
+#[derive(Debug, Default)]
+struct YulScanner {
+    /// The unsupported builtin calls.
+    found: Vec<(Location, Kind)>,
+}
+
+impl Visitor for YulScanner {
+    fn visit_object(&mut self, object: &Object) {
+        self.visit_code(&object.code);
+        if let Some(inner_object) = object.inner_object.as_ref() {
+            self.visit_object(inner_object);
+        }
+    }
+
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        if let Some(kind) = Kind::from_yul(&function_call.name) {
+            self.found.push((function_call.location, kind));
+        }
+        walk_function_call(self, function_call);
+    }
+}
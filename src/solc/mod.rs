@@ -11,7 +11,7 @@ use std::io::Write;
 use std::path::PathBuf;
 
 use self::combined_json::CombinedJson;
-use self::pipeline::Pipeline;
+use self::standard_json::input::settings::evm_version::EVMVersion;
 use self::standard_json::input::Input as StandardJsonInput;
 use self::standard_json::output::Output as StandardJsonOutput;
 use self::version::Version;
@@ -40,7 +40,7 @@ impl Compiler {
     pub const FIRST_CLI_VIA_IR_VERSION: semver::Version = semver::Version::new(0, 8, 13);
 
     /// The last supported version of `solc`.
-    pub const LAST_SUPPORTED_VERSION: semver::Version = semver::Version::new(0, 8, 20);
+    pub const LAST_SUPPORTED_VERSION: semver::Version = semver::Version::new(0, 8, 24);
 
     ///
     /// A shortcut constructor.
@@ -60,8 +60,7 @@ impl Compiler {
     ///
     pub fn standard_json(
         &self,
-        input: StandardJsonInput,
-        pipeline: Pipeline,
+        input: &StandardJsonInput,
         base_path: Option<String>,
         include_paths: Vec<String>,
         allow_paths: Option<String>,
@@ -84,7 +83,7 @@ impl Compiler {
             command.arg(allow_paths);
         }
 
-        let input_json = serde_json::to_vec(&input).expect("Always valid");
+        let input_json = serde_json::to_vec(input).expect("Always valid");
 
         let process = command.spawn().map_err(|error| {
             anyhow::anyhow!("{} subprocess spawning error: {:?}", self.executable, error)
@@ -122,7 +121,7 @@ impl Compiler {
                         ),
                 )
             })?;
-        output.preprocess_ast(pipeline)?;
+        output.preprocess_ast()?;
 
         Ok(output)
     }
@@ -134,6 +133,7 @@ impl Compiler {
         &mut self,
         paths: &[PathBuf],
         combined_json_argument: &str,
+        evm_version: Option<EVMVersion>,
     ) -> anyhow::Result<CombinedJson> {
         let mut command = std::process::Command::new(self.executable.as_str());
         command.args(paths);
@@ -144,6 +144,10 @@ impl Compiler {
         } else if version >= Self::FIRST_YUL_VERSION {
             command.arg("--experimental-via-ir");
         }
+        if let Some(evm_version) = evm_version {
+            command.arg("--evm-version");
+            command.arg(evm_version.to_string());
+        }
 
         let mut combined_json_flags = Vec::new();
         let mut combined_json_fake_flag_pushed = false;
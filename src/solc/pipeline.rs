@@ -2,13 +2,16 @@
 //! The Solidity compiler pipeline type.
 //!
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::solc::version::Version as SolcVersion;
 use crate::solc::Compiler as SolcCompiler;
 
 ///
 /// The Solidity compiler pipeline type.
 ///
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum Pipeline {
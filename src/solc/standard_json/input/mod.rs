@@ -15,6 +15,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::evm_version::EVMVersion as SolcStandardJsonInputSettingsEVMVersion;
 use crate::solc::standard_json::input::settings::metadata::Metadata as SolcStandardJsonInputSettingsMetadata;
 use crate::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
 use crate::solc::standard_json::input::settings::selection::Selection as SolcStandardJsonInputSettingsSelection;
@@ -52,6 +53,19 @@ impl Input {
         Ok(input)
     }
 
+    ///
+    /// Switches the input to the EVM legacy assembly pipeline.
+    ///
+    /// Used to recompile the contracts the Yul pipeline has failed for.
+    ///
+    pub fn switch_to_evmla(&mut self) {
+        self.settings
+            .output_selection
+            .get_or_insert_with(SolcStandardJsonInputSettingsSelection::default)
+            .extend_with_required(SolcPipeline::EVMLA);
+        self.settings.via_ir = None;
+    }
+
     ///
     /// A shortcut constructor from paths.
     ///
@@ -63,6 +77,7 @@ impl Input {
         optimizer: SolcStandardJsonInputSettingsOptimizer,
         metadata: Option<SolcStandardJsonInputSettingsMetadata>,
         via_ir: bool,
+        evm_version: Option<SolcStandardJsonInputSettingsEVMVersion>,
     ) -> anyhow::Result<Self> {
         let sources = paths
             .into_par_iter()
@@ -79,7 +94,14 @@ impl Input {
         Ok(Self {
             language,
             sources,
-            settings: Settings::new(libraries, output_selection, optimizer, metadata, via_ir),
+            settings: Settings::new(
+                libraries,
+                output_selection,
+                optimizer,
+                metadata,
+                via_ir,
+                evm_version,
+            ),
         })
     }
 
@@ -95,6 +117,7 @@ impl Input {
         optimizer: SolcStandardJsonInputSettingsOptimizer,
         metadata: Option<SolcStandardJsonInputSettingsMetadata>,
         via_ir: bool,
+        evm_version: Option<SolcStandardJsonInputSettingsEVMVersion>,
     ) -> anyhow::Result<Self> {
         let sources = sources
             .into_par_iter()
@@ -104,7 +127,14 @@ impl Input {
         Ok(Self {
             language: Language::Solidity,
             sources,
-            settings: Settings::new(libraries, output_selection, optimizer, metadata, via_ir),
+            settings: Settings::new(
+                libraries,
+                output_selection,
+                optimizer,
+                metadata,
+                via_ir,
+                evm_version,
+            ),
         })
     }
 }
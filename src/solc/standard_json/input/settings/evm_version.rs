@@ -0,0 +1,108 @@
+//!
+//! The `solc --standard-json` input settings EVM version.
+//!
+
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The `solc --standard-json` input settings EVM version.
+///
+/// Defines the EVM target of the `solc` output, e.g. whether the `PUSH0` instruction may appear
+/// in the EVM legacy assembly.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum EVMVersion {
+    /// The eponymous EVM version.
+    Homestead,
+    /// The eponymous EVM version.
+    TangerineWhistle,
+    /// The eponymous EVM version.
+    SpuriousDragon,
+    /// The eponymous EVM version.
+    Byzantium,
+    /// The eponymous EVM version.
+    Constantinople,
+    /// The eponymous EVM version.
+    Petersburg,
+    /// The eponymous EVM version.
+    Istanbul,
+    /// The eponymous EVM version.
+    Berlin,
+    /// The eponymous EVM version.
+    London,
+    /// The eponymous EVM version.
+    Paris,
+    /// The eponymous EVM version.
+    Shanghai,
+    /// The eponymous EVM version.
+    Cancun,
+}
+
+impl EVMVersion {
+    /// All the EVM versions, from the oldest to the newest.
+    pub const ALL: [Self; 12] = [
+        Self::Homestead,
+        Self::TangerineWhistle,
+        Self::SpuriousDragon,
+        Self::Byzantium,
+        Self::Constantinople,
+        Self::Petersburg,
+        Self::Istanbul,
+        Self::Berlin,
+        Self::London,
+        Self::Paris,
+        Self::Shanghai,
+        Self::Cancun,
+    ];
+
+    ///
+    /// Returns the version name as accepted by `solc`.
+    ///
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Homestead => "homestead",
+            Self::TangerineWhistle => "tangerineWhistle",
+            Self::SpuriousDragon => "spuriousDragon",
+            Self::Byzantium => "byzantium",
+            Self::Constantinople => "constantinople",
+            Self::Petersburg => "petersburg",
+            Self::Istanbul => "istanbul",
+            Self::Berlin => "berlin",
+            Self::London => "london",
+            Self::Paris => "paris",
+            Self::Shanghai => "shanghai",
+            Self::Cancun => "cancun",
+        }
+    }
+}
+
+impl FromStr for EVMVersion {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|version| version.as_str() == string)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid EVM version `{}`. Expected one of: {}",
+                    string,
+                    Self::ALL
+                        .iter()
+                        .map(|version| version.as_str())
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl std::fmt::Display for EVMVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -2,6 +2,7 @@
 //! The `solc --standard-json` input settings.
 //!
 
+pub mod evm_version;
 pub mod metadata;
 pub mod optimizer;
 pub mod selection;
@@ -11,6 +12,7 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use self::evm_version::EVMVersion;
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
 use self::selection::Selection;
@@ -35,6 +37,9 @@ pub struct Settings {
     /// Whether to compile via IR. Only used by `solc` >=0.8.0.
     #[serde(rename = "viaIR", skip_serializing_if = "Option::is_none")]
     pub via_ir: Option<bool>,
+    /// The EVM target version of the `solc` output.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evm_version: Option<EVMVersion>,
 }
 
 impl Settings {
@@ -47,6 +52,7 @@ impl Settings {
         optimizer: Optimizer,
         metadata: Option<Metadata>,
         via_ir: bool,
+        evm_version: Option<EVMVersion>,
     ) -> Self {
         Self {
             libraries: Some(libraries),
@@ -54,6 +60,7 @@ impl Settings {
             optimizer,
             metadata,
             via_ir: if via_ir { Some(true) } else { None },
+            evm_version,
         }
     }
 
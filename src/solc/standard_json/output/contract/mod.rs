@@ -9,6 +9,8 @@ use std::collections::BTreeMap;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+
 use self::evm::EVM;
 
 ///
@@ -44,4 +46,7 @@ pub struct Contract {
     /// The contracts factory dependencies.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub factory_dependencies: Option<BTreeMap<String, String>>,
+    /// The `solc` pipeline the contract has been compiled with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc_pipeline: Option<SolcPipeline>,
 }
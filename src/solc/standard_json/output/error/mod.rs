@@ -34,6 +34,21 @@ pub struct Error {
 }
 
 impl Error {
+    ///
+    /// A shortcut constructor of a warning without a source location.
+    ///
+    pub fn new_warning(message: String) -> Self {
+        Self {
+            component: "general".to_owned(),
+            error_code: None,
+            formatted_message: format!("Warning: {message}"),
+            message,
+            severity: "warning".to_owned(),
+            source_location: None,
+            r#type: "Warning".to_owned(),
+        }
+    }
+
     ///
     /// Returns the `ecrecover` function usage warning.
     ///
@@ -191,13 +206,19 @@ impl Error {
     }
 
     ///
-    /// Returns the internal function pointer usage error.
+    /// Returns the `msize` instruction usage warning.
     ///
-    pub fn message_internal_function_pointer(src: Option<&str>) -> Self {
+    pub fn message_msize(src: Option<&str>) -> Self {
         let message = r#"
 ┌──────────────────────────────────────────────────────────────────────────────────────────────────┐
-│ Error: Internal function pointers are not supported in EVM legacy assembly pipeline.             │
-│ Please use the Yul IR codegen instead.                                                           │
+│ Warning: Your code or one of its dependencies uses the 'msize' instruction. On zkEVM, 'msize'    │
+│ returns the size of the heap, which also includes the memory allocated by the compiler itself,   │
+│ e.g. for the ABI encoding of calls and the return data, so it may be larger than on EVM.         │
+│ Besides, the solc optimizer removes and reorders memory operations assuming the EVM semantics of │
+│ 'msize', which might break your code on zkEVM.                                                   │
+│ It is highly recommended to use the free memory pointer 'mload(0x40)' instead. If you cannot     │
+│ avoid 'msize', disable the solc optimizer with '--disable-solc-optimizer' or with the            │
+│ 'settings.optimizer.enabled' standard JSON field set to false.                                   │
 └──────────────────────────────────────────────────────────────────────────────────────────────────┘"#
             .to_owned();
 
@@ -206,9 +227,9 @@ impl Error {
             error_code: None,
             formatted_message: message.clone(),
             message,
-            severity: "error".to_owned(),
+            severity: "warning".to_owned(),
             source_location: src.map(SourceLocation::from_str).and_then(Result::ok),
-            r#type: "Error".to_owned(),
+            r#type: "Warning".to_owned(),
         }
     }
 
@@ -219,6 +240,18 @@ impl Error {
         self.formatted_message
             .push_str(format!("\n--> {path}\n").as_str());
     }
+
+    ///
+    /// Whether the error has been raised by the `solc` code generator, e.g. during the Yul IR
+    /// generation, and not by the source code analysis.
+    ///
+    pub fn is_code_generation_error(&self) -> bool {
+        self.severity.as_str() == "error"
+            && matches!(
+                self.r#type.as_str(),
+                "CodeGenerationError" | "UnimplementedFeatureError" | "YulException"
+            )
+    }
 }
 
 impl std::fmt::Display for Error {
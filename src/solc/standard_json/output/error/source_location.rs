@@ -10,7 +10,7 @@ use serde::Serialize;
 ///
 /// The `solc --standard-json` output error source location.
 ///
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct SourceLocation {
     /// The source file path.
@@ -45,3 +45,9 @@ impl FromStr for SourceLocation {
         })
     }
 }
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}..{}", self.file, self.start, self.end)
+    }
+}
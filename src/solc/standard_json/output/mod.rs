@@ -7,7 +7,11 @@ pub mod error;
 pub mod source;
 
 use std::collections::BTreeMap;
+use std::str::FromStr;
 
+use rayon::iter::IntoParallelRefIterator;
+use rayon::iter::IntoParallelRefMutIterator;
+use rayon::iter::ParallelIterator;
 use serde::Deserialize;
 use serde::Serialize;
 use sha3::Digest;
@@ -15,13 +19,16 @@ use sha3::Digest;
 use crate::evmla::assembly::instruction::Instruction;
 use crate::evmla::assembly::Assembly;
 use crate::project::contract::ir::IR as ProjectContractIR;
+use crate::project::contract::state::State as ProjectContractState;
 use crate::project::contract::Contract as ProjectContract;
+use crate::project::msize_usage::MSizeUsage;
 use crate::project::Project;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::statement::object::Object;
 
 use self::contract::Contract;
+use self::error::source_location::SourceLocation;
 use self::error::Error as SolcStandardJsonOutputError;
 use self::source::Source;
 
@@ -54,11 +61,17 @@ impl Output {
     ///
     /// Converts the `solc` JSON output into a convenient project.
     ///
+    /// If `is_strict_evmla` is set, the dead EVM legacy assembly blocks are errors instead of
+    /// warnings. The `evmla_block_limit` overrides the default limit of visited blocks in the
+    /// Ethereal IR analysis.
+    ///
     pub fn try_to_project(
         &mut self,
         source_code_files: BTreeMap<String, String>,
         libraries: BTreeMap<String, BTreeMap<String, String>>,
         pipeline: SolcPipeline,
+        is_strict_evmla: bool,
+        evmla_block_limit: Option<usize>,
         version: &semver::Version,
         debug_config: Option<&compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Project> {
@@ -78,74 +91,321 @@ impl Output {
                 );
             }
         };
-        let mut project_contracts = BTreeMap::new();
+        let source_paths: BTreeMap<isize, String> = self
+            .sources
+            .as_ref()
+            .map(|sources| {
+                sources
+                    .iter()
+                    .map(|(path, source)| (source.id as isize, path.to_owned()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let code_generation_errors: Vec<&SolcStandardJsonOutputError> = match pipeline {
+            SolcPipeline::Yul => self
+                .errors
+                .iter()
+                .flatten()
+                .filter(|error| error.is_code_generation_error())
+                .collect(),
+            SolcPipeline::EVMLA => vec![],
+        };
 
-        for (path, contracts) in files.iter() {
-            for (name, contract) in contracts.iter() {
+        #[allow(clippy::type_complexity)]
+        let results: Vec<(String, &Contract, anyhow::Result<Option<ProjectContract>>)> = files
+            .par_iter()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .par_iter()
+                    .map(move |(name, contract)| (path, name, contract))
+            })
+            .map(|(path, name, contract)| {
                 let full_path = format!("{path}:{name}");
+                let result = Self::try_to_project_contract(
+                    full_path.as_str(),
+                    path.as_str(),
+                    contract,
+                    &source_code_files,
+                    &source_paths,
+                    code_generation_errors.as_slice(),
+                    pipeline,
+                    is_strict_evmla,
+                    evmla_block_limit,
+                    version,
+                    debug_config,
+                );
+                (full_path, contract, result)
+            })
+            .collect();
 
-                let source = match pipeline {
-                    SolcPipeline::Yul => {
-                        let ir_optimized = match contract.ir_optimized.to_owned() {
-                            Some(ir_optimized) => ir_optimized,
-                            None => continue,
-                        };
-                        if ir_optimized.is_empty() {
-                            continue;
-                        }
-
-                        if let Some(debug_config) = debug_config {
-                            debug_config.dump_yul(full_path.as_str(), ir_optimized.as_str())?;
-                        }
+        let mut project_contracts = BTreeMap::new();
+        let mut errors = BTreeMap::new();
+        for (full_path, contract, result) in results.into_iter() {
+            match result {
+                Ok(Some(project_contract)) => {
+                    project_contracts.insert(full_path, project_contract);
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    let identifier =
+                        Self::contract_identifier(full_path.as_str(), contract, pipeline);
+                    errors.insert(full_path, (identifier, error));
+                }
+            }
+        }
 
-                        let mut lexer = Lexer::new(ir_optimized.to_owned());
-                        let object = Object::parse(&mut lexer, None).map_err(|error| {
-                            anyhow::anyhow!("Contract `{}` parsing error: {:?}", full_path, error)
-                        })?;
+        let mut project = Project::new(version.to_owned(), project_contracts, libraries);
+        for (full_path, (identifier, error)) in errors.into_iter() {
+            if let Some(identifier) = identifier {
+                project
+                    .identifier_paths
+                    .insert(identifier, full_path.clone());
+            }
+            project
+                .contract_states
+                .insert(full_path, ProjectContractState::Error(error));
+        }
+        Ok(project)
+    }
 
-                        ProjectContractIR::new_yul(ir_optimized.to_owned(), object)
-                    }
-                    SolcPipeline::EVMLA => {
-                        let assembly = match contract
-                            .evm
-                            .as_ref()
-                            .and_then(|evm| evm.assembly.to_owned())
-                        {
-                            Some(assembly) => assembly.to_owned(),
-                            None => continue,
+    ///
+    /// Converts the `solc` output contract into a project contract.
+    ///
+    /// Returns `None` if the contract has no IR, e.g. if it is an interface. If `solc` has failed
+    /// to generate the Yul IR, the contract fails with the code generation errors of its source
+    /// file, or all of them, as `solc` drops the IR of every contract in such case.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    fn try_to_project_contract(
+        full_path: &str,
+        path: &str,
+        contract: &Contract,
+        source_code_files: &BTreeMap<String, String>,
+        source_paths: &BTreeMap<isize, String>,
+        code_generation_errors: &[&SolcStandardJsonOutputError],
+        pipeline: SolcPipeline,
+        is_strict_evmla: bool,
+        evmla_block_limit: Option<usize>,
+        version: &semver::Version,
+        debug_config: Option<&compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<Option<ProjectContract>> {
+        let source = match pipeline {
+            SolcPipeline::Yul => {
+                let ir_optimized = match contract.ir_optimized.to_owned() {
+                    Some(ir_optimized) => ir_optimized,
+                    None if code_generation_errors.is_empty() => return Ok(None),
+                    None => {
+                        let file_errors: Vec<String> = code_generation_errors
+                            .iter()
+                            .filter(|error| {
+                                error
+                                    .source_location
+                                    .as_ref()
+                                    .map(|source_location| source_location.file.as_str())
+                                    == Some(path)
+                            })
+                            .map(|error| error.to_string())
+                            .collect();
+                        let errors = if file_errors.is_empty() {
+                            code_generation_errors
+                                .iter()
+                                .map(|error| error.to_string())
+                                .collect()
+                        } else {
+                            file_errors
                         };
-
-                        ProjectContractIR::new_evmla(assembly)
+                        anyhow::bail!("The Yul IR generation error(s):\n{}", errors.join("\n"));
                     }
                 };
+                if ir_optimized.is_empty() {
+                    return Ok(None);
+                }
 
-                let source_code = source_code_files
-                    .get(path.as_str())
-                    .ok_or_else(|| anyhow::anyhow!("Source code for path `{}` not found", path))?;
-                let source_hash = sha3::Keccak256::digest(source_code.as_bytes()).into();
-
-                let project_contract = ProjectContract::new(
-                    full_path.clone(),
-                    source_hash,
-                    version.to_owned(),
-                    source,
-                    contract.metadata.to_owned(),
-                );
-                project_contracts.insert(full_path, project_contract);
+                if let Some(debug_config) = debug_config {
+                    debug_config.dump_yul(full_path, ir_optimized.as_str())?;
+                }
+
+                let mut lexer = Lexer::new(ir_optimized.to_owned());
+                let mut object = Object::parse(&mut lexer, None).map_err(|error| {
+                    anyhow::anyhow!("Contract `{}` parsing error: {:?}", full_path, error)
+                })?;
+                // The child objects are copies of the upper-level contracts, which are addressed
+                // via the factory dependencies, so they are dropped to keep the AST small.
+                object.take_children();
+
+                ProjectContractIR::new_yul(ir_optimized.to_owned(), object)
             }
-        }
+            SolcPipeline::EVMLA => {
+                let mut assembly = match contract
+                    .evm
+                    .as_ref()
+                    .and_then(|evm| evm.assembly.to_owned())
+                {
+                    Some(assembly) => assembly.to_owned(),
+                    None => return Ok(None),
+                };
+                assembly.set_source_paths(source_paths.clone());
+                assembly.set_strict(is_strict_evmla);
+                assembly.set_block_visits_limit(evmla_block_limit);
+
+                ProjectContractIR::new_evmla(assembly)
+            }
+        };
+
+        let source_code = source_code_files
+            .get(path)
+            .ok_or_else(|| anyhow::anyhow!("Source code for path `{}` not found", path))?;
+        let source_hash = sha3::Keccak256::digest(source_code.as_bytes()).into();
 
-        Ok(Project::new(
+        Ok(Some(ProjectContract::new(
+            full_path.to_owned(),
+            source_hash,
             version.to_owned(),
-            project_contracts,
-            libraries,
-        ))
+            source,
+            contract.metadata.to_owned(),
+        )))
+    }
+
+    ///
+    /// Returns the identifier the dependents address the contract by, which is the Yul object
+    /// identifier or the full path for EVM legacy assembly.
+    ///
+    /// Used for the contracts that have failed to be converted, so their dependents fail with
+    /// the original error instead of a missing dependency.
+    ///
+    fn contract_identifier(
+        full_path: &str,
+        contract: &Contract,
+        pipeline: SolcPipeline,
+    ) -> Option<String> {
+        match pipeline {
+            SolcPipeline::Yul => contract
+                .ir_optimized
+                .as_deref()
+                .and_then(Object::parse_identifier),
+            SolcPipeline::EVMLA => Some(full_path.to_owned()),
+        }
+    }
+
+    ///
+    /// Returns the `solc` errors the compilation must be aborted with.
+    ///
+    /// With the Yul pipeline, the code generation errors are skipped, as the contracts left without
+    /// the Yul IR are recompiled with the EVM legacy assembly pipeline.
+    ///
+    pub fn compilation_errors(&self, pipeline: SolcPipeline) -> Vec<&SolcStandardJsonOutputError> {
+        self.errors
+            .iter()
+            .flatten()
+            .filter(|error| error.severity.as_str() == "error")
+            .filter(|error| pipeline == SolcPipeline::EVMLA || !error.is_code_generation_error())
+            .collect()
+    }
+
+    ///
+    /// Removes the code generation errors, which have been resolved by recompiling the contracts
+    /// with the EVM legacy assembly pipeline.
+    ///
+    /// The errors are reported as the recompilation warnings of the contracts instead.
+    ///
+    pub fn remove_code_generation_errors(&mut self) {
+        if let Some(errors) = self.errors.as_mut() {
+            errors.retain(|error| !error.is_code_generation_error());
+        }
+    }
+
+    ///
+    /// Converts the `msize` usages found in the project IR into warnings.
+    ///
+    /// The usages already reported by the AST check are skipped, so the inline assembly usages are
+    /// not reported twice. The AST warnings reference the source files by their `solc` identifiers.
+    ///
+    pub fn msize_warnings(&self, usages: Vec<MSizeUsage>) -> Vec<SolcStandardJsonOutputError> {
+        let message = SolcStandardJsonOutputError::message_msize(None).message;
+        let reported_locations: Vec<&SourceLocation> = self
+            .errors
+            .iter()
+            .flatten()
+            .filter(|error| error.message == message)
+            .filter_map(|error| error.source_location.as_ref())
+            .collect();
+
+        usages
+            .into_iter()
+            .filter(|usage| {
+                !reported_locations
+                    .iter()
+                    .any(|location| self.is_msize_usage_reported(usage, location))
+            })
+            .map(|usage| usage.to_warning())
+            .collect()
+    }
+
+    ///
+    /// Whether the `msize` usage has been reported by the AST check at the `reported` location.
+    ///
+    /// The usages mapped to the source code, e.g. by the EVM legacy assembly, must be at the same
+    /// location. The Yul IR cannot be mapped to the source code, so the other usages are matched by
+    /// the definition of their contract enclosing the reported location.
+    ///
+    fn is_msize_usage_reported(&self, usage: &MSizeUsage, reported: &SourceLocation) -> bool {
+        let (path, name) = match usage.path.rsplit_once(':') {
+            Some(path_name) => path_name,
+            None => return false,
+        };
+
+        match usage.source_location.as_ref() {
+            Some(location) => {
+                self.source_id(location.file.as_str()).as_deref() == Some(reported.file.as_str())
+                    && location.start == reported.start
+                    && location.end == reported.end
+            }
+            None => match self.contract_location(path, name) {
+                Some(contract) => {
+                    contract.file == reported.file
+                        && contract.start <= reported.start
+                        && reported.end <= contract.end
+                }
+                None => false,
+            },
+        }
+    }
+
+    ///
+    /// Returns the `solc` identifier of the source file.
+    ///
+    fn source_id(&self, path: &str) -> Option<String> {
+        self.sources
+            .as_ref()?
+            .get(path)
+            .map(|source| source.id.to_string())
+    }
+
+    ///
+    /// Returns the location of the contract definition from the source file AST.
+    ///
+    fn contract_location(&self, path: &str, name: &str) -> Option<SourceLocation> {
+        self.sources
+            .as_ref()?
+            .get(path)?
+            .ast
+            .as_ref()?
+            .get("nodes")?
+            .as_array()?
+            .iter()
+            .find(|node| {
+                node.get("nodeType").and_then(serde_json::Value::as_str)
+                    == Some("ContractDefinition")
+                    && node.get("name").and_then(serde_json::Value::as_str) == Some(name)
+            })
+            .and_then(|node| node.get("src")?.as_str())
+            .and_then(|src| SourceLocation::from_str(src).ok())
     }
 
     ///
     /// Traverses the AST and returns the list of additional errors and warnings.
     ///
-    pub fn preprocess_ast(&mut self, pipeline: SolcPipeline) -> anyhow::Result<()> {
+    pub fn preprocess_ast(&mut self) -> anyhow::Result<()> {
         let sources = match self.sources.as_ref() {
             Some(sources) => sources,
             None => return Ok(()),
@@ -154,7 +414,7 @@ impl Output {
         let mut messages = Vec::new();
         for (path, source) in sources.iter() {
             if let Some(ast) = source.ast.as_ref() {
-                let mut zkevm_messages = Source::get_messages(ast, pipeline);
+                let mut zkevm_messages = Source::get_messages(ast);
                 for message in zkevm_messages.iter_mut() {
                     message.push_contract_path(path.as_str());
                 }
@@ -176,47 +436,54 @@ impl Output {
     ///
     /// The pass, which replaces with dependency indexes with actual data.
     ///
+    /// The contracts are processed in parallel. The results are collected in the original
+    /// order, so the hash collisions and errors are resolved deterministically.
+    ///
     fn preprocess_dependencies(&mut self) -> anyhow::Result<()> {
         let files = match self.contracts.as_mut() {
             Some(files) => files,
             None => return Ok(()),
         };
-        let mut hash_path_mapping = BTreeMap::new();
 
-        for (path, contracts) in files.iter() {
-            for (name, contract) in contracts.iter() {
-                let full_path = format!("{path}:{name}");
-                let hash = match contract
+        let hash_path_mapping: BTreeMap<String, String> = files
+            .par_iter_mut()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .par_iter_mut()
+                    .map(move |(name, contract)| (path, name, contract))
+            })
+            .filter_map(|(path, name, contract)| {
+                let hash = contract
                     .evm
-                    .as_ref()
-                    .and_then(|evm| evm.assembly.as_ref())
-                    .map(|assembly| assembly.keccak256())
-                {
-                    Some(hash) => hash,
-                    None => continue,
-                };
-
-                hash_path_mapping.insert(hash, full_path);
-            }
-        }
+                    .as_mut()
+                    .and_then(|evm| evm.assembly.as_mut())?
+                    .cache_keccak256();
+                Some((hash, format!("{path}:{name}")))
+            })
+            .collect();
 
-        for (path, contracts) in files.iter_mut() {
-            for (name, contract) in contracts.iter_mut() {
-                let assembly = match contract.evm.as_mut().and_then(|evm| evm.assembly.as_mut()) {
-                    Some(assembly) => assembly,
-                    None => continue,
-                };
+        let results: Vec<anyhow::Result<()>> = files
+            .par_iter_mut()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .par_iter_mut()
+                    .map(move |(name, contract)| (path, name, contract))
+            })
+            .filter_map(|(path, name, contract)| {
+                let assembly = contract
+                    .evm
+                    .as_mut()
+                    .and_then(|evm| evm.assembly.as_mut())?;
 
                 let full_path = format!("{path}:{name}");
-                Self::preprocess_dependency_level(
+                Some(Self::preprocess_dependency_level(
                     full_path.as_str(),
                     assembly,
                     &hash_path_mapping,
-                )?;
-            }
-        }
-
-        Ok(())
+                ))
+            })
+            .collect();
+        results.into_iter().collect()
     }
 
     ///
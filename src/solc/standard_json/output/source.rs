@@ -5,7 +5,6 @@
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::solc::pipeline::Pipeline as SolcPipeline;
 use crate::solc::standard_json::output::error::Error as SolcStandardJsonOutputError;
 
 ///
@@ -247,40 +246,33 @@ impl Source {
     }
 
     ///
-    /// Checks the AST node for the internal function pointers value usage.
+    /// Checks the AST node for the `msize` assembly instruction usage.
     ///
-    pub fn check_internal_function_pointer(
-        ast: &serde_json::Value,
-    ) -> Option<SolcStandardJsonOutputError> {
+    pub fn check_assembly_msize(ast: &serde_json::Value) -> Option<SolcStandardJsonOutputError> {
         let ast = ast.as_object()?;
 
-        if ast.get("nodeType")?.as_str()? != "VariableDeclaration" {
+        if ast.get("nodeType")?.as_str()? != "YulFunctionCall" {
             return None;
         }
-
-        let type_descriptions = ast.get("typeDescriptions")?.as_object()?;
-        if !type_descriptions
-            .get("typeIdentifier")?
+        if ast
+            .get("functionName")?
+            .as_object()?
+            .get("name")?
             .as_str()?
-            .starts_with("t_function_internal")
+            != "msize"
         {
             return None;
         }
 
-        Some(
-            SolcStandardJsonOutputError::message_internal_function_pointer(
-                ast.get("src")?.as_str(),
-            ),
-        )
+        Some(SolcStandardJsonOutputError::message_msize(
+            ast.get("src")?.as_str(),
+        ))
     }
 
     ///
     /// Returns the list of messages for some specific parts of the AST.
     ///
-    pub fn get_messages(
-        ast: &serde_json::Value,
-        pipeline: SolcPipeline,
-    ) -> Vec<SolcStandardJsonOutputError> {
+    pub fn get_messages(ast: &serde_json::Value) -> Vec<SolcStandardJsonOutputError> {
         let mut messages = Vec::new();
         if let Some(message) = Self::check_ecrecover(ast) {
             messages.push(message);
@@ -309,21 +301,19 @@ impl Source {
         if let Some(message) = Self::check_block_number(ast) {
             messages.push(message);
         }
-        if let SolcPipeline::EVMLA = pipeline {
-            if let Some(message) = Self::check_internal_function_pointer(ast) {
-                messages.push(message);
-            }
+        if let Some(message) = Self::check_assembly_msize(ast) {
+            messages.push(message);
         }
 
         match ast {
             serde_json::Value::Array(array) => {
                 for element in array.iter() {
-                    messages.extend(Self::get_messages(element, pipeline));
+                    messages.extend(Self::get_messages(element));
                 }
             }
             serde_json::Value::Object(object) => {
                 for (_key, value) in object.iter() {
-                    messages.extend(Self::get_messages(value, pipeline));
+                    messages.extend(Self::get_messages(value));
                 }
             }
             _ => {}
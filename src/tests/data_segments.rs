@@ -0,0 +1,190 @@
+//!
+//! The Solidity compiler unit tests for Yul data segments.
+//!
+
+#![cfg(test)]
+
+use crate::yul::interpreter::environment::Environment;
+use crate::yul::interpreter::outcome::Outcome;
+use crate::yul::interpreter::Interpreter;
+use crate::yul::lexer::Lexer;
+use crate::yul::parser::statement::object::Object;
+
+const TABLE: [u8; 34] = [
+    0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10,
+    0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
+    0x21, 0x22,
+];
+
+///
+/// Compiles the Yul source code, and returns the outcome of its deploy code executed by the
+/// reference interpreter.
+///
+fn build_and_run_deploy(source_code: &str) -> Outcome {
+    super::build_yul(source_code).expect("Test failure");
+
+    let mut lexer = Lexer::new(source_code.to_owned());
+    let object = Object::parse(&mut lexer, None).expect("Test failure");
+    Interpreter::new(Environment::default())
+        .run_deploy(&object)
+        .expect("Test failure")
+}
+
+#[test]
+fn datacopy_constant_size() {
+    let source_code = r#"
+object "Test" {
+    code {
+        datacopy(0, dataoffset("Table"), 33)
+        return(0, 34)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122"
+}
+    "#;
+
+    let mut expected = TABLE[..33].to_vec();
+    expected.push(0);
+    assert_eq!(build_and_run_deploy(source_code), Outcome::Return(expected));
+}
+
+#[test]
+fn datacopy_runtime_size() {
+    let source_code = r#"
+object "Test" {
+    code {
+        let size := add(calldatasize(), 30)
+        datacopy(0, dataoffset("Table"), size)
+        return(0, 32)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122"
+}
+    "#;
+
+    let mut expected = TABLE[..30].to_vec();
+    expected.extend([0, 0]);
+    assert_eq!(build_and_run_deploy(source_code), Outcome::Return(expected));
+}
+
+#[test]
+fn datasize_segment() {
+    let source_code = r#"
+object "Test" {
+    code {
+        mstore(0, datasize("Table"))
+        return(0, 32)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122"
+}
+    "#;
+
+    let mut expected = vec![0; 32];
+    expected[31] = TABLE.len() as u8;
+    assert_eq!(build_and_run_deploy(source_code), Outcome::Return(expected));
+}
+
+#[test]
+fn datacopy_plain_string_source() {
+    let source_code = r#"
+object "Test" {
+    code {
+        datacopy(0, "0102", 2)
+        return(0, 2)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    assert_eq!(
+        build_and_run_deploy(source_code),
+        Outcome::Return(vec![0, 0])
+    );
+}
+
+#[test]
+fn datacopy_hexadecimal_literal_source() {
+    let source_code = r#"
+object "Test" {
+    code {
+        datacopy(0, hex"0102", 2)
+        return(0, 2)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"0102"
+}
+    "#;
+
+    assert_eq!(
+        build_and_run_deploy(source_code),
+        Outcome::Return(vec![0, 0])
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "`dataoffset` of the data segment `Table` is only supported as the `datacopy` source offset"
+)]
+fn dataoffset_variable_source() {
+    let source_code = r#"
+object "Test" {
+    code {
+        let offset := dataoffset("Table")
+        datacopy(0, offset, datasize("Table"))
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"0102030405"
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
+
+#[test]
+#[should_panic(
+    expected = "`dataoffset` of the data segment `Table` is only supported as the `datacopy` source offset"
+)]
+fn dataoffset_long_segment_value() {
+    let source_code = r#"
+object "Test" {
+    code {
+        mstore(0, dataoffset("Table"))
+        return(0, 32)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122"
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
@@ -0,0 +1,113 @@
+//!
+//! The Solidity compiler unit tests for the Ethereal IR dead blocks.
+//!
+
+#![cfg(test)]
+
+use std::collections::BTreeMap;
+
+use crate::build::Build;
+use crate::evmla::assembly::Assembly;
+use crate::project::contract::ir::IR;
+use crate::project::contract::Contract;
+use crate::project::Project;
+use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+use crate::solc::version::Version as SolcVersion;
+use crate::solc::Compiler as SolcCompiler;
+
+const CONTRACT_PATH: &str = "test.sol:Test";
+
+const UNREACHABLE_BLOCK_DEPLOY_CODE: &str = r#"[
+    { "name": "STOP" },
+    { "name": "tag", "value": "1" },
+    { "name": "JUMPDEST" },
+    { "name": "STOP" }
+]"#;
+
+fn build_evmla(deploy_code: &str, is_strict: bool) -> anyhow::Result<Build> {
+    inkwell::support::enable_llvm_pretty_stack_trace();
+    compiler_llvm_context::initialize_target();
+    let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+
+    let deploy_code: serde_json::Value = serde_json::from_str(deploy_code).expect("Always valid");
+    let mut assembly: Assembly = serde_json::from_value(serde_json::json!({
+        ".code": deploy_code,
+        ".data": { "0": { ".code": [{ "name": "STOP" }] } },
+    }))
+    .expect("Always valid");
+    assembly.set_full_path(CONTRACT_PATH.to_owned());
+    assembly.set_strict(is_strict);
+
+    let mut contracts = BTreeMap::new();
+    contracts.insert(
+        CONTRACT_PATH.to_owned(),
+        Contract::new(
+            CONTRACT_PATH.to_owned(),
+            [0u8; compiler_common::BYTE_LENGTH_FIELD],
+            SolcCompiler::LAST_SUPPORTED_VERSION,
+            IR::new_evmla(assembly),
+            None,
+        ),
+    );
+    let project = Project::new(
+        SolcCompiler::LAST_SUPPORTED_VERSION,
+        contracts,
+        BTreeMap::new(),
+    );
+    project.compile_all(
+        compiler_llvm_context::TargetMachine::new(&optimizer_settings)?,
+        optimizer_settings,
+        false,
+        false,
+        None,
+    )
+}
+
+#[test]
+fn unreachable_block_warning() {
+    let build = build_evmla(UNREACHABLE_BLOCK_DEPLOY_CODE, false).expect("Test failure");
+
+    let warnings = build.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].severity, "warning");
+    assert!(warnings[0]
+        .message
+        .contains("Found 1 dead block(s) in the contract `test.sol:Test`"));
+    assert!(warnings[0].message.contains("is unreachable"));
+}
+
+#[test]
+fn unreachable_block_standard_json() {
+    let build = build_evmla(UNREACHABLE_BLOCK_DEPLOY_CODE, false).expect("Test failure");
+
+    let mut output: SolcStandardJsonOutput = serde_json::from_str("{}").expect("Always valid");
+    build
+        .write_to_standard_json(
+            &mut output,
+            &SolcVersion::new(
+                SolcCompiler::LAST_SUPPORTED_VERSION.to_string(),
+                SolcCompiler::LAST_SUPPORTED_VERSION,
+            ),
+            &semver::Version::new(0, 0, 0),
+        )
+        .expect("Test failure");
+
+    let errors = output.errors.expect("Always exists");
+    assert!(errors.iter().any(|error| error.severity == "warning"
+        && error
+            .message
+            .contains("dead block(s) in the contract `test.sol:Test`")));
+}
+
+#[test]
+fn no_dead_blocks() {
+    let build = build_evmla(r#"[{ "name": "STOP" }]"#, true).expect("Test failure");
+
+    assert!(build.warnings().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Found 1 dead block(s) in the contract `test.sol:Test`")]
+fn unreachable_block_strict() {
+    build_evmla(UNREACHABLE_BLOCK_DEPLOY_CODE, true).expect("Test failure");
+}
@@ -0,0 +1,69 @@
+//!
+//! The Solidity compiler unit tests for the EVM version setting.
+//!
+
+#![cfg(test)]
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::evm_version::EVMVersion as SolcStandardJsonInputSettingsEVMVersion;
+
+pub const TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.20;
+
+contract Test {
+    uint256[] public values;
+
+    function push() public {
+        values.push(0);
+    }
+
+    function sum() public view returns (uint256 result) {
+        for (uint256 index = 0; index < values.length; index++) {
+            result += values[index];
+        }
+    }
+}
+    "#;
+
+#[test]
+fn evmla_shanghai_push0() {
+    super::build_solidity_with_evm_version(
+        TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Shanghai),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+fn evmla_paris() {
+    super::build_solidity_with_evm_version(
+        TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Paris),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+fn yul_shanghai() {
+    super::build_solidity_with_evm_version(
+        TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Shanghai),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "Invalid EVM version `prague`")]
+fn invalid() {
+    SolcStandardJsonInputSettingsEVMVersion::from_str("prague").expect("Test failure");
+}
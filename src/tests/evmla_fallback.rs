@@ -0,0 +1,359 @@
+//!
+//! The Solidity compiler unit tests for the EVM legacy assembly pipeline fallback.
+//!
+
+#![cfg(test)]
+
+use std::collections::BTreeMap;
+
+use crate::build::Build;
+use crate::project::contract::state::State as ContractState;
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
+use crate::solc::standard_json::input::settings::selection::Selection as SolcStandardJsonInputSettingsSelection;
+use crate::solc::standard_json::input::Input as SolcStandardJsonInput;
+use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
+use crate::solc::version::Version as SolcVersion;
+use crate::solc::Compiler as SolcCompiler;
+use crate::yul::parser::statement::object::Object;
+
+use super::unsupported_opcodes::CALLCODE_TEST_SOURCE;
+
+const FAILING_CONTRACT_PATH: &str = "test.sol:Failing";
+
+const PASSING_CONTRACT_PATH: &str = "test.sol:Passing";
+
+const TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Failing {
+    function get() public pure returns(uint256) {
+        return 42;
+    }
+}
+
+contract Passing {
+    function get() public pure returns(uint256) {
+        return 24;
+    }
+}
+    "#;
+
+const DEPENDENCY_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Dependency {
+    function get() public pure returns(uint256) {
+        return 42;
+    }
+}
+
+contract Dependent {
+    function deploy() public returns(address) {
+        return address(new Dependency());
+    }
+}
+    "#;
+
+const CODE_GENERATION_ERROR_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Test {
+    fixed public value;
+}
+    "#;
+
+fn solc_input(source_code: &str) -> anyhow::Result<SolcStandardJsonInput> {
+    let mut sources = BTreeMap::new();
+    sources.insert("test.sol".to_string(), source_code.to_string());
+    SolcStandardJsonInput::try_from_sources(
+        sources,
+        BTreeMap::new(),
+        SolcStandardJsonInputSettingsSelection::new_required(SolcPipeline::Yul),
+        SolcStandardJsonInputSettingsOptimizer::new(true, None),
+        None,
+        true,
+        None,
+    )
+}
+
+///
+/// Compiles the test source with the Yul pipeline, failing the `Failing` contract on purpose, so
+/// it is recompiled with the EVM legacy assembly pipeline.
+///
+fn build_with_fallback() -> anyhow::Result<Build> {
+    inkwell::support::enable_llvm_pretty_stack_trace();
+    compiler_llvm_context::initialize_target();
+    let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+
+    let input = solc_input(TEST_SOURCE)?;
+    let sources = input
+        .sources
+        .iter()
+        .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+        .collect();
+
+    let solc = SolcCompiler::new("solc".to_owned());
+    let mut output = solc.standard_json(&input, None, vec![], None)?;
+    let mut project = output.try_to_project(
+        sources,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        false,
+        None,
+        &SolcCompiler::LAST_SUPPORTED_VERSION,
+        None,
+    )?;
+    project.contract_states.insert(
+        FAILING_CONTRACT_PATH.to_owned(),
+        ContractState::Error(anyhow::anyhow!("The Yul pipeline test error")),
+    );
+
+    crate::compile_with_evmla_fallback(
+        project,
+        &solc,
+        input,
+        SolcPipeline::Yul,
+        false,
+        None,
+        &SolcCompiler::LAST_SUPPORTED_VERSION,
+        None,
+        vec![],
+        None,
+        compiler_llvm_context::TargetMachine::new(&optimizer_settings)?,
+        optimizer_settings,
+        false,
+        false,
+        None,
+    )
+}
+
+#[test]
+fn failing_contract_recompiled() {
+    let build = build_with_fallback().expect("Test failure");
+
+    let failing = build
+        .contracts
+        .get(FAILING_CONTRACT_PATH)
+        .expect("Always exists");
+    assert_eq!(failing.solc_pipeline, Some(SolcPipeline::EVMLA));
+    assert!(failing.warnings.iter().any(|warning| warning
+        .message
+        .contains("is recompiled with the EVM legacy assembly pipeline due to the Yul pipeline error: The Yul pipeline test error")));
+}
+
+#[test]
+fn passing_contract_kept() {
+    let build = build_with_fallback().expect("Test failure");
+
+    let passing = build
+        .contracts
+        .get(PASSING_CONTRACT_PATH)
+        .expect("Always exists");
+    assert_eq!(passing.solc_pipeline, Some(SolcPipeline::Yul));
+    assert!(passing.metadata.get("solc_pipeline").is_none());
+    assert!(passing.warnings.is_empty());
+}
+
+#[test]
+fn standard_json_reporting() {
+    let build = build_with_fallback().expect("Test failure");
+
+    let solc = SolcCompiler::new("solc".to_owned());
+    let mut output = solc
+        .standard_json(
+            &solc_input(TEST_SOURCE).expect("Test failure"),
+            None,
+            vec![],
+            None,
+        )
+        .expect("Test failure");
+    build
+        .write_to_standard_json(
+            &mut output,
+            &SolcVersion::new(
+                SolcCompiler::LAST_SUPPORTED_VERSION.to_string(),
+                SolcCompiler::LAST_SUPPORTED_VERSION,
+            ),
+            &semver::Version::new(0, 0, 0),
+        )
+        .expect("Test failure");
+
+    let output = serde_json::to_value(&output).expect("Always valid");
+    assert_eq!(
+        output["contracts"]["test.sol"]["Failing"]["solcPipeline"],
+        "EVMLA"
+    );
+    assert_eq!(
+        output["contracts"]["test.sol"]["Passing"]["solcPipeline"],
+        "Yul"
+    );
+    assert!(output["errors"]
+        .as_array()
+        .expect("Always exists")
+        .iter()
+        .any(|error| error["severity"] == "warning"
+            && error["message"]
+                .as_str()
+                .expect("Always exists")
+                .contains("The contract `test.sol:Failing` is recompiled")));
+}
+
+#[test]
+fn parsing_error_dependency() {
+    inkwell::support::enable_llvm_pretty_stack_trace();
+    compiler_llvm_context::initialize_target();
+
+    let input = solc_input(DEPENDENCY_TEST_SOURCE).expect("Test failure");
+    let sources = input
+        .sources
+        .iter()
+        .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+        .collect();
+
+    let solc = SolcCompiler::new("solc".to_owned());
+    let mut output: SolcStandardJsonOutput = solc
+        .standard_json(&input, None, vec![], None)
+        .expect("Test failure");
+    let dependency = output
+        .contracts
+        .as_mut()
+        .and_then(|files| files.get_mut("test.sol"))
+        .and_then(|contracts| contracts.get_mut("Dependency"))
+        .expect("Always exists");
+    let identifier = dependency
+        .ir_optimized
+        .as_deref()
+        .and_then(Object::parse_identifier)
+        .expect("Always exists");
+    dependency.ir_optimized = Some(format!("object \"{identifier}\" {{ code {{"));
+
+    let project = output
+        .try_to_project(
+            sources,
+            BTreeMap::new(),
+            SolcPipeline::Yul,
+            false,
+            None,
+            &SolcCompiler::LAST_SUPPORTED_VERSION,
+            None,
+        )
+        .expect("Test failure");
+    assert_eq!(
+        project.identifier_paths.get(identifier.as_str()),
+        Some(&"test.sol:Dependency".to_owned())
+    );
+
+    let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+    let (_build, errors) = project
+        .try_compile_all(
+            compiler_llvm_context::TargetMachine::new(&optimizer_settings).expect("Test failure"),
+            optimizer_settings,
+            false,
+            false,
+            None,
+        )
+        .expect("Test failure");
+    let error = errors
+        .get("test.sol:Dependent")
+        .expect("Always exists")
+        .to_string();
+    assert!(error.contains(format!("Dependency contract `{identifier}` compiling error").as_str()));
+    assert!(error.contains("Contract `test.sol:Dependency` parsing error"));
+}
+
+///
+/// The fixed point types are not implemented by the `solc` code generators, so the Yul IR
+/// generation fails. The error must reach the fallback instead of aborting the compilation, and
+/// since the EVM legacy assembly code generator fails as well, the fallback reports its own error.
+///
+#[test]
+fn code_generation_error() {
+    inkwell::support::enable_llvm_pretty_stack_trace();
+    compiler_llvm_context::initialize_target();
+    let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+
+    let input = solc_input(CODE_GENERATION_ERROR_TEST_SOURCE).expect("Test failure");
+    let sources = input
+        .sources
+        .iter()
+        .map(|(path, source)| (path.to_owned(), source.content.to_owned()))
+        .collect();
+
+    let solc = SolcCompiler::new("solc".to_owned());
+    let mut output: SolcStandardJsonOutput = solc
+        .standard_json(&input, None, vec![], None)
+        .expect("Test failure");
+    assert!(output
+        .errors
+        .iter()
+        .flatten()
+        .any(|error| error.is_code_generation_error()));
+    assert!(output.compilation_errors(SolcPipeline::Yul).is_empty());
+    assert!(!output.compilation_errors(SolcPipeline::EVMLA).is_empty());
+
+    let project = output
+        .try_to_project(
+            sources,
+            BTreeMap::new(),
+            SolcPipeline::Yul,
+            false,
+            None,
+            &SolcCompiler::LAST_SUPPORTED_VERSION,
+            None,
+        )
+        .expect("Test failure");
+    assert!(matches!(
+        project.contract_states.get("test.sol:Test"),
+        Some(ContractState::Error(error)) if error.to_string().contains("The Yul IR generation error(s)")
+    ));
+
+    let error = crate::compile_with_evmla_fallback(
+        project,
+        &solc,
+        input,
+        SolcPipeline::Yul,
+        false,
+        None,
+        &SolcCompiler::LAST_SUPPORTED_VERSION,
+        None,
+        vec![],
+        None,
+        compiler_llvm_context::TargetMachine::new(&optimizer_settings).expect("Test failure"),
+        optimizer_settings,
+        false,
+        false,
+        None,
+    )
+    .expect_err("Test failure")
+    .to_string();
+    assert!(error.contains("The EVM legacy assembly pipeline fallback error(s)"));
+}
+
+#[test]
+fn unsupported_instruction_error() {
+    inkwell::support::enable_llvm_pretty_stack_trace();
+    compiler_llvm_context::initialize_target();
+    let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
+
+    let (_output, project) =
+        super::get_solidity_project(CALLCODE_TEST_SOURCE, SolcPipeline::Yul).expect("Test failure");
+    let (_build, errors) = project
+        .try_compile_all(
+            compiler_llvm_context::TargetMachine::new(&optimizer_settings).expect("Test failure"),
+            optimizer_settings,
+            false,
+            false,
+            None,
+        )
+        .expect("Test failure");
+    let error = errors
+        .get("test.sol:CallcodeTest")
+        .expect("Always exists")
+        .to_string();
+    assert!(error.contains("Found 1 unsupported instruction(s)"));
+    assert!(error.contains("The `CALLCODE` instruction is not supported"));
+}
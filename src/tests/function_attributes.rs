@@ -0,0 +1,57 @@
+//!
+//! The Solidity compiler unit tests for the Yul function attributes.
+//!
+
+#![cfg(test)]
+
+#[test]
+fn near_call_first_parameter() {
+    let source_code = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, test(gas(), 42))
+                return(0, 32)
+            }
+
+            /// @zksync near_call
+            function test(value) -> result {
+                result := add(value, 1)
+            }
+        }
+    }
+}
+    "#;
+
+    super::build_yul_with_mode(source_code, true).expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "annotations are only allowed before function definitions")]
+fn misplaced_annotation() {
+    let source_code = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            /// @zksync noinline
+            let value := 42
+            mstore(0, value)
+            return(0, 32)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
@@ -0,0 +1,260 @@
+//!
+//! The Solidity compiler unit tests for internal functions.
+//!
+
+#![cfg(test)]
+
+use std::collections::BTreeMap;
+
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+
+pub const RECURSIVE_AND_MULTIPLE_RETURNS_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract Test {
+    function fibonacci(uint256 n) internal pure returns(uint256) {
+        if (n < 2) {
+            return n;
+        }
+        return fibonacci(n - 1) + fibonacci(n - 2);
+    }
+
+    function divmod(uint256 a, uint256 b) internal pure returns(uint256, uint256) {
+        return (a / b, a % b);
+    }
+
+    function main(uint256 a, uint256 b) public pure returns(uint256) {
+        (uint256 quotient, uint256 remainder) = divmod(a, b);
+        return fibonacci(quotient) + fibonacci(remainder);
+    }
+}
+    "#;
+
+#[test]
+fn evmla_recursive_and_multiple_returns() {
+    super::build_solidity(
+        RECURSIVE_AND_MULTIPLE_RETURNS_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+    )
+    .expect("Test failure");
+}
+
+pub const FUNCTION_POINTER_ARGUMENT_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract InternalFunctionPointerExample {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a - b;
+    }
+
+    function executeOperation(
+        function (uint256, uint256) internal pure returns (uint256) operation,
+        uint256 a,
+        uint256 b
+    ) private pure returns (uint256) {
+        return operation(a, b);
+    }
+
+    function testAdd(uint256 a, uint256 b) public pure returns (uint256) {
+        return executeOperation(add, a, b);
+    }
+
+    function testSub(uint256 a, uint256 b) public pure returns (uint256) {
+        return executeOperation(sub, a, b);
+    }
+}
+    "#;
+
+#[test]
+fn evmla_function_pointer_argument() {
+    super::build_solidity(
+        FUNCTION_POINTER_ARGUMENT_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+    )
+    .expect("Test failure");
+}
+
+#[test]
+fn evmla_function_pointer_stack() {
+    let source_code = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract StackFunctionPointerExample {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a - b;
+    }
+
+    function testAdd(uint256 a, uint256 b) public pure returns (uint256) {
+        function (uint256, uint256) internal pure returns (uint256) operation = add;
+        return operation(a, b);
+    }
+
+    function testSub(uint256 a, uint256 b) public pure returns (uint256) {
+        function (uint256, uint256) internal pure returns (uint256) operation = sub;
+        return operation(a, b);
+    }
+}
+    "#;
+
+    super::build_solidity(source_code, BTreeMap::new(), SolcPipeline::EVMLA).expect("Test failure");
+}
+
+#[test]
+fn evmla_function_pointer_storage() {
+    let source_code = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract StorageFunctionPointerExample {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a - b;
+    }
+
+    function (uint256, uint256) internal pure returns (uint256) operation;
+    bool private isOperationSet = false;
+
+    function setOperation(bool isAdd) public {
+        if (isAdd) {
+            operation = add;
+        } else {
+            operation = sub;
+        }
+        isOperationSet = true;
+    }
+
+    function executeOperation(uint256 a, uint256 b) public view returns (uint256) {
+        require(isOperationSet, "Operation not set");
+        return operation(a, b);
+    }
+}
+    "#;
+
+    super::build_solidity(source_code, BTreeMap::new(), SolcPipeline::EVMLA).expect("Test failure");
+}
+
+#[test]
+fn evmla_recursive_and_multiple_returns_recovered() {
+    let ethereal_ir =
+        super::get_solidity_ethereal_ir(RECURSIVE_AND_MULTIPLE_RETURNS_TEST_SOURCE, "Test")
+            .expect("Test failure");
+
+    let functions = ethereal_ir["functions"].as_object().expect("Always exists");
+    assert!(functions.values().any(|function| {
+        let r#type = &function["type"]["Internal"];
+        r#type["input_size"] == 1 && r#type["output_size"] == 1
+    }));
+}
+
+#[test]
+fn evmla_function_pointer_argument_recovered() {
+    let ethereal_ir = super::get_solidity_ethereal_ir(
+        FUNCTION_POINTER_ARGUMENT_TEST_SOURCE,
+        "InternalFunctionPointerExample",
+    )
+    .expect("Test failure");
+
+    assert!(!ethereal_ir["functions"]
+        .as_object()
+        .expect("Always exists")
+        .is_empty());
+}
+
+pub const FUNCTION_POINTER_CONSTRUCTOR_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract ConstructorFunctionPointerExample {
+    function add(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a + b;
+    }
+
+    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
+        return a - b;
+    }
+
+    function (uint256, uint256) internal pure returns (uint256) operation;
+    uint256 public initial;
+
+    constructor(bool isAdd) {
+        if (isAdd) {
+            operation = add;
+        } else {
+            operation = sub;
+        }
+        initial = operation(2, 1);
+    }
+
+    function executeOperation(uint256 a, uint256 b) public view returns (uint256) {
+        return operation(a, b);
+    }
+}
+    "#;
+
+#[test]
+fn evmla_function_pointer_constructor() {
+    let ethereal_ir = super::get_solidity_ethereal_ir(
+        FUNCTION_POINTER_CONSTRUCTOR_TEST_SOURCE,
+        "ConstructorFunctionPointerExample",
+    )
+    .expect("Test failure");
+
+    let mut dynamic_jumps = Vec::new();
+    find_dynamic_jumps(&ethereal_ir, &mut dynamic_jumps);
+    assert!(!dynamic_jumps.is_empty());
+    assert!(dynamic_jumps
+        .iter()
+        .all(|destinations| !destinations.is_empty()));
+    assert!(ethereal_ir["entry_function"]["truncated_blocks"]
+        .as_array()
+        .expect("Always exists")
+        .is_empty());
+}
+
+///
+/// Collects the destinations of all dynamic jumps found in the Ethereal IR JSON.
+///
+fn find_dynamic_jumps(value: &serde_json::Value, dynamic_jumps: &mut Vec<Vec<serde_json::Value>>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            if object
+                .get("instruction")
+                .and_then(serde_json::Value::as_str)
+                == Some("DynamicJump")
+            {
+                dynamic_jumps.push(
+                    object
+                        .get("destinations")
+                        .and_then(serde_json::Value::as_array)
+                        .cloned()
+                        .unwrap_or_default(),
+                );
+            }
+            for value in object.values() {
+                find_dynamic_jumps(value, dynamic_jumps);
+            }
+        }
+        serde_json::Value::Array(array) => {
+            for value in array.iter() {
+                find_dynamic_jumps(value, dynamic_jumps);
+            }
+        }
+        _ => {}
+    }
+}
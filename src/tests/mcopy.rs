@@ -0,0 +1,114 @@
+//!
+//! The Solidity compiler unit tests for the `MCOPY` instruction.
+//!
+
+#![cfg(test)]
+
+use std::collections::BTreeMap;
+
+use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::evm_version::EVMVersion as SolcStandardJsonInputSettingsEVMVersion;
+
+pub const TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract Test {
+    function copy(uint256 destination, uint256 source, uint256 size) public pure returns (bytes memory result) {
+        assembly {
+            result := mload(0x40)
+            mstore(result, 0x80)
+            let data := add(result, 0x20)
+            mstore(data, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mstore(add(data, 0x20), 0x2122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40)
+            mcopy(add(data, destination), add(data, source), size)
+            mstore(0x40, add(data, 0x80))
+        }
+    }
+}
+    "#;
+
+#[test]
+fn evmla() {
+    super::build_solidity_with_evm_version(
+        TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+fn yul() {
+    super::build_solidity_with_evm_version(
+        TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+fn yul_forward_overlapping() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mstore(32, 0x2122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40)
+            mcopy(0, 1, 40)
+            return(0, 64)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
+
+#[test]
+fn yul_backward_overlapping() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mstore(32, 0x2122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40)
+            mcopy(1, 0, 40)
+            return(0, 64)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
+
+#[test]
+fn yul_trailing_partial_word() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mstore(64, not(0))
+            mcopy(64, 0, 5)
+            return(64, 32)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
@@ -6,6 +6,8 @@
 
 use std::collections::BTreeMap;
 
+use crate::project::msize_usage::MSizeUsage;
+use crate::project::Project;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
 
 #[test]
@@ -273,122 +275,154 @@ contract BlockNumberExample {
 }
 
 #[test]
-fn internal_function_pointer_argument() {
+fn msize_assembly() {
     let source_code = r#"
 // SPDX-License-Identifier: MIT
 pragma solidity ^0.8.0;
 
-contract InternalFunctionPointerExample {
-    function add(uint256 a, uint256 b) internal pure returns (uint256) {
-        return a + b;
-    }
-
-    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
-        return a - b;
-    }
+contract MSizeExample {
+    function getMemorySize() public pure returns (uint256) {
+        uint256 memorySize;
 
-    function executeOperation(
-        function (uint256, uint256) internal pure returns (uint256) operation,
-        uint256 a,
-        uint256 b
-    ) private pure returns (uint256) {
-        return operation(a, b);
-    }
-
-    function testAdd(uint256 a, uint256 b) public pure returns (uint256) {
-        return executeOperation(add, a, b);
-    }
+        assembly {
+            memorySize := msize() // Get the memory size using the 'msize' instruction
+        }
 
-    function testSub(uint256 a, uint256 b) public pure returns (uint256) {
-        return executeOperation(sub, a, b);
+        return memorySize;
     }
 }
     "#;
 
     assert!(super::check_solidity_warning(
         source_code,
-        "Error: Internal function pointers are not supported in EVM legacy assembly pipeline.",
+        "Warning: Your code or one of its dependencies uses the 'msize' instruction.",
         BTreeMap::new(),
-        SolcPipeline::EVMLA,
+        SolcPipeline::Yul,
     )
     .expect("Test failure"));
 }
 
-#[test]
-fn internal_function_pointer_stack() {
-    let source_code = r#"
+pub const MSIZE_TEST_SOURCE: &str = r#"
 // SPDX-License-Identifier: MIT
 pragma solidity ^0.8.0;
 
-contract StackFunctionPointerExample {
-    function add(uint256 a, uint256 b) internal pure returns (uint256) {
-        return a + b;
-    }
-
-    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
-        return a - b;
-    }
-
-    function testAdd(uint256 a, uint256 b) public pure returns (uint256) {
-        function (uint256, uint256) internal pure returns (uint256) operation = add;
-        return operation(a, b);
-    }
-
-    function testSub(uint256 a, uint256 b) public pure returns (uint256) {
-        function (uint256, uint256) internal pure returns (uint256) operation = sub;
-        return operation(a, b);
+contract MSizeExample {
+    function getMemorySize() public pure returns (uint256 memorySize) {
+        assembly {
+            memorySize := msize()
+        }
     }
 }
     "#;
 
-    assert!(super::check_solidity_warning(
-        source_code,
-        "Error: Internal function pointers are not supported in EVM legacy assembly pipeline.",
-        BTreeMap::new(),
-        SolcPipeline::EVMLA,
-    )
-    .expect("Test failure"));
+#[test]
+fn msize_usages_evmla() {
+    let (output, project) =
+        super::get_solidity_project(MSIZE_TEST_SOURCE, SolcPipeline::EVMLA).expect("Test failure");
+
+    let usages = project.msize_usages();
+    assert!(usages
+        .iter()
+        .any(|usage| usage.path == "test.sol:MSizeExample"
+            && usage.location.starts_with("runtime code instruction #")));
+    assert!(output.msize_warnings(usages).is_empty());
 }
 
 #[test]
-fn internal_function_pointer_storage() {
-    let source_code = r#"
+fn msize_usages_yul() {
+    let (output, project) =
+        super::get_solidity_project(MSIZE_TEST_SOURCE, SolcPipeline::Yul).expect("Test failure");
+
+    let usages = project.msize_usages();
+    assert!(usages
+        .iter()
+        .any(|usage| usage.path == "test.sol:MSizeExample" && usage.location.starts_with("Yul ")));
+    assert!(output.msize_warnings(usages).is_empty());
+}
+
+pub const MSIZE_TWO_CONTRACTS_TEST_SOURCE: &str = r#"
 // SPDX-License-Identifier: MIT
 pragma solidity ^0.8.0;
 
-contract StorageFunctionPointerExample {
-    function add(uint256 a, uint256 b) internal pure returns (uint256) {
-        return a + b;
+contract MSizeExample {
+    function getMemorySize() public pure returns (uint256 memorySize) {
+        assembly {
+            memorySize := msize()
+        }
     }
+}
 
-    function sub(uint256 a, uint256 b) internal pure returns (uint256) {
-        return a - b;
+contract Other {
+    function get() public pure returns (uint256) {
+        return 42;
     }
+}
+    "#;
 
-    function (uint256, uint256) internal pure returns (uint256) operation;
-    bool private isOperationSet = false;
+#[test]
+fn msize_usages_other_location_evmla() {
+    let (output, project) =
+        super::get_solidity_project(MSIZE_TWO_CONTRACTS_TEST_SOURCE, SolcPipeline::EVMLA)
+            .expect("Test failure");
+
+    let mut usages = project.msize_usages();
+    let mut other_location = usages
+        .iter()
+        .find_map(|usage| usage.source_location.clone())
+        .expect("Always exists");
+    other_location.end += 1;
+    usages.push(MSizeUsage::new(
+        "test.sol:MSizeExample".to_owned(),
+        "runtime code instruction #0".to_owned(),
+        Some(other_location),
+    ));
+
+    let warnings = output.msize_warnings(usages);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0]
+        .to_string()
+        .contains("test.sol:MSizeExample (runtime code instruction #0)"));
+}
 
-    function setOperation(bool isAdd) public {
-        if (isAdd) {
-            operation = add;
-        } else {
-            operation = sub;
-        }
-        isOperationSet = true;
-    }
+#[test]
+fn msize_usages_other_contract_yul() {
+    let (output, project) =
+        super::get_solidity_project(MSIZE_TWO_CONTRACTS_TEST_SOURCE, SolcPipeline::Yul)
+            .expect("Test failure");
+
+    let mut usages = project.msize_usages();
+    usages.push(MSizeUsage::new(
+        "test.sol:Other".to_owned(),
+        "Yul 1:1".to_owned(),
+        None,
+    ));
+
+    let warnings = output.msize_warnings(usages);
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0].to_string().contains("test.sol:Other (Yul 1:1)"));
+}
 
-    function executeOperation(uint256 a, uint256 b) public view returns (uint256) {
-        require(isOperationSet, "Operation not set");
-        return operation(a, b);
+#[test]
+fn msize_usages_yul_mode() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, msize())
+            return(0, 32)
+        }
     }
 }
     "#;
 
-    assert!(super::check_solidity_warning(
-        source_code,
-        "Error: Internal function pointers are not supported in EVM legacy assembly pipeline.",
-        BTreeMap::new(),
-        SolcPipeline::EVMLA,
-    )
-    .expect("Test failure"));
+    let project = Project::try_from_yul_string("test.yul", source_code).expect("Test failure");
+    let usages = project.msize_usages();
+    assert_eq!(usages.len(), 1);
+    assert!(usages[0]
+        .to_warning()
+        .to_string()
+        .contains("Warning: Your code or one of its dependencies uses the 'msize' instruction."));
 }
@@ -4,24 +4,88 @@
 
 #![cfg(test)]
 
+mod data_segments;
+mod dead_blocks;
+mod evm_version;
+mod evmla_fallback;
+mod function_attributes;
+mod internal_functions;
 mod libraries;
+mod mcopy;
 mod messages;
 mod runtime_code;
 mod unsupported_opcodes;
+mod verbatim;
+mod yul_types;
 
 use std::collections::BTreeMap;
 
+use crate::evmla::ethereal_ir::EtherealIR;
 use crate::project::Project;
 use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::evm_version::EVMVersion as SolcStandardJsonInputSettingsEVMVersion;
 use crate::solc::standard_json::input::settings::optimizer::Optimizer as SolcStandardJsonInputSettingsOptimizer;
 use crate::solc::standard_json::input::settings::selection::Selection as SolcStandardJsonInputSettingsSelection;
 use crate::solc::standard_json::input::Input as SolcStandardJsonInput;
+use crate::solc::standard_json::output::Output as SolcStandardJsonOutput;
 use crate::solc::Compiler as SolcCompiler;
 
 pub fn build_solidity(
     source_code: &str,
     libraries: BTreeMap<String, BTreeMap<String, String>>,
     pipeline: SolcPipeline,
+) -> anyhow::Result<()> {
+    build_solidity_with_evm_version(source_code, libraries, pipeline, None)
+}
+
+pub fn build_solidity_with_evm_version(
+    source_code: &str,
+    libraries: BTreeMap<String, BTreeMap<String, String>>,
+    pipeline: SolcPipeline,
+    evm_version: Option<SolcStandardJsonInputSettingsEVMVersion>,
+) -> anyhow::Result<()> {
+    build_solidity_with_settings(source_code, libraries, pipeline, evm_version, None)
+}
+
+pub fn get_solidity_ethereal_ir(
+    source_code: &str,
+    contract_name: &str,
+) -> anyhow::Result<serde_json::Value> {
+    let output_directory = std::env::temp_dir().join(format!(
+        "zksolc-ethir-{}-{}",
+        contract_name,
+        std::process::id()
+    ));
+    std::fs::create_dir_all(output_directory.as_path())?;
+
+    let debug_config = compiler_llvm_context::DebugConfig::new(output_directory.clone());
+    let file_path = EtherealIR::dump_file_path(
+        &debug_config,
+        format!("test.sol:{contract_name}").as_str(),
+        EtherealIR::JSON_FILE_EXTENSION,
+    );
+
+    let result = build_solidity_with_settings(
+        source_code,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        None,
+        Some(debug_config),
+    )
+    .and_then(|()| {
+        let json = std::fs::read(file_path.as_path())?;
+        Ok(serde_json::from_slice(json.as_slice())?)
+    });
+    std::fs::remove_dir_all(output_directory.as_path())?;
+    result
+}
+
+pub fn build_solidity_with_settings(
+    source_code: &str,
+    libraries: BTreeMap<String, BTreeMap<String, String>>,
+    pipeline: SolcPipeline,
+    evm_version: Option<SolcStandardJsonInputSettingsEVMVersion>,
+    debug_config: Option<compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<()> {
     inkwell::support::enable_llvm_pretty_stack_trace();
     compiler_llvm_context::initialize_target();
@@ -36,24 +100,27 @@ pub fn build_solidity(
         SolcStandardJsonInputSettingsOptimizer::new(true, None),
         None,
         pipeline == SolcPipeline::Yul,
+        evm_version,
     )?;
 
     let solc = SolcCompiler::new("solc".to_owned());
-    let mut output = solc.standard_json(input, pipeline, None, vec![], None)?;
+    let mut output = solc.standard_json(&input, None, vec![], None)?;
 
     let project = output.try_to_project(
         sources,
         libraries,
         pipeline,
-        &SolcCompiler::LAST_SUPPORTED_VERSION,
+        false,
         None,
+        &SolcCompiler::LAST_SUPPORTED_VERSION,
+        debug_config.as_ref(),
     )?;
     let _build = project.compile_all(
         compiler_llvm_context::TargetMachine::new(&optimizer_settings)?,
         optimizer_settings,
         false,
         false,
-        None,
+        debug_config,
     )?;
 
     Ok(())
@@ -74,10 +141,11 @@ pub fn check_solidity_warning(
         SolcStandardJsonInputSettingsOptimizer::new(true, None),
         None,
         pipeline == SolcPipeline::Yul,
+        None,
     )?;
 
     let solc = SolcCompiler::new("solc".to_owned());
-    let output = solc.standard_json(input, pipeline, None, vec![], None)?;
+    let output = solc.standard_json(&input, None, vec![], None)?;
     let contains_warning = output
         .errors
         .ok_or_else(|| anyhow::anyhow!("Solidity compiler messages not found"))?
@@ -87,7 +155,43 @@ pub fn check_solidity_warning(
     Ok(contains_warning)
 }
 
+pub fn get_solidity_project(
+    source_code: &str,
+    pipeline: SolcPipeline,
+) -> anyhow::Result<(SolcStandardJsonOutput, Project)> {
+    let mut sources = BTreeMap::new();
+    sources.insert("test.sol".to_string(), source_code.to_string());
+    let input = SolcStandardJsonInput::try_from_sources(
+        sources.clone(),
+        BTreeMap::new(),
+        SolcStandardJsonInputSettingsSelection::new_required(pipeline),
+        SolcStandardJsonInputSettingsOptimizer::new(true, None),
+        None,
+        pipeline == SolcPipeline::Yul,
+        None,
+    )?;
+
+    let solc = SolcCompiler::new("solc".to_owned());
+    let mut output = solc.standard_json(&input, None, vec![], None)?;
+
+    let project = output.try_to_project(
+        sources,
+        BTreeMap::new(),
+        pipeline,
+        false,
+        None,
+        &SolcCompiler::LAST_SUPPORTED_VERSION,
+        None,
+    )?;
+
+    Ok((output, project))
+}
+
 pub fn build_yul(source_code: &str) -> anyhow::Result<()> {
+    build_yul_with_mode(source_code, false)
+}
+
+pub fn build_yul_with_mode(source_code: &str, is_system_mode: bool) -> anyhow::Result<()> {
     inkwell::support::enable_llvm_pretty_stack_trace();
     compiler_llvm_context::initialize_target();
     let optimizer_settings = compiler_llvm_context::OptimizerSettings::none();
@@ -96,7 +200,7 @@ pub fn build_yul(source_code: &str) -> anyhow::Result<()> {
     let _build = project.compile_all(
         compiler_llvm_context::TargetMachine::new(&optimizer_settings)?,
         optimizer_settings,
-        false,
+        is_system_mode,
         false,
         None,
     )?;
@@ -7,6 +7,7 @@
 use std::collections::BTreeMap;
 
 use crate::solc::pipeline::Pipeline as SolcPipeline;
+use crate::solc::standard_json::input::settings::evm_version::EVMVersion as SolcStandardJsonInputSettingsEVMVersion;
 
 #[test]
 #[should_panic(expected = "The `CODECOPY` instruction is not supported")]
@@ -164,3 +165,261 @@ fn selfdestruct_yul() {
     super::build_solidity(SELFDESTRUCT_TEST_SOURCE, BTreeMap::new(), SolcPipeline::Yul)
         .expect("Test failure");
 }
+
+pub const TLOAD_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract TransientLoad {
+    function load(uint256 key) public view returns (uint256 value) {
+        assembly {
+            value := tload(key)
+        }
+    }
+}
+    "#;
+
+#[test]
+#[should_panic(expected = "The `TLOAD` instruction is not supported")]
+fn tload_evmla() {
+    super::build_solidity_with_evm_version(
+        TLOAD_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "The `TLOAD` instruction is not supported")]
+fn tload_yul() {
+    super::build_solidity_with_evm_version(
+        TLOAD_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+pub const TSTORE_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract TransientStore {
+    function store(uint256 key, uint256 value) public {
+        assembly {
+            tstore(key, value)
+        }
+    }
+}
+    "#;
+
+#[test]
+#[should_panic(expected = "The `TSTORE` instruction is not supported")]
+fn tstore_evmla() {
+    super::build_solidity_with_evm_version(
+        TSTORE_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "The `TSTORE` instruction is not supported")]
+fn tstore_yul() {
+    super::build_solidity_with_evm_version(
+        TSTORE_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+pub const TRANSIENT_LOCK_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract TransientLock {
+    uint256 public counter;
+
+    modifier nonReentrant() {
+        assembly {
+            if tload(0) { revert(0, 0) }
+            tstore(0, 1)
+        }
+        _;
+        assembly {
+            tstore(0, 0)
+        }
+    }
+
+    function increment() public nonReentrant {
+        counter += 1;
+    }
+}
+    "#;
+
+///
+/// The transient storage cannot be emulated with a dedicated region of the persistent storage, as
+/// zkEVM has no storage cleared at the end of the transaction. The lock above resets its slot, but
+/// the compiler cannot prove it for every contract, and the values left behind would leak into the
+/// next transactions. So even such locks are rejected with a hint to use the persistent storage.
+///
+#[test]
+#[should_panic(
+    expected = "Transient storage is not available on zkEVM yet. Use `sload` and `sstore`"
+)]
+fn transient_lock_evmla() {
+    super::build_solidity_with_evm_version(
+        TRANSIENT_LOCK_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+#[should_panic(
+    expected = "Transient storage is not available on zkEVM yet. Use `sload` and `sstore`"
+)]
+fn transient_lock_yul() {
+    super::build_solidity_with_evm_version(
+        TRANSIENT_LOCK_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+pub const BLOBHASH_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract BlobHash {
+    function hash(uint256 index) public view returns (bytes32 result) {
+        assembly {
+            result := blobhash(index)
+        }
+    }
+}
+    "#;
+
+#[test]
+#[should_panic(expected = "The `BLOBHASH` instruction is not supported")]
+fn blobhash_evmla() {
+    super::build_solidity_with_evm_version(
+        BLOBHASH_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "The `BLOBHASH` instruction is not supported")]
+fn blobhash_yul() {
+    super::build_solidity_with_evm_version(
+        BLOBHASH_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+pub const BLOBBASEFEE_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+contract BlobBaseFee {
+    function fee() public view returns (uint256 result) {
+        assembly {
+            result := blobbasefee()
+        }
+    }
+}
+    "#;
+
+#[test]
+#[should_panic(expected = "The `BLOBBASEFEE` instruction is not supported")]
+fn blobbasefee_evmla() {
+    super::build_solidity_with_evm_version(
+        BLOBBASEFEE_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::EVMLA,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "The `BLOBBASEFEE` instruction is not supported")]
+fn blobbasefee_yul() {
+    super::build_solidity_with_evm_version(
+        BLOBBASEFEE_TEST_SOURCE,
+        BTreeMap::new(),
+        SolcPipeline::Yul,
+        Some(SolcStandardJsonInputSettingsEVMVersion::Cancun),
+    )
+    .expect("Test failure");
+}
+
+pub const MULTIPLE_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract CallcodeTest {
+    function testCallcode(address target) public returns (bool success) {
+        assembly {
+            success := callcode(gas(), target, 0, 0, 0, 0, 0)
+        }
+    }
+}
+
+contract MinimalDestructible {
+    function destroy() public {
+        selfdestruct(payable(msg.sender));
+    }
+}
+    "#;
+
+#[test]
+fn multiple_evmla() {
+    let error = super::build_solidity(MULTIPLE_TEST_SOURCE, BTreeMap::new(), SolcPipeline::EVMLA)
+        .expect_err("Test failure")
+        .to_string();
+    assert!(error.contains("test.sol:CallcodeTest (runtime code instruction #"));
+    assert!(error.contains("The `CALLCODE` instruction is not supported. Use `delegatecall`"));
+    assert!(error.contains("test.sol:MinimalDestructible (runtime code instruction #"));
+    assert!(error.contains("The `SELFDESTRUCT` instruction is not supported"));
+}
+
+#[test]
+fn multiple_evmla_source_locations() {
+    let error = super::build_solidity(MULTIPLE_TEST_SOURCE, BTreeMap::new(), SolcPipeline::EVMLA)
+        .expect_err("Test failure")
+        .to_string();
+    assert!(error.lines().any(|line| {
+        line.contains("test.sol:CallcodeTest (runtime code instruction #")
+            && line.contains(", test.sol:")
+    }));
+}
+
+#[test]
+fn multiple_yul() {
+    let error = super::build_solidity(MULTIPLE_TEST_SOURCE, BTreeMap::new(), SolcPipeline::Yul)
+        .expect_err("Test failure")
+        .to_string();
+    assert!(error.contains("test.sol:CallcodeTest (Yul "));
+    assert!(error.contains("The `CALLCODE` instruction is not supported. Use `delegatecall`"));
+    assert!(error.contains("test.sol:MinimalDestructible (Yul "));
+    assert!(error.contains("The `SELFDESTRUCT` instruction is not supported"));
+}
@@ -0,0 +1,111 @@
+//!
+//! The Solidity compiler unit tests for the verbatim instructions and zkEVM extensions.
+//!
+
+#![cfg(test)]
+
+pub const MUL_512_TEST_SOURCE: &str = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            let low, high := verbatim_2i_2o("mul_512", calldataload(0), calldataload(32))
+            mstore(0, low)
+            mstore(32, high)
+            return(0, 64)
+        }
+    }
+}
+    "#;
+
+#[test]
+fn mul_512() {
+    super::build_yul_with_mode(MUL_512_TEST_SOURCE, true).expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "Verbatim instructions are only allowed in the system mode")]
+fn mul_512_non_system_mode() {
+    super::build_yul_with_mode(MUL_512_TEST_SOURCE, false).expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "Internal function `to_l1` does not return multiple values")]
+fn multiple_return_values_unsupported() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            let a, b := verbatim_3i_2o("to_l1", 0, 0, 0)
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    super::build_yul_with_mode(source_code, true).expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "Internal function `mul_high` expected 2 arguments, found 1")]
+fn invalid_number_of_arguments() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, verbatim_1i_1o("mul_high", calldataload(0)))
+            return(0, 32)
+        }
+    }
+}
+    "#;
+
+    super::build_yul_with_mode(source_code, true).expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "Found unknown internal function `mul_1024`")]
+fn unknown_simulation() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, verbatim_2i_1o("mul_1024", calldataload(0), calldataload(32)))
+            return(0, 32)
+        }
+    }
+}
+    "#;
+
+    super::build_yul_with_mode(source_code, true).expect("Test failure");
+}
+
+#[test]
+fn zkevm_builtin_non_system_mode() {
+    let source_code = r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, $zk_multiplication_high(calldataload(0), calldataload(32)))
+            return(0, 32)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
@@ -0,0 +1,61 @@
+//!
+//! The Solidity compiler unit tests for the Yul type annotations.
+//!
+
+#![cfg(test)]
+
+#[test]
+fn narrow_arithmetic() {
+    let source_code = r#"
+object "Test" {
+    code {
+        function negate(a:int32) -> b:int32 {
+            b := sub(0:int32, a)
+        }
+        function double(a:uint8) -> b:uint8 {
+            b := add(a, a)
+        }
+
+        let x:int32 := negate(5)
+        let y:uint8 := double(200)
+        let z := add(x, y)
+        sstore(0, x)
+        sstore(1, y)
+        sstore(2, z)
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
+
+#[test]
+#[should_panic(expected = "Argument 1 of `negate` expects type `int32`, found `uint8`")]
+fn narrow_argument_mismatch() {
+    let source_code = r#"
+object "Test" {
+    code {
+        function negate(a:int32) -> b:int32 {
+            b := sub(0:int32, a)
+        }
+
+        let x:uint8 := 1
+        sstore(0, negate(x))
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+    super::build_yul(source_code).expect("Test failure");
+}
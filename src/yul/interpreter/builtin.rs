@@ -0,0 +1,538 @@
+//!
+//! The Yul interpreter builtin functions.
+//!
+
+use num::One;
+use num::ToPrimitive;
+use num::Zero;
+use sha3::Digest;
+
+use crate::yul::lexer::token::lexeme::literal::string::String as StringLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::parser::statement::expression::function_call::builtin::simulation::kind::Kind as SimulationKind;
+use crate::yul::parser::statement::expression::function_call::builtin::simulation::Simulation;
+use crate::yul::parser::statement::expression::function_call::builtin::Builtin;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+
+use super::executor::Executor;
+use super::executor::Halt;
+use super::outcome::Outcome;
+use super::word;
+
+impl<'a> Executor<'a> {
+    ///
+    /// Calls the builtin function.
+    ///
+    /// The builtins taking literal arguments, such as `dataoffset` or `loadimmutable`, inspect the
+    /// argument expressions, whereas the rest get the argument values.
+    ///
+    pub fn call_builtin(&mut self, call: &'a FunctionCall) -> Result<Vec<num::BigUint>, Halt> {
+        if let Some(builtin) = Builtin::get(&call.name) {
+            builtin.check_availability(
+                self.environment.is_system_mode,
+                Some(self.code_type),
+                call.location,
+            )?;
+        }
+
+        match call.name {
+            Name::DataOffset => {
+                let identifier = Self::literal_argument(call, 0)?;
+                let value = match self.object.data.get(identifier) {
+                    Some(_) => {
+                        return Err(Self::error(
+                            call.location,
+                            format!(
+                                "`dataoffset` of the data segment `{identifier}` is only supported as the `datacopy` source offset"
+                            ),
+                        ));
+                    }
+                    None if self.is_own_code(identifier) => num::BigUint::zero(),
+                    None => match self.environment.contract_hashes.get(identifier) {
+                        Some(hash) => hash.to_owned(),
+                        None => num::BigUint::from_bytes_be(
+                            sha3::Keccak256::digest(identifier.as_bytes()).as_slice(),
+                        ),
+                    },
+                };
+                return Ok(vec![value]);
+            }
+            Name::DataSize => {
+                let identifier = Self::literal_argument(call, 0)?;
+                let value = match self.object.data.get(identifier) {
+                    Some(segment) => num::BigUint::from(segment.len()),
+                    None if self.is_own_code(identifier) => num::BigUint::zero(),
+                    None => num::BigUint::from(
+                        compiler_common::BYTE_LENGTH_X32 + compiler_common::BYTE_LENGTH_FIELD,
+                    ),
+                };
+                return Ok(vec![value]);
+            }
+            Name::DataCopy => {
+                match self.static_data(&call.arguments[1]) {
+                    Some(mut segment) => {
+                        let size = self.evaluate_single(&call.arguments[2])?;
+                        let destination = self.evaluate_single(&call.arguments[0])?;
+                        if let Some(size) = word::to_usize(&size) {
+                            segment.truncate(size);
+                        }
+                        self.memory_write(&destination, segment.as_slice())?;
+                    }
+                    None => {
+                        let [destination, source, _size]: [num::BigUint; 3] = self
+                            .evaluate_expressions(call.arguments.as_slice())?
+                            .try_into()
+                            .expect("Always valid");
+                        let offset = destination
+                            + (compiler_common::BYTE_LENGTH_X32
+                                + compiler_common::BYTE_LENGTH_FIELD);
+                        self.memory_write(&word::wrap(offset), &word::to_bytes(&source))?;
+                    }
+                }
+                return Ok(vec![]);
+            }
+            Name::LoadImmutable => {
+                let key = Self::literal_argument(call, 0)?;
+                let value = if key == "library_deploy_address" {
+                    self.environment.address.to_owned()
+                } else {
+                    self.state
+                        .immutables
+                        .get(key)
+                        .cloned()
+                        .unwrap_or_else(num::BigUint::zero)
+                };
+                return Ok(vec![value]);
+            }
+            Name::SetImmutable => {
+                let key = Self::literal_argument(call, 1)?;
+                let value = self.evaluate_single(&call.arguments[2])?;
+                self.evaluate_single(&call.arguments[0])?;
+                if key != "library_deploy_address" {
+                    self.state.immutables.insert(key.to_owned(), value);
+                }
+                return Ok(vec![]);
+            }
+            Name::LinkerSymbol => {
+                let path = Self::literal_argument(call, 0)?;
+                let address = self.environment.libraries.get(path).ok_or_else(|| {
+                    Self::error(call.location, format!("Library `{path}` is not linked"))
+                })?;
+                return Ok(vec![address.to_owned()]);
+            }
+            Name::ZkGlobalLoad => {
+                let key = Self::literal_argument(call, 0)?;
+                let value = self
+                    .state
+                    .globals
+                    .get(key)
+                    .cloned()
+                    .unwrap_or_else(num::BigUint::zero);
+                return Ok(vec![value]);
+            }
+            Name::ZkGlobalStore => {
+                let key = Self::literal_argument(call, 0)?;
+                let value = self.evaluate_single(&call.arguments[1])?;
+                self.state.globals.insert(key.to_owned(), value);
+                return Ok(vec![]);
+            }
+            Name::Verbatim { .. } => return self.call_verbatim(call),
+            _ => {}
+        }
+
+        let arguments = self.evaluate_expressions(call.arguments.as_slice())?;
+        self.execute_builtin(call, &call.name, arguments)
+    }
+
+    ///
+    /// Executes the builtin function with the evaluated arguments.
+    ///
+    fn execute_builtin(
+        &mut self,
+        call: &FunctionCall,
+        name: &Name,
+        arguments: Vec<num::BigUint>,
+    ) -> Result<Vec<num::BigUint>, Halt> {
+        let a = arguments.as_slice();
+        let word_size = num::BigUint::from(compiler_common::BYTE_LENGTH_FIELD);
+        let is_deploy_code = matches!(self.code_type, compiler_llvm_context::CodeType::Deploy);
+
+        if let Some(value) = evaluate_pure(name, a) {
+            return Ok(vec![value]);
+        }
+
+        let value = match name {
+            Name::Pop => return Ok(vec![]),
+
+            Name::Keccak256 => {
+                let data = self.memory_read(&a[0], &a[1])?;
+                num::BigUint::from_bytes_be(sha3::Keccak256::digest(data.as_slice()).as_slice())
+            }
+
+            Name::MLoad => num::BigUint::from_bytes_be(&self.memory_read(&a[0], &word_size)?),
+            Name::MStore => {
+                self.memory_write(&a[0], &word::to_bytes(&a[1]))?;
+                return Ok(vec![]);
+            }
+            Name::MStore8 => {
+                let byte = word::to_bytes(&a[1])[compiler_common::BYTE_LENGTH_FIELD - 1];
+                self.memory_write(&a[0], &[byte])?;
+                return Ok(vec![]);
+            }
+            Name::MCopy => {
+                let data = self.memory_read(&a[1], &a[2])?;
+                self.memory_write(&a[0], data.as_slice())?;
+                return Ok(vec![]);
+            }
+            Name::MSize => num::BigUint::from(self.memory.len()),
+
+            Name::SLoad => self.state.storage_value(&a[0]),
+            Name::SStore => {
+                self.state.storage.insert(a[0].to_owned(), a[1].to_owned());
+                return Ok(vec![]);
+            }
+
+            Name::CallDataLoad if is_deploy_code => num::BigUint::zero(),
+            Name::CallDataLoad => num::BigUint::from_bytes_be(
+                Self::slice(
+                    self.environment.calldata.as_slice(),
+                    &a[0],
+                    compiler_common::BYTE_LENGTH_FIELD,
+                )
+                .as_slice(),
+            ),
+            Name::CallDataSize if is_deploy_code => num::BigUint::zero(),
+            Name::CallDataSize => num::BigUint::from(self.environment.calldata.len()),
+            Name::CallDataCopy => {
+                let source: &[u8] = if is_deploy_code {
+                    &[]
+                } else {
+                    self.environment.calldata.as_slice()
+                };
+                let data = Self::slice(source, &a[1], self.copy_size(call, &a[2])?);
+                self.memory_write(&a[0], data.as_slice())?;
+                return Ok(vec![]);
+            }
+            Name::CodeSize if is_deploy_code => num::BigUint::from(self.environment.calldata.len()),
+            Name::CodeCopy => {
+                let data = Self::slice(
+                    self.environment.calldata.as_slice(),
+                    &a[1],
+                    self.copy_size(call, &a[2])?,
+                );
+                self.memory_write(&a[0], data.as_slice())?;
+                return Ok(vec![]);
+            }
+            Name::ReturnDataSize => num::BigUint::from(self.return_data.len()),
+            Name::ReturnDataCopy => {
+                let data = Self::slice(
+                    self.return_data.as_slice(),
+                    &a[1],
+                    self.copy_size(call, &a[2])?,
+                );
+                self.memory_write(&a[0], data.as_slice())?;
+                return Ok(vec![]);
+            }
+
+            Name::Return => {
+                let data = self.memory_read(&a[0], &a[1])?;
+                return Err(Halt::Outcome(Outcome::Return(data)));
+            }
+            Name::Revert => {
+                let data = self.memory_read(&a[0], &a[1])?;
+                return Err(Halt::Outcome(Outcome::Revert(data)));
+            }
+            Name::Stop => return Err(Halt::Outcome(Outcome::Stop)),
+            Name::Invalid => return Err(Halt::Outcome(Outcome::Invalid)),
+
+            Name::Log0 | Name::Log1 | Name::Log2 | Name::Log3 | Name::Log4 => {
+                let data = self.memory_read(&a[0], &a[1])?;
+                self.state.logs.push((a[2..].to_vec(), data));
+                return Ok(vec![]);
+            }
+
+            Name::MemoryGuard => a[0].to_owned(),
+
+            Name::Address => self.environment.address.to_owned(),
+            Name::Caller => self.environment.caller.to_owned(),
+            Name::CallValue => self.environment.call_value.to_owned(),
+            Name::Gas => self.environment.gas.to_owned(),
+            Name::Balance => self.balance(&a[0]),
+            Name::SelfBalance => self.balance(&self.environment.address),
+            Name::GasLimit => self.environment.gas_limit.to_owned(),
+            Name::GasPrice => self.environment.gas_price.to_owned(),
+            Name::Origin => self.environment.origin.to_owned(),
+            Name::ChainId => self.environment.chain_id.to_owned(),
+            Name::Number => self.environment.block_number.to_owned(),
+            Name::Timestamp => self.environment.block_timestamp.to_owned(),
+            Name::BlockHash => num::BigUint::zero(),
+            Name::Difficulty | Name::Prevrandao => self.environment.difficulty.to_owned(),
+            Name::CoinBase => self.environment.coinbase.to_owned(),
+            Name::BaseFee => self.environment.base_fee.to_owned(),
+
+            Name::ZkToL1 => {
+                self.state
+                    .l1_messages
+                    .push([a[0].to_owned(), a[1].to_owned(), a[2].to_owned()]);
+                num::BigUint::one()
+            }
+            Name::ZkCodeSource => self.environment.address.to_owned(),
+            Name::ZkMeta => self.environment.meta.to_owned(),
+            Name::ZkSetContextU128 => {
+                self.state.context_u128 = a[0].to_owned();
+                return Ok(vec![]);
+            }
+            Name::ZkSetPubdataPrice => {
+                self.state.pubdata_price = a[0].to_owned();
+                return Ok(vec![]);
+            }
+            Name::ZkIncrementTxCounter => {
+                self.state.tx_counter = word::wrap(&self.state.tx_counter + num::BigUint::one());
+                return Ok(vec![]);
+            }
+            Name::ZkEventInitialize => {
+                self.state.events.push(a.to_vec());
+                return Ok(vec![]);
+            }
+            Name::ZkEventWrite => {
+                match self.state.events.last_mut() {
+                    Some(event) => event.extend_from_slice(a),
+                    None => self.state.events.push(a.to_vec()),
+                }
+                return Ok(vec![]);
+            }
+            Name::ZkLoadCalldataIntoActivePtr => {
+                self.active_pointer = (self.environment.calldata.to_owned(), 0);
+                return Ok(vec![]);
+            }
+            Name::ZkLoadReturndataIntoActivePtr => {
+                self.active_pointer = (self.return_data.to_owned(), 0);
+                return Ok(vec![]);
+            }
+            Name::ZkPtrAddIntoActive => {
+                let offset = word::to_usize(&a[0]).unwrap_or(usize::MAX);
+                self.active_pointer.1 = self.active_pointer.1.saturating_add(offset);
+                return Ok(vec![]);
+            }
+            Name::ZkPtrShrinkIntoActive => {
+                let size = word::to_usize(&a[0]).unwrap_or(usize::MAX);
+                let length = self.active_pointer.0.len().saturating_sub(size);
+                self.active_pointer.0.truncate(length);
+                return Ok(vec![]);
+            }
+            Name::ZkPtrPackIntoActive => return Ok(vec![]),
+            Name::ZkMultiplicationHigh => word::multiplication_high(&a[0], &a[1]),
+            Name::ZkGlobalExtraAbiData => a[0]
+                .to_usize()
+                .and_then(|index| self.environment.extra_abi_data.get(index))
+                .cloned()
+                .unwrap_or_else(num::BigUint::zero),
+
+            _ => return Err(Self::unsupported(call)),
+        };
+
+        Ok(vec![value])
+    }
+
+    ///
+    /// Calls the verbatim instruction, modelling the subset without external effects.
+    ///
+    fn call_verbatim(&mut self, call: &'a FunctionCall) -> Result<Vec<num::BigUint>, Halt> {
+        if !self.environment.is_system_mode {
+            return Err(Self::error(
+                call.location,
+                "Verbatim instructions are only allowed in the system mode".to_owned(),
+            ));
+        }
+
+        let identifier = Self::literal_argument(call, 0)?;
+        let simulation = Simulation::get(identifier).ok_or_else(|| {
+            Self::error(
+                call.location,
+                format!("Found unknown internal function `{identifier}`"),
+            )
+        })?;
+        let arguments = self.evaluate_expressions(&call.arguments[1..])?;
+        if arguments.len() != simulation.input_size {
+            return Err(Self::error(
+                call.location,
+                format!(
+                    "Internal function `{}` expected {} arguments, found {}",
+                    identifier,
+                    simulation.input_size,
+                    arguments.len()
+                ),
+            ));
+        }
+
+        let name = match simulation.kind {
+            SimulationKind::Mul512 => {
+                let product = &arguments[0] * &arguments[1];
+                return Ok(vec![
+                    word::wrap(product.to_owned()),
+                    product >> compiler_common::BIT_LENGTH_FIELD,
+                ]);
+            }
+            SimulationKind::Throw => return Err(Halt::Outcome(Outcome::Revert(vec![]))),
+            SimulationKind::MulHigh => Name::ZkMultiplicationHigh,
+            SimulationKind::ToL1 => Name::ZkToL1,
+            SimulationKind::CodeSource => Name::ZkCodeSource,
+            SimulationKind::Meta => Name::ZkMeta,
+            SimulationKind::SetContextU128 => Name::ZkSetContextU128,
+            SimulationKind::SetPubdataPrice => Name::ZkSetPubdataPrice,
+            SimulationKind::IncrementTxCounter => Name::ZkIncrementTxCounter,
+            SimulationKind::EventInitialize => Name::ZkEventInitialize,
+            SimulationKind::EventWrite => Name::ZkEventWrite,
+            SimulationKind::CalldataPtrToActive => Name::ZkLoadCalldataIntoActivePtr,
+            SimulationKind::ReturnDataPtrToActive => Name::ZkLoadReturndataIntoActivePtr,
+            SimulationKind::ActivePtrAddAssign => Name::ZkPtrAddIntoActive,
+            SimulationKind::ActivePtrShrinkAssign => Name::ZkPtrShrinkIntoActive,
+            SimulationKind::ActivePtrPackAssign => Name::ZkPtrPackIntoActive,
+            _ => return Err(Self::unsupported(call)),
+        };
+
+        self.execute_builtin(call, &name, arguments)
+    }
+
+    ///
+    /// Returns the string literal argument at `index`.
+    ///
+    fn literal_argument(call: &'a FunctionCall, index: usize) -> Result<&'a str, Halt> {
+        match call.arguments.get(index) {
+            Some(Expression::Literal(Literal {
+                inner: LexicalLiteral::String(StringLiteral { inner, .. }),
+                ..
+            })) => Ok(inner.as_str()),
+            _ => Err(Self::error(
+                call.location,
+                format!("`{}` literal is missing", call.name),
+            )),
+        }
+    }
+
+    ///
+    /// Returns the data segment if the `datacopy` source is static, that is, a `dataoffset` of a
+    /// data segment.
+    ///
+    fn static_data(&self, source: &Expression) -> Option<Vec<u8>> {
+        match source {
+            Expression::FunctionCall(call) if call.name == Name::DataOffset => {
+                match call.arguments.first() {
+                    Some(Expression::Literal(Literal {
+                        inner: LexicalLiteral::String(StringLiteral { inner, .. }),
+                        ..
+                    })) => self.object.data.get(inner.as_str()).cloned(),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Whether the `dataoffset` or `datasize` identifier references the code being executed or
+    /// its runtime code, which are not separate contracts in zkEVM.
+    ///
+    fn is_own_code(&self, identifier: &str) -> bool {
+        identifier.ends_with("_deployed") || identifier == self.object.identifier
+    }
+
+    ///
+    /// Returns the balance of the `address`.
+    ///
+    fn balance(&self, address: &num::BigUint) -> num::BigUint {
+        self.environment
+            .balances
+            .get(address)
+            .cloned()
+            .unwrap_or_else(num::BigUint::zero)
+    }
+
+    ///
+    /// Checks the size of the memory copying.
+    ///
+    fn copy_size(&self, call: &FunctionCall, size: &num::BigUint) -> Result<usize, Halt> {
+        match word::to_usize(size) {
+            Some(size) if size <= Self::MEMORY_LIMIT => Ok(size),
+            _ => Err(Self::error(
+                call.location,
+                format!("Copying of {size} bytes exceeds the memory limit"),
+            )),
+        }
+    }
+
+    ///
+    /// Returns `size` bytes of the `data` starting at `offset`, padded with zeros.
+    ///
+    fn slice(data: &[u8], offset: &num::BigUint, size: usize) -> Vec<u8> {
+        let mut result = vec![0u8; size];
+        if let Some(offset) = word::to_usize(offset) {
+            if offset < data.len() {
+                let length = (data.len() - offset).min(size);
+                result[..length].copy_from_slice(&data[offset..offset + length]);
+            }
+        }
+        result
+    }
+
+    ///
+    /// Creates the error for the builtin whose effects are not modelled.
+    ///
+    fn unsupported(call: &FunctionCall) -> Halt {
+        Self::error(
+            call.location,
+            format!(
+                "The `{}` instruction is not modelled by the interpreter",
+                call.name
+            ),
+        )
+    }
+}
+
+///
+/// Evaluates the pure arithmetic, comparison, and bitwise builtin, returning `None` for the rest.
+///
+/// It is shared with the constant folding, so both agree on the EVM semantics.
+///
+pub fn evaluate_pure(name: &Name, a: &[num::BigUint]) -> Option<num::BigUint> {
+    let value = match name {
+        Name::Add => word::wrap(&a[0] + &a[1]),
+        Name::Sub => word::wrap(&a[0] + word::modulus() - &a[1]),
+        Name::Mul => word::wrap(&a[0] * &a[1]),
+        Name::Div if a[1].is_zero() => num::BigUint::zero(),
+        Name::Div => &a[0] / &a[1],
+        Name::Mod if a[1].is_zero() => num::BigUint::zero(),
+        Name::Mod => &a[0] % &a[1],
+        Name::Sdiv => word::signed_division(&a[0], &a[1]),
+        Name::Smod => word::signed_remainder(&a[0], &a[1]),
+
+        Name::Lt => word::from_bool(a[0] < a[1]),
+        Name::Gt => word::from_bool(a[0] > a[1]),
+        Name::Eq => word::from_bool(a[0] == a[1]),
+        Name::IsZero => word::from_bool(a[0].is_zero()),
+        Name::Slt => word::from_bool(word::to_signed(&a[0]) < word::to_signed(&a[1])),
+        Name::Sgt => word::from_bool(word::to_signed(&a[0]) > word::to_signed(&a[1])),
+
+        Name::Or => &a[0] | &a[1],
+        Name::Xor => &a[0] ^ &a[1],
+        Name::Not => word::modulus() - num::BigUint::one() - &a[0],
+        Name::And => &a[0] & &a[1],
+        Name::Shl => word::shift_left(&a[0], &a[1]),
+        Name::Shr => word::shift_right(&a[0], &a[1]),
+        Name::Sar => word::shift_right_arithmetic(&a[0], &a[1]),
+        Name::Byte => word::byte(&a[0], &a[1]),
+
+        Name::AddMod if a[2].is_zero() => num::BigUint::zero(),
+        Name::AddMod => (&a[0] + &a[1]) % &a[2],
+        Name::MulMod if a[2].is_zero() => num::BigUint::zero(),
+        Name::MulMod => (&a[0] * &a[1]) % &a[2],
+        Name::Exp => word::exponent(&a[0], &a[1]),
+        Name::SignExtend => word::sign_extend(&a[0], &a[1]),
+
+        _ => return None,
+    };
+    Some(value)
+}
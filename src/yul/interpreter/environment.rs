@@ -0,0 +1,91 @@
+//!
+//! The Yul interpreter execution environment.
+//!
+
+use std::collections::BTreeMap;
+
+use num::Zero;
+
+///
+/// The Yul interpreter execution environment.
+///
+/// Describes the read-only context of the simulated call, which is not changed by the executed code.
+///
+#[derive(Debug, Clone)]
+pub struct Environment {
+    /// The address of the executed contract.
+    pub address: num::BigUint,
+    /// The caller address.
+    pub caller: num::BigUint,
+    /// The transaction origin address.
+    pub origin: num::BigUint,
+    /// The call value.
+    pub call_value: num::BigUint,
+    /// The calldata, which contains the constructor arguments in the deploy code.
+    pub calldata: Vec<u8>,
+    /// The contract balances.
+    pub balances: BTreeMap<num::BigUint, num::BigUint>,
+    /// The value returned by `gas`.
+    pub gas: num::BigUint,
+    /// The block gas limit.
+    pub gas_limit: num::BigUint,
+    /// The gas price.
+    pub gas_price: num::BigUint,
+    /// The chain ID.
+    pub chain_id: num::BigUint,
+    /// The block number.
+    pub block_number: num::BigUint,
+    /// The block timestamp.
+    pub block_timestamp: num::BigUint,
+    /// The block difficulty, also returned by `prevrandao`.
+    pub difficulty: num::BigUint,
+    /// The block coinbase address.
+    pub coinbase: num::BigUint,
+    /// The block base fee.
+    pub base_fee: num::BigUint,
+    /// The value returned by `$zk_meta`.
+    pub meta: num::BigUint,
+    /// The extra ABI data returned by `$zk_global_extra_abi_data`.
+    pub extra_abi_data: Vec<num::BigUint>,
+    /// The linked library addresses.
+    pub libraries: BTreeMap<String, num::BigUint>,
+    /// The contract hashes returned by `dataoffset`, which default to the identifier hash.
+    pub contract_hashes: BTreeMap<String, num::BigUint>,
+    /// Whether the system mode builtins are allowed.
+    pub is_system_mode: bool,
+    /// The maximum number of executed statements and loop iterations.
+    pub step_limit: usize,
+}
+
+impl Environment {
+    /// The default maximum number of executed statements and loop iterations.
+    pub const STEP_LIMIT_DEFAULT: usize = 1_000_000;
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self {
+            address: num::BigUint::zero(),
+            caller: num::BigUint::zero(),
+            origin: num::BigUint::zero(),
+            call_value: num::BigUint::zero(),
+            calldata: vec![],
+            balances: BTreeMap::new(),
+            gas: num::BigUint::from(u32::MAX),
+            gas_limit: num::BigUint::from(u32::MAX),
+            gas_price: num::BigUint::zero(),
+            chain_id: num::BigUint::zero(),
+            block_number: num::BigUint::zero(),
+            block_timestamp: num::BigUint::zero(),
+            difficulty: num::BigUint::zero(),
+            coinbase: num::BigUint::zero(),
+            base_fee: num::BigUint::zero(),
+            meta: num::BigUint::zero(),
+            extra_abi_data: vec![num::BigUint::zero(); compiler_llvm_context::EXTRA_ABI_DATA_SIZE],
+            libraries: BTreeMap::new(),
+            contract_hashes: BTreeMap::new(),
+            is_system_mode: false,
+            step_limit: Self::STEP_LIMIT_DEFAULT,
+        }
+    }
+}
@@ -0,0 +1,588 @@
+//!
+//! The Yul interpreter executor.
+//!
+
+use std::collections::HashMap;
+
+use num::Num;
+use num::Zero;
+
+use crate::yul::lexer::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::Statement;
+
+use super::environment::Environment;
+use super::outcome::Outcome;
+use super::state::State;
+use super::word;
+
+///
+/// The reason of the execution interruption.
+///
+#[derive(Debug)]
+pub enum Halt {
+    /// The execution has finished with the outcome.
+    Outcome(Outcome),
+    /// The execution has failed, e.g. due to an unsupported instruction.
+    Error(anyhow::Error),
+}
+
+impl From<anyhow::Error> for Halt {
+    fn from(error: anyhow::Error) -> Self {
+        Self::Error(error)
+    }
+}
+
+///
+/// The statement control flow.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    /// Proceed to the next statement.
+    Normal,
+    /// Exit the innermost loop.
+    Break,
+    /// Proceed to the next iteration of the innermost loop.
+    Continue,
+    /// Exit the current function.
+    Leave,
+}
+
+///
+/// The Yul interpreter executor.
+///
+/// Executes a single code section, holding the volatile memory and the variable scopes.
+///
+#[derive(Debug)]
+pub struct Executor<'a> {
+    /// The execution environment.
+    pub environment: &'a Environment,
+    /// The persistent state.
+    pub state: &'a mut State,
+    /// The executed object, which provides the data segments.
+    pub object: &'a Object,
+    /// The executed code part.
+    pub code_type: compiler_llvm_context::CodeType,
+    /// The heap memory.
+    pub memory: Vec<u8>,
+    /// The return data of the last call, which is always empty, as calls are not modelled.
+    pub return_data: Vec<u8>,
+    /// The active pointer data and offset.
+    pub active_pointer: (Vec<u8>, usize),
+    /// The variable values, grouped by scope.
+    variables: Vec<HashMap<String, num::BigUint>>,
+    /// The functions, grouped by scope.
+    functions: Vec<HashMap<&'a str, &'a FunctionDefinition>>,
+    /// The current function call depth.
+    depth: usize,
+    /// The number of executed steps.
+    steps: usize,
+}
+
+impl<'a> Executor<'a> {
+    /// The maximum function call depth.
+    pub const DEPTH_LIMIT: usize = 1024;
+
+    /// The maximum heap memory size in bytes.
+    pub const MEMORY_LIMIT: usize = 1 << 24;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        environment: &'a Environment,
+        state: &'a mut State,
+        object: &'a Object,
+        code_type: compiler_llvm_context::CodeType,
+    ) -> Self {
+        Self {
+            environment,
+            state,
+            object,
+            code_type,
+            memory: vec![],
+            return_data: vec![],
+            active_pointer: (vec![], 0),
+            variables: vec![],
+            functions: vec![],
+            depth: 0,
+            steps: 0,
+        }
+    }
+
+    ///
+    /// Executes the code until it terminates or reaches its end.
+    ///
+    pub fn run(mut self, code: &'a Code) -> anyhow::Result<Outcome> {
+        match self.execute_block(&code.block) {
+            Ok(_) => Ok(Outcome::Stop),
+            Err(Halt::Outcome(outcome)) => Ok(outcome),
+            Err(Halt::Error(error)) => Err(error),
+        }
+    }
+
+    ///
+    /// Evaluates the expression, which must produce exactly one value.
+    ///
+    pub fn evaluate_single(&mut self, expression: &'a Expression) -> Result<num::BigUint, Halt> {
+        let mut values = self.evaluate_count(expression, 1)?;
+        Ok(values.remove(0))
+    }
+
+    ///
+    /// Evaluates the expressions from right to left, as the code generator does, and returns
+    /// their values in the original order.
+    ///
+    pub fn evaluate_expressions(
+        &mut self,
+        expressions: &'a [Expression],
+    ) -> Result<Vec<num::BigUint>, Halt> {
+        let mut values = Vec::with_capacity(expressions.len());
+        for expression in expressions.iter().rev() {
+            values.push(self.evaluate_single(expression)?);
+        }
+        values.reverse();
+        Ok(values)
+    }
+
+    ///
+    /// Reads the heap memory range, expanding the memory if necessary.
+    ///
+    pub fn memory_read(
+        &mut self,
+        offset: &num::BigUint,
+        size: &num::BigUint,
+    ) -> Result<Vec<u8>, Halt> {
+        let (offset, size) = self.memory_expand(offset, size)?;
+        Ok(self.memory[offset..offset + size].to_vec())
+    }
+
+    ///
+    /// Writes the data to the heap memory, expanding the memory if necessary.
+    ///
+    pub fn memory_write(&mut self, offset: &num::BigUint, data: &[u8]) -> Result<(), Halt> {
+        let (offset, size) = self.memory_expand(offset, &num::BigUint::from(data.len()))?;
+        self.memory[offset..offset + size].copy_from_slice(data);
+        Ok(())
+    }
+
+    ///
+    /// Executes the block in a new scope.
+    ///
+    fn execute_block(&mut self, block: &'a Block) -> Result<Flow, Halt> {
+        self.functions.push(Self::hoist_functions(block));
+        self.variables.push(HashMap::new());
+        let flow = self.execute_statements(block.statements.as_slice());
+        self.variables.pop();
+        self.functions.pop();
+        flow
+    }
+
+    ///
+    /// Executes the statements in the current scope.
+    ///
+    fn execute_statements(&mut self, statements: &'a [Statement]) -> Result<Flow, Halt> {
+        for statement in statements.iter() {
+            match self.execute_statement(statement)? {
+                Flow::Normal => continue,
+                flow => return Ok(flow),
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    ///
+    /// Executes the statement.
+    ///
+    fn execute_statement(&mut self, statement: &'a Statement) -> Result<Flow, Halt> {
+        self.step()?;
+
+        match statement {
+            Statement::Block(block) => self.execute_block(block),
+            Statement::Expression(expression) => {
+                self.evaluate(expression)?;
+                Ok(Flow::Normal)
+            }
+            Statement::VariableDeclaration(declaration) => {
+                let values = match declaration.expression.as_ref() {
+                    Some(expression) => {
+                        self.evaluate_count(expression, declaration.bindings.len())?
+                    }
+                    None => vec![num::BigUint::zero(); declaration.bindings.len()],
+                };
+                let scope = self.variables.last_mut().expect("Always exists");
+                for (binding, value) in declaration.bindings.iter().zip(values) {
+                    scope.insert(binding.inner.to_owned(), value);
+                }
+                Ok(Flow::Normal)
+            }
+            Statement::Assignment(assignment) => {
+                let values =
+                    self.evaluate_count(&assignment.initializer, assignment.bindings.len())?;
+                for (binding, value) in assignment.bindings.iter().zip(values) {
+                    self.assign(binding, value)?;
+                }
+                Ok(Flow::Normal)
+            }
+            Statement::IfConditional(conditional) => {
+                if self.evaluate_single(&conditional.condition)?.is_zero() {
+                    Ok(Flow::Normal)
+                } else {
+                    self.execute_block(&conditional.block)
+                }
+            }
+            Statement::Switch(switch) => {
+                let scrutinee = self.evaluate_single(&switch.expression)?;
+                for case in switch.cases.iter() {
+                    if Self::literal_value(&case.literal)? == scrutinee {
+                        return self.execute_block(&case.block);
+                    }
+                }
+                match switch.default.as_ref() {
+                    Some(default) => self.execute_block(default),
+                    None => Ok(Flow::Normal),
+                }
+            }
+            Statement::ForLoop(for_loop) => {
+                self.functions
+                    .push(Self::hoist_functions(&for_loop.initializer));
+                self.variables.push(HashMap::new());
+                let flow = self.execute_for_loop(for_loop);
+                self.variables.pop();
+                self.functions.pop();
+                flow
+            }
+            Statement::Continue(_) => Ok(Flow::Continue),
+            Statement::Break(_) => Ok(Flow::Break),
+            Statement::Leave(_) => Ok(Flow::Leave),
+            Statement::FunctionDefinition(_) | Statement::Object(_) | Statement::Code(_) => {
+                Ok(Flow::Normal)
+            }
+        }
+    }
+
+    ///
+    /// Executes the `for` loop, whose initializer variables are declared in the current scope.
+    ///
+    fn execute_for_loop(&mut self, for_loop: &'a ForLoop) -> Result<Flow, Halt> {
+        if let Flow::Leave = self.execute_statements(for_loop.initializer.statements.as_slice())? {
+            return Ok(Flow::Leave);
+        }
+
+        loop {
+            self.step()?;
+
+            if self.evaluate_single(&for_loop.condition)?.is_zero() {
+                break;
+            }
+
+            match self.execute_block(&for_loop.body)? {
+                Flow::Break => break,
+                Flow::Leave => return Ok(Flow::Leave),
+                Flow::Normal | Flow::Continue => {}
+            }
+
+            if let Flow::Leave = self.execute_block(&for_loop.finalizer)? {
+                return Ok(Flow::Leave);
+            }
+        }
+
+        Ok(Flow::Normal)
+    }
+
+    ///
+    /// Evaluates the expression.
+    ///
+    fn evaluate(&mut self, expression: &'a Expression) -> Result<Vec<num::BigUint>, Halt> {
+        match expression {
+            Expression::Literal(literal) => Ok(vec![Self::literal_value(literal)?]),
+            Expression::Identifier(identifier) => self
+                .variables
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(identifier.inner.as_str()))
+                .map(|value| vec![value.to_owned()])
+                .ok_or_else(|| {
+                    Self::error(
+                        identifier.location,
+                        format!("Undeclared variable `{}`", identifier.inner),
+                    )
+                }),
+            Expression::FunctionCall(call) => match call.name {
+                Name::UserDefined(ref name) => {
+                    let function = self
+                        .functions
+                        .iter()
+                        .rev()
+                        .find_map(|scope| scope.get(name.as_str()).copied())
+                        .ok_or_else(|| {
+                            Self::error(call.location, format!("Undeclared function `{name}`"))
+                        })?;
+                    let arguments = self.evaluate_expressions(call.arguments.as_slice())?;
+                    if arguments.len() != function.arguments.len() {
+                        return Err(Self::error(
+                            call.location,
+                            format!(
+                                "Function `{}` expected {} arguments, found {}",
+                                name,
+                                function.arguments.len(),
+                                arguments.len()
+                            ),
+                        ));
+                    }
+                    self.call_function(call, function, arguments)
+                }
+                _ => self.call_builtin(call),
+            },
+        }
+    }
+
+    ///
+    /// Evaluates the expression, which must produce exactly `count` values.
+    ///
+    fn evaluate_count(
+        &mut self,
+        expression: &'a Expression,
+        count: usize,
+    ) -> Result<Vec<num::BigUint>, Halt> {
+        let values = self.evaluate(expression)?;
+        if values.len() != count {
+            return Err(Self::error(
+                expression.location(),
+                format!("Expected {} values, found {}", count, values.len()),
+            ));
+        }
+        Ok(values)
+    }
+
+    ///
+    /// Calls the user-defined function in a fresh variable scope.
+    ///
+    fn call_function(
+        &mut self,
+        call: &'a FunctionCall,
+        function: &'a FunctionDefinition,
+        arguments: Vec<num::BigUint>,
+    ) -> Result<Vec<num::BigUint>, Halt> {
+        if self.depth >= Self::DEPTH_LIMIT {
+            return Err(Self::error(
+                call.location,
+                format!("Call depth limit of {} exceeded", Self::DEPTH_LIMIT),
+            ));
+        }
+
+        let mut scope = HashMap::with_capacity(function.arguments.len() + function.result.len());
+        for (argument, value) in function.arguments.iter().zip(arguments) {
+            scope.insert(argument.inner.to_owned(), value);
+        }
+        for result in function.result.iter() {
+            scope.insert(result.inner.to_owned(), num::BigUint::zero());
+        }
+
+        self.depth += 1;
+        let outer = std::mem::replace(&mut self.variables, vec![scope]);
+        let flow = self.execute_block(&function.body);
+        let mut scope = std::mem::replace(&mut self.variables, outer);
+        self.depth -= 1;
+        flow?;
+
+        let scope = scope.pop().expect("Always exists");
+        Ok(function
+            .result
+            .iter()
+            .map(|result| {
+                scope
+                    .get(result.inner.as_str())
+                    .cloned()
+                    .unwrap_or_else(num::BigUint::zero)
+            })
+            .collect())
+    }
+
+    ///
+    /// Assigns the value to the variable in the innermost scope that declares it.
+    ///
+    fn assign(&mut self, binding: &Identifier, value: num::BigUint) -> Result<(), Halt> {
+        match self
+            .variables
+            .iter_mut()
+            .rev()
+            .find_map(|scope| scope.get_mut(binding.inner.as_str()))
+        {
+            Some(variable) => {
+                *variable = value;
+                Ok(())
+            }
+            None => Err(Self::error(
+                binding.location,
+                format!("Assignment to an undeclared variable `{}`", binding.inner),
+            )),
+        }
+    }
+
+    ///
+    /// Expands the heap memory to cover the range, rounding its size up to a whole word.
+    ///
+    fn memory_expand(
+        &mut self,
+        offset: &num::BigUint,
+        size: &num::BigUint,
+    ) -> Result<(usize, usize), Halt> {
+        if size.is_zero() {
+            return Ok((0, 0));
+        }
+
+        let (offset, size) = match (word::to_usize(offset), word::to_usize(size)) {
+            (Some(offset), Some(size)) if offset + size <= Self::MEMORY_LIMIT => (offset, size),
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Memory access at offset {} of size {} exceeds the limit of {} bytes",
+                    offset,
+                    size,
+                    Self::MEMORY_LIMIT
+                )
+                .into())
+            }
+        };
+
+        let end = offset + size;
+        if end > self.memory.len() {
+            let length = (end + compiler_common::BYTE_LENGTH_FIELD - 1)
+                / compiler_common::BYTE_LENGTH_FIELD
+                * compiler_common::BYTE_LENGTH_FIELD;
+            self.memory.resize(length, 0);
+        }
+
+        Ok((offset, size))
+    }
+
+    ///
+    /// Counts the executed step, failing if the limit is exceeded.
+    ///
+    fn step(&mut self) -> Result<(), Halt> {
+        self.steps += 1;
+        if self.steps > self.environment.step_limit {
+            return Err(
+                anyhow::anyhow!("Step limit of {} exceeded", self.environment.step_limit).into(),
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Collects the function definitions of the block, which are visible in the whole block.
+    ///
+    fn hoist_functions(block: &'a Block) -> HashMap<&'a str, &'a FunctionDefinition> {
+        block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDefinition(function) => {
+                    Some((function.identifier.as_str(), function))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the value of the literal.
+    ///
+    /// The string literals are left-aligned in the word, whereas the ones longer than a word are
+    /// zero, like in the code generator.
+    ///
+    pub fn literal_value(literal: &Literal) -> Result<num::BigUint, Halt> {
+        match literal.inner {
+            LexicalLiteral::Boolean(BooleanLiteral::False) => Ok(num::BigUint::zero()),
+            LexicalLiteral::Boolean(BooleanLiteral::True) => Ok(word::from_bool(true)),
+            LexicalLiteral::Integer(IntegerLiteral::Decimal { ref inner }) => {
+                num::BigUint::from_str_radix(inner.as_str(), compiler_common::BASE_DECIMAL)
+                    .map(word::wrap)
+                    .map_err(|error| Self::error(literal.location, error.to_string()))
+            }
+            LexicalLiteral::Integer(IntegerLiteral::Hexadecimal { ref inner }) => {
+                num::BigUint::from_str_radix(
+                    &inner["0x".len()..],
+                    compiler_common::BASE_HEXADECIMAL,
+                )
+                .map(word::wrap)
+                .map_err(|error| Self::error(literal.location, error.to_string()))
+            }
+            LexicalLiteral::String(ref inner) => {
+                let bytes = if inner.is_hexadecimal {
+                    hex::decode(inner.inner.as_str())
+                        .map_err(|error| Self::error(literal.location, error.to_string()))?
+                } else {
+                    Self::unescape(inner.inner.as_str())
+                };
+                if bytes.len() > compiler_common::BYTE_LENGTH_FIELD {
+                    return Ok(num::BigUint::zero());
+                }
+
+                let mut word = [0u8; compiler_common::BYTE_LENGTH_FIELD];
+                word[..bytes.len()].copy_from_slice(bytes.as_slice());
+                Ok(num::BigUint::from_bytes_be(&word))
+            }
+        }
+    }
+
+    ///
+    /// Resolves the escape sequences of the string literal.
+    ///
+    fn unescape(string: &str) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(string.len());
+        let mut characters = string.chars().peekable();
+        while let Some(character) = characters.next() {
+            if character != '\\' {
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                continue;
+            }
+
+            match characters.next() {
+                Some('x') => {
+                    let code: String = characters.by_ref().take(2).collect();
+                    bytes.push(u8::from_str_radix(code.as_str(), 16).unwrap_or_default());
+                }
+                Some('u') => {
+                    let code: String = characters.by_ref().take(4).collect();
+                    let character = u32::from_str_radix(code.as_str(), 16)
+                        .ok()
+                        .and_then(char::from_u32)
+                        .unwrap_or_default();
+                    let mut buffer = [0u8; 4];
+                    bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                }
+                Some('n') => bytes.push(b'\n'),
+                Some('t') => bytes.push(b'\t'),
+                Some('r') => bytes.push(b'\r'),
+                Some('\n') => {}
+                Some(character) => {
+                    let mut buffer = [0u8; 4];
+                    bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                }
+                None => bytes.push(b'\\'),
+            }
+        }
+        bytes
+    }
+
+    ///
+    /// Creates an execution error at the location.
+    ///
+    pub fn error(location: Location, message: String) -> Halt {
+        Halt::Error(anyhow::anyhow!("{} {}", location, message))
+    }
+}
@@ -0,0 +1,306 @@
+//!
+//! The Yul reference interpreter.
+//!
+
+pub mod builtin;
+pub mod environment;
+pub mod executor;
+pub mod outcome;
+pub mod state;
+pub mod word;
+
+use crate::yul::parser::statement::object::Object;
+
+use self::environment::Environment;
+use self::executor::Executor;
+use self::outcome::Outcome;
+use self::state::State;
+
+///
+/// The Yul reference interpreter.
+///
+/// Executes the Yul AST directly in a simulated environment, so the observable behavior of a
+/// contract can be compared before and after an optimization or code generation change without
+/// running a node. The heap memory is cleared before each execution, whereas the storage and
+/// other persistent effects are kept in the state.
+///
+/// The model follows the zkEVM semantics where they differ from the EVM ones, e.g. the calldata is
+/// empty in the deploy code, whereas `codecopy` reads the constructor arguments. The external
+/// calls and contract creation are not modelled and result in an error.
+///
+#[derive(Debug)]
+pub struct Interpreter {
+    /// The execution environment.
+    pub environment: Environment,
+    /// The persistent state.
+    pub state: State,
+}
+
+impl Interpreter {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(environment: Environment) -> Self {
+        Self {
+            environment,
+            state: State::default(),
+        }
+    }
+
+    ///
+    /// Executes the deploy code of the `object`.
+    ///
+    pub fn run_deploy(&mut self, object: &Object) -> anyhow::Result<Outcome> {
+        Executor::new(
+            &self.environment,
+            &mut self.state,
+            object,
+            compiler_llvm_context::CodeType::Deploy,
+        )
+        .run(&object.code)
+    }
+
+    ///
+    /// Executes the runtime code of the `object`, which is its inner `_deployed` object.
+    ///
+    pub fn run_runtime(&mut self, object: &Object) -> anyhow::Result<Outcome> {
+        let runtime = object
+            .inner_object
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Object `{}` has no runtime code", object.identifier))?;
+
+        Executor::new(
+            &self.environment,
+            &mut self.state,
+            runtime,
+            compiler_llvm_context::CodeType::Runtime,
+        )
+        .run(&runtime.code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::environment::Environment;
+    use super::outcome::Outcome;
+    use super::Interpreter;
+
+    fn parse(input: &str) -> Object {
+        let mut lexer = Lexer::new(input.to_owned());
+        Object::parse(&mut lexer, None).expect("Always valid")
+    }
+
+    #[test]
+    fn ok_storage_loops_functions() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        function sum(n) -> result {
+            for { let i := 1 } iszero(gt(i, n)) { i := add(i, 1) } {
+                if eq(i, 3) { continue }
+                result := add(result, i)
+            }
+        }
+        sstore(0, sum(5))
+        sstore(1, sub(0, 1))
+        mstore(0, sload(0))
+        return(0, 32)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#,
+        );
+
+        let mut interpreter = Interpreter::new(Environment::default());
+        let outcome = interpreter.run_deploy(&object).expect("Always valid");
+
+        let mut expected = vec![0u8; compiler_common::BYTE_LENGTH_FIELD];
+        expected[compiler_common::BYTE_LENGTH_FIELD - 1] = 12;
+        assert_eq!(outcome, Outcome::Return(expected));
+        assert_eq!(
+            interpreter.state.storage_value(&num::BigUint::from(1u32)),
+            super::word::modulus() - num::BigUint::from(1u32)
+        );
+    }
+
+    #[test]
+    fn ok_runtime_calldata_revert() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            if lt(calldatasize(), 4) { revert(0, 0) }
+            mstore(0, shr(224, calldataload(0)))
+            revert(28, 4)
+        }
+    }
+}
+    "#,
+        );
+
+        let mut interpreter = Interpreter::new(Environment {
+            calldata: vec![0xde, 0xad, 0xbe, 0xef],
+            ..Environment::default()
+        });
+        let outcome = interpreter.run_runtime(&object).expect("Always valid");
+
+        assert_eq!(outcome, Outcome::Revert(vec![0xde, 0xad, 0xbe, 0xef]));
+    }
+
+    #[test]
+    fn ok_mcopy_overlapping() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mcopy(1, 0, 4)
+            return(0, 6)
+        }
+    }
+}
+    "#,
+        );
+
+        let mut interpreter = Interpreter::new(Environment::default());
+        let outcome = interpreter.run_runtime(&object).expect("Always valid");
+
+        assert_eq!(
+            outcome,
+            Outcome::Return(vec![0x01, 0x01, 0x02, 0x03, 0x04, 0x06])
+        );
+    }
+
+    #[test]
+    fn ok_mcopy_forward_overlapping() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mcopy(0, 1, 4)
+            return(0, 6)
+        }
+    }
+}
+    "#,
+        );
+
+        let mut interpreter = Interpreter::new(Environment::default());
+        let outcome = interpreter.run_runtime(&object).expect("Always valid");
+
+        assert_eq!(
+            outcome,
+            Outcome::Return(vec![0x02, 0x03, 0x04, 0x05, 0x05, 0x06])
+        );
+    }
+
+    #[test]
+    fn ok_mcopy_trailing_partial_word() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            mstore(0, 0x0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20)
+            mstore(32, 0x2122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f40)
+            mstore(96, not(0))
+            mcopy(64, 0, 35)
+            return(96, 4)
+        }
+    }
+}
+    "#,
+        );
+
+        let mut interpreter = Interpreter::new(Environment::default());
+        let outcome = interpreter.run_runtime(&object).expect("Always valid");
+
+        assert_eq!(outcome, Outcome::Return(vec![0x21, 0x22, 0x23, 0xff]));
+    }
+
+    #[test]
+    fn ok_printed_object_same_outcome() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        function f(a, b) -> x, y {
+            x := mul(a, b)
+            y := keccak256(0, a)
+            leave
+        }
+        let p, q := f(3, 4)
+        switch p
+        case 12 { mstore(0, q) }
+        default { invalid() }
+        return(0, 32)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#,
+        );
+        let printed = parse(object.to_string().as_str());
+
+        let expected = Interpreter::new(Environment::default())
+            .run_deploy(&object)
+            .expect("Always valid");
+        let found = Interpreter::new(Environment::default())
+            .run_deploy(&printed)
+            .expect("Always valid");
+
+        assert_eq!(expected, found);
+    }
+
+    #[test]
+    fn error_not_modelled() {
+        let object = parse(
+            r#"
+object "Test" {
+    code {
+        pop(call(gas(), 1, 0, 0, 0, 0, 0))
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#,
+        );
+
+        let result = Interpreter::new(Environment::default()).run_deploy(&object);
+
+        assert!(result
+            .expect_err("Always invalid")
+            .to_string()
+            .contains("is not modelled by the interpreter"));
+    }
+}
@@ -0,0 +1,18 @@
+//!
+//! The Yul interpreter execution outcome.
+//!
+
+///
+/// The Yul interpreter execution outcome.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The execution returned the data with `return`.
+    Return(Vec<u8>),
+    /// The execution reverted with the data.
+    Revert(Vec<u8>),
+    /// The execution stopped with `stop` or by reaching the end of the code.
+    Stop,
+    /// The execution hit the `invalid` instruction.
+    Invalid,
+}
@@ -0,0 +1,47 @@
+//!
+//! The Yul interpreter persistent state.
+//!
+
+use std::collections::BTreeMap;
+
+use num::Zero;
+
+///
+/// The Yul interpreter persistent state.
+///
+/// Unlike the heap memory, the state is preserved between the executions, so the runtime code can
+/// observe the effects of the deploy code.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct State {
+    /// The contract storage.
+    pub storage: BTreeMap<num::BigUint, num::BigUint>,
+    /// The immutable values set with `setimmutable`.
+    pub immutables: BTreeMap<String, num::BigUint>,
+    /// The global variables set with `$zk_global_store`.
+    pub globals: BTreeMap<String, num::BigUint>,
+    /// The emitted logs as topics and data.
+    pub logs: Vec<(Vec<num::BigUint>, Vec<u8>)>,
+    /// The events written with `$zk_event_initialize` and `$zk_event_write`.
+    pub events: Vec<Vec<num::BigUint>>,
+    /// The L1 messages sent with `$zk_to_l1`.
+    pub l1_messages: Vec<[num::BigUint; 3]>,
+    /// The context value set with `$zk_set_context_u128`.
+    pub context_u128: num::BigUint,
+    /// The pubdata price set with `$zk_set_pubdata_price`.
+    pub pubdata_price: num::BigUint,
+    /// The transaction counter incremented with `$zk_increment_tx_counter`.
+    pub tx_counter: num::BigUint,
+}
+
+impl State {
+    ///
+    /// Returns the storage value, which is zero if the slot has never been written.
+    ///
+    pub fn storage_value(&self, key: &num::BigUint) -> num::BigUint {
+        self.storage
+            .get(key)
+            .cloned()
+            .unwrap_or_else(num::BigUint::zero)
+    }
+}
@@ -0,0 +1,166 @@
+//!
+//! The Yul interpreter 256-bit word arithmetic.
+//!
+
+use num::One;
+use num::Signed;
+use num::ToPrimitive;
+use num::Zero;
+
+///
+/// Returns `2^256`.
+///
+pub fn modulus() -> num::BigUint {
+    num::BigUint::one() << compiler_common::BIT_LENGTH_FIELD
+}
+
+///
+/// Truncates the value to 256 bits.
+///
+pub fn wrap(value: num::BigUint) -> num::BigUint {
+    value % modulus()
+}
+
+///
+/// Converts the two's complement word into a signed integer.
+///
+pub fn to_signed(value: &num::BigUint) -> num::BigInt {
+    if value.bit((compiler_common::BIT_LENGTH_FIELD - 1) as u64) {
+        num::BigInt::from(value.to_owned()) - num::BigInt::from(modulus())
+    } else {
+        num::BigInt::from(value.to_owned())
+    }
+}
+
+///
+/// Converts the signed integer into a two's complement word.
+///
+pub fn from_signed(value: num::BigInt) -> num::BigUint {
+    let modulus = num::BigInt::from(modulus());
+    let value = ((value % &modulus) + &modulus) % &modulus;
+    value.to_biguint().expect("Always non-negative")
+}
+
+///
+/// Converts the word into a `usize`, returning `None` if it does not fit.
+///
+pub fn to_usize(value: &num::BigUint) -> Option<usize> {
+    value.to_usize()
+}
+
+///
+/// Converts the word into its 32-byte big-endian representation.
+///
+pub fn to_bytes(value: &num::BigUint) -> [u8; compiler_common::BYTE_LENGTH_FIELD] {
+    let bytes = value.to_bytes_be();
+    let mut result = [0u8; compiler_common::BYTE_LENGTH_FIELD];
+    result[compiler_common::BYTE_LENGTH_FIELD - bytes.len()..].copy_from_slice(bytes.as_slice());
+    result
+}
+
+///
+/// Converts the boolean into a word.
+///
+pub fn from_bool(value: bool) -> num::BigUint {
+    if value {
+        num::BigUint::one()
+    } else {
+        num::BigUint::zero()
+    }
+}
+
+///
+/// Translates the `sdiv` instruction, which rounds towards zero.
+///
+pub fn signed_division(operand_1: &num::BigUint, operand_2: &num::BigUint) -> num::BigUint {
+    if operand_2.is_zero() {
+        return num::BigUint::zero();
+    }
+    from_signed(to_signed(operand_1) / to_signed(operand_2))
+}
+
+///
+/// Translates the `smod` instruction, whose result has the sign of the dividend.
+///
+pub fn signed_remainder(operand_1: &num::BigUint, operand_2: &num::BigUint) -> num::BigUint {
+    if operand_2.is_zero() {
+        return num::BigUint::zero();
+    }
+    from_signed(to_signed(operand_1) % to_signed(operand_2))
+}
+
+///
+/// Translates the `shl` instruction.
+///
+pub fn shift_left(shift: &num::BigUint, value: &num::BigUint) -> num::BigUint {
+    match to_usize(shift) {
+        Some(shift) if shift < compiler_common::BIT_LENGTH_FIELD => wrap(value << shift),
+        _ => num::BigUint::zero(),
+    }
+}
+
+///
+/// Translates the `shr` instruction.
+///
+pub fn shift_right(shift: &num::BigUint, value: &num::BigUint) -> num::BigUint {
+    match to_usize(shift) {
+        Some(shift) if shift < compiler_common::BIT_LENGTH_FIELD => value >> shift,
+        _ => num::BigUint::zero(),
+    }
+}
+
+///
+/// Translates the `sar` instruction.
+///
+pub fn shift_right_arithmetic(shift: &num::BigUint, value: &num::BigUint) -> num::BigUint {
+    let value = to_signed(value);
+    match to_usize(shift) {
+        Some(shift) if shift < compiler_common::BIT_LENGTH_FIELD => from_signed(value >> shift),
+        _ if value.is_negative() => modulus() - num::BigUint::one(),
+        _ => num::BigUint::zero(),
+    }
+}
+
+///
+/// Translates the `byte` instruction, where the byte `0` is the most significant one.
+///
+pub fn byte(index: &num::BigUint, value: &num::BigUint) -> num::BigUint {
+    match to_usize(index) {
+        Some(index) if index < compiler_common::BYTE_LENGTH_FIELD => {
+            num::BigUint::from(to_bytes(value)[index])
+        }
+        _ => num::BigUint::zero(),
+    }
+}
+
+///
+/// Translates the `signextend` instruction.
+///
+pub fn sign_extend(bytes: &num::BigUint, value: &num::BigUint) -> num::BigUint {
+    let bytes = match to_usize(bytes) {
+        Some(bytes) if bytes < compiler_common::BYTE_LENGTH_FIELD - 1 => bytes,
+        _ => return value.to_owned(),
+    };
+
+    let bit = bytes * (u8::BITS as usize) + (u8::BITS as usize - 1);
+    let mask = (num::BigUint::one() << (bit + 1)) - num::BigUint::one();
+    if value.bit(bit as u64) {
+        value | (modulus() - num::BigUint::one() - &mask)
+    } else {
+        value & mask
+    }
+}
+
+///
+/// Translates the `exp` instruction.
+///
+pub fn exponent(base: &num::BigUint, exponent: &num::BigUint) -> num::BigUint {
+    base.modpow(exponent, &modulus())
+}
+
+///
+/// Returns the upper 256 bits of the 512-bit product.
+///
+pub fn multiplication_high(operand_1: &num::BigUint, operand_2: &num::BigUint) -> num::BigUint {
+    (operand_1 * operand_2) >> compiler_common::BIT_LENGTH_FIELD
+}
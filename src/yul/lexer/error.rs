@@ -17,4 +17,10 @@ pub enum Error {
         /// The invalid sequence of characters.
         sequence: String,
     },
+    /// The `@zksync` annotations not followed by a function definition.
+    #[error("{location} The `@zksync` annotations are only allowed before function definitions")]
+    MisplacedAnnotation {
+        /// The annotation location.
+        location: Location,
+    },
 }
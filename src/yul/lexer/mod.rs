@@ -9,8 +9,10 @@ pub mod token;
 mod tests;
 
 use self::error::Error;
+use self::token::lexeme::comment::single_line::Comment as SingleLineComment;
 use self::token::lexeme::comment::Comment;
 use self::token::lexeme::identifier::Identifier;
+use self::token::lexeme::keyword::Keyword;
 use self::token::lexeme::literal::integer::Integer as IntegerLiteral;
 use self::token::lexeme::literal::string::String as StringLiteral;
 use self::token::lexeme::symbol::Symbol;
@@ -30,6 +32,8 @@ pub struct Lexer {
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// The `@zksync` annotations preceding the last lexed token.
+    annotations: Vec<(Location, String)>,
 }
 
 impl Lexer {
@@ -44,6 +48,7 @@ impl Lexer {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            annotations: Vec::new(),
         }
     }
 
@@ -56,6 +61,46 @@ impl Lexer {
             return Ok(peeked);
         }
 
+        self.annotations.clear();
+        let token = self.lex()?;
+        match self.annotations.first() {
+            Some((location, _)) if !matches!(token.lexeme, Lexeme::Keyword(Keyword::Function)) => {
+                Err(Error::MisplacedAnnotation {
+                    location: *location,
+                })
+            }
+            _ => Ok(token),
+        }
+    }
+
+    ///
+    /// Returns the `@zksync` annotations preceding the last lexed token.
+    ///
+    /// The annotations are written as `/// @zksync <word>...` comments and are only allowed before
+    /// a function definition, which is checked by `next`.
+    ///
+    pub fn annotations(&self) -> &[(Location, String)] {
+        self.annotations.as_slice()
+    }
+
+    ///
+    /// Peeks the next lexeme without advancing the iterator.
+    ///
+    pub fn peek(&mut self) -> Result<Token, Error> {
+        match self.peeked {
+            Some(ref peeked) => Ok(peeked.clone()),
+            None => {
+                let peeked = self.next()?;
+                self.peeked = Some(peeked.clone());
+                Ok(peeked)
+            }
+        }
+    }
+
+    ///
+    /// Lexes the next token, collecting the annotations preceding it.
+    ///
+    fn lex(&mut self) -> Result<Token, Error> {
         while let Some(character) = self.input.chars().nth(self.offset) {
             if character.is_ascii_whitespace() {
                 if character == '\n' {
@@ -69,6 +114,16 @@ impl Lexer {
             }
 
             if let Some(token) = Comment::parse(&self.input[self.offset..]) {
+                let text = &self.input[self.offset..self.offset + token.length];
+                if let Some(annotations) = SingleLineComment::annotations(text) {
+                    let location = self.location;
+                    self.annotations.extend(
+                        annotations
+                            .into_iter()
+                            .map(|annotation| (location, annotation)),
+                    );
+                }
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -118,18 +173,4 @@ impl Lexer {
 
         Ok(Token::new(self.location, Lexeme::EndOfFile, 0))
     }
-
-    ///
-    /// Peeks the next lexeme without advancing the iterator.
-    ///
-    pub fn peek(&mut self) -> Result<Token, Error> {
-        match self.peeked {
-            Some(ref peeked) => Ok(peeked.clone()),
-            None => {
-                let peeked = self.next()?;
-                self.peeked = Some(peeked.clone());
-                Ok(peeked)
-            }
-        }
-    }
 }
@@ -89,3 +89,42 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn annotations() {
+    let input = r#"
+// @zksync cold
+/// A helper.
+/// @zksync noinline   cold
+function test() {}
+"#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    lexer.next().expect("Always valid");
+    assert_eq!(
+        lexer.annotations(),
+        &[
+            (Location::new(4, 1), "noinline".to_owned()),
+            (Location::new(4, 1), "cold".to_owned()),
+        ]
+    );
+
+    lexer.next().expect("Always valid");
+    assert!(lexer.annotations().is_empty());
+}
+
+#[test]
+fn error_misplaced_annotation() {
+    let input = r#"
+/// @zksync noinline
+let test := 42
+"#;
+
+    let mut lexer = Lexer::new(input.to_owned());
+    assert_eq!(
+        lexer.next(),
+        Err(Error::MisplacedAnnotation {
+            location: Location::new(2, 1),
+        })
+    );
+}
@@ -17,6 +17,10 @@ impl Comment {
     pub const START: &'static str = "//";
     /// The end symbol.
     pub const END: &'static str = "\n";
+    /// The documentation comment start symbol, which may contain annotations.
+    pub const DOCUMENTATION_START: &'static str = "///";
+    /// The annotation tag.
+    pub const ANNOTATION_TAG: &'static str = "@zksync";
 
     ///
     /// Returns the comment's length, including the trimmed whitespace around it.
@@ -27,4 +31,17 @@ impl Comment {
 
         Token::new(Location::new(1, 1), Lexeme::Comment, length)
     }
+
+    ///
+    /// Returns the annotation words if the comment is a `/// @zksync <word>...` annotation.
+    ///
+    pub fn annotations(input: &str) -> Option<Vec<String>> {
+        let text = input.strip_prefix(Self::DOCUMENTATION_START)?;
+        let mut words = text.split_whitespace();
+        if words.next() != Some(Self::ANNOTATION_TAG) {
+            return None;
+        }
+
+        Some(words.map(|word| word.to_owned()).collect())
+    }
 }
@@ -3,5 +3,7 @@
 //!
 
 pub mod error;
+pub mod interpreter;
 pub mod lexer;
+pub mod optimizer;
 pub mod parser;
@@ -0,0 +1,76 @@
+//!
+//! The Yul constant folding pass.
+//!
+
+use crate::yul::interpreter::builtin::evaluate_pure;
+use crate::yul::interpreter::executor::Executor;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::visitor::mutable::walk_expression_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
+
+///
+/// The Yul constant folding pass.
+///
+/// Replaces the calls to the pure arithmetic, comparison, and bitwise builtins whose arguments
+/// are all untyped literals with the literal result. The nested calls are folded bottom-up, so
+/// whole constant subexpressions are collapsed.
+///
+#[derive(Debug, Default)]
+pub struct ConstantFolding;
+
+impl ConstantFolding {
+    /// The pass name.
+    pub const NAME: &'static str = "constant_folding";
+
+    ///
+    /// Runs the pass over the code.
+    ///
+    pub fn run(code: &mut Code) {
+        Self.visit_code_mut(code);
+    }
+}
+
+impl VisitorMut for ConstantFolding {
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+
+        let call = match expression {
+            Expression::FunctionCall(call) => call,
+            _ => return,
+        };
+
+        let mut arguments = Vec::with_capacity(call.arguments.len());
+        for argument in call.arguments.iter() {
+            match argument {
+                Expression::Literal(literal)
+                    if literal
+                        .yul_type
+                        .as_ref()
+                        .map_or(true, |r#type| r#type == &Type::default()) =>
+                {
+                    match Executor::literal_value(literal) {
+                        Ok(value) => arguments.push(value),
+                        Err(_) => return,
+                    }
+                }
+                _ => return,
+            }
+        }
+
+        let value = match evaluate_pure(&call.name, arguments.as_slice()) {
+            Some(value) => value,
+            None => return,
+        };
+        let location = call.location;
+        *expression = Expression::Literal(Literal {
+            location,
+            inner: LexicalLiteral::Integer(IntegerLiteral::new_decimal(value.to_string())),
+            yul_type: None,
+        });
+    }
+}
@@ -0,0 +1,86 @@
+//!
+//! The Yul dead code elimination pass.
+//!
+
+use num::Zero;
+
+use crate::yul::interpreter::executor::Executor;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::builtin::side_effects::SideEffects;
+use crate::yul::parser::statement::expression::function_call::builtin::Builtin;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::mutable::walk_block_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
+
+///
+/// The Yul dead code elimination pass.
+///
+/// Removes the `if` statements with a zero literal condition, unwraps the ones with a non-zero
+/// literal condition, and removes the statements following an unconditional `return`, `revert`,
+/// `stop`, `invalid`, `break`, `continue`, or `leave` in the same block. The function
+/// definitions are kept, as they are visible in the whole block.
+///
+#[derive(Debug, Default)]
+pub struct DeadCodeElimination;
+
+impl DeadCodeElimination {
+    /// The pass name.
+    pub const NAME: &'static str = "dead_code_elimination";
+
+    ///
+    /// Runs the pass over the code.
+    ///
+    pub fn run(code: &mut Code) {
+        Self.visit_code_mut(code);
+    }
+
+    ///
+    /// Whether the statement unconditionally transfers the control flow out of the block.
+    ///
+    fn is_terminating(statement: &Statement) -> bool {
+        match statement {
+            Statement::Break(_) | Statement::Continue(_) | Statement::Leave(_) => true,
+            Statement::Expression(Expression::FunctionCall(call)) => Builtin::get(&call.name)
+                .map_or(false, |builtin| {
+                    builtin.side_effects == SideEffects::Terminate
+                }),
+            Statement::Block(block) => block.statements.iter().any(Self::is_terminating),
+            _ => false,
+        }
+    }
+}
+
+impl VisitorMut for DeadCodeElimination {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        walk_block_mut(self, block);
+
+        block.statements = std::mem::take(&mut block.statements)
+            .into_iter()
+            .filter_map(|statement| match statement {
+                Statement::IfConditional(conditional) => {
+                    let condition = match conditional.condition {
+                        Expression::Literal(ref literal) => Executor::literal_value(literal).ok(),
+                        _ => None,
+                    };
+                    match condition {
+                        Some(value) if value.is_zero() => None,
+                        Some(_) => Some(Statement::Block(conditional.block)),
+                        None => Some(Statement::IfConditional(conditional)),
+                    }
+                }
+                statement => Some(statement),
+            })
+            .collect();
+
+        if let Some(position) = block.statements.iter().position(Self::is_terminating) {
+            let unreachable = block.statements.split_off(position + 1);
+            block.statements.extend(
+                unreachable
+                    .into_iter()
+                    .filter(|statement| matches!(statement, Statement::FunctionDefinition(_))),
+            );
+        }
+    }
+}
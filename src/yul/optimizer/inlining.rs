@@ -0,0 +1,222 @@
+//!
+//! The Yul tiny function inlining pass.
+//!
+
+use std::collections::HashMap;
+
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::function_definition::attribute::Attribute;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::mutable::walk_expression_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
+use crate::yul::parser::visitor::walk_function_call;
+use crate::yul::parser::visitor::walk_function_definition;
+use crate::yul::parser::visitor::Visitor;
+
+///
+/// The Yul tiny function inlining pass.
+///
+/// A function is tiny if its body is a single assignment of an expression of its arguments to
+/// its only result, e.g. `function f(a, b) -> r { r := add(a, b) }`. Its calls are replaced with
+/// the expression if all the call arguments are identifiers or literals, so neither the
+/// evaluation order nor the number of evaluations of the arguments change.
+///
+/// The typed functions, the `noinline` functions, and the near call ABI functions are never
+/// inlined. Neither are the functions whose names are defined more than once in the code, e.g. in
+/// sibling blocks, and the functions calling them, as the map of tiny functions is not scoped.
+///
+#[derive(Debug, Default)]
+pub struct Inlining {
+    /// The tiny functions, mapped to their argument names and expressions.
+    functions: HashMap<String, (Vec<String>, Expression)>,
+    /// The number of definitions of each function name.
+    definitions: HashMap<String, usize>,
+}
+
+impl Inlining {
+    /// The pass name.
+    pub const NAME: &'static str = "inlining";
+
+    ///
+    /// Runs the pass over the code.
+    ///
+    pub fn run(code: &mut Code) {
+        let mut pass = Self::default();
+        pass.visit_code(code);
+        pass.remove_ambiguous();
+        if !pass.functions.is_empty() {
+            pass.visit_code_mut(code);
+        }
+    }
+
+    ///
+    /// Removes the tiny functions whose names or callees are defined more than once.
+    ///
+    fn remove_ambiguous(&mut self) {
+        let definitions = &self.definitions;
+        let is_unique = |name: &str| definitions.get(name).copied().unwrap_or_default() <= 1;
+
+        self.functions.retain(|name, (_, expression)| {
+            let mut references = References::default();
+            references.visit_expression(expression);
+            is_unique(name) && references.calls.iter().all(|call| is_unique(call))
+        });
+    }
+
+    ///
+    /// Returns the expression of the function if it is tiny.
+    ///
+    fn tiny_expression(function: &FunctionDefinition) -> Option<&Expression> {
+        if function.attributes.contains(&Attribute::NoInline)
+            || function
+                .identifier
+                .contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX)
+            || function
+                .identifier
+                .contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_EXCEPTION_HANDLER)
+        {
+            return None;
+        }
+
+        let is_untyped = |identifier: &Identifier| {
+            identifier
+                .r#type
+                .as_ref()
+                .map_or(true, |r#type| r#type == &Type::default())
+        };
+        if !function.arguments.iter().all(is_untyped) || !function.result.iter().all(is_untyped) {
+            return None;
+        }
+
+        let result = match function.result.as_slice() {
+            [result] => result.inner.as_str(),
+            _ => return None,
+        };
+        let expression = match function.body.statements.as_slice() {
+            [Statement::Assignment(Assignment {
+                bindings,
+                initializer,
+                ..
+            })] if bindings.len() == 1 && bindings[0].inner == result => initializer,
+            _ => return None,
+        };
+
+        let mut references = References::default();
+        references.visit_expression(expression);
+        if references.identifiers.iter().any(|name| name == result)
+            || references.calls.contains(&function.identifier)
+        {
+            return None;
+        }
+
+        Some(expression)
+    }
+}
+
+impl Visitor for Inlining {
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition) {
+        *self
+            .definitions
+            .entry(function_definition.identifier.to_owned())
+            .or_default() += 1;
+        if let Some(expression) = Self::tiny_expression(function_definition) {
+            let arguments = function_definition
+                .arguments
+                .iter()
+                .map(|argument| argument.inner.to_owned())
+                .collect();
+            self.functions.insert(
+                function_definition.identifier.to_owned(),
+                (arguments, expression.to_owned()),
+            );
+        }
+        walk_function_definition(self, function_definition);
+    }
+}
+
+impl VisitorMut for Inlining {
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+
+        let call = match expression {
+            Expression::FunctionCall(FunctionCall {
+                name: Name::UserDefined(ref name),
+                ref arguments,
+                ..
+            }) if arguments.iter().all(|argument| {
+                matches!(argument, Expression::Identifier(_) | Expression::Literal(_))
+            }) =>
+            {
+                (name, arguments)
+            }
+            _ => return,
+        };
+        let (parameters, body) = match self.functions.get(call.0.as_str()) {
+            Some(function) if function.0.len() == call.1.len() => function,
+            _ => return,
+        };
+
+        let mut substitution = Substitution {
+            values: parameters
+                .iter()
+                .cloned()
+                .zip(call.1.iter().cloned())
+                .collect(),
+        };
+        let mut inlined = body.to_owned();
+        substitution.visit_expression_mut(&mut inlined);
+        *expression = inlined;
+    }
+}
+
+///
+/// Collects the identifiers and the called functions of an expression.
+///
+#[derive(Debug, Default)]
+struct References {
+    /// The referenced identifiers.
+    identifiers: Vec<String>,
+    /// The called user-defined functions.
+    calls: Vec<String>,
+}
+
+impl Visitor for References {
+    fn visit_identifier(&mut self, identifier: &Identifier) {
+        self.identifiers.push(identifier.inner.to_owned());
+    }
+
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        if let Name::UserDefined(ref name) = function_call.name {
+            self.calls.push(name.to_owned());
+        }
+        walk_function_call(self, function_call);
+    }
+}
+
+///
+/// Replaces the function arguments with the call argument expressions.
+///
+#[derive(Debug)]
+struct Substitution {
+    /// The argument values.
+    values: HashMap<String, Expression>,
+}
+
+impl VisitorMut for Substitution {
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        if let Expression::Identifier(identifier) = expression {
+            if let Some(value) = self.values.get(identifier.inner.as_str()) {
+                *expression = value.to_owned();
+            }
+            return;
+        }
+        walk_expression_mut(self, expression);
+    }
+}
@@ -0,0 +1,227 @@
+//!
+//! The Yul pre-optimizer.
+//!
+
+pub mod constant_folding;
+pub mod dead_code_elimination;
+pub mod inlining;
+pub mod unused_function_removal;
+
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::object::Object;
+
+use self::constant_folding::ConstantFolding;
+use self::dead_code_elimination::DeadCodeElimination;
+use self::inlining::Inlining;
+use self::unused_function_removal::UnusedFunctionRemoval;
+
+///
+/// The Yul pre-optimizer.
+///
+/// Runs a pipeline of simple AST passes before the LLVM IR generation. The passes are cheap and
+/// conservative, so they mostly reduce the amount of IR handed to LLVM rather than enable new
+/// optimizations. Each pass is applied to the deploy and runtime code of every object.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Optimizer {
+    /// Whether to dump the Yul after each pass to the debug output directory.
+    pub is_dump_enabled: bool,
+}
+
+impl Optimizer {
+    /// The passes in the order of execution.
+    pub const PASSES: [(&'static str, fn(&mut Code)); 4] = [
+        (Inlining::NAME, Inlining::run),
+        (ConstantFolding::NAME, ConstantFolding::run),
+        (DeadCodeElimination::NAME, DeadCodeElimination::run),
+        (UnusedFunctionRemoval::NAME, UnusedFunctionRemoval::run),
+    ];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(is_dump_enabled: bool) -> Self {
+        Self { is_dump_enabled }
+    }
+
+    ///
+    /// Optimizes the object of the contract at `path`.
+    ///
+    pub fn optimize(
+        &self,
+        object: &mut Object,
+        path: &str,
+        debug_config: Option<&compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<()> {
+        for (index, (name, pass)) in Self::PASSES.iter().enumerate() {
+            Self::run_pass(object, *pass);
+
+            if let (true, Some(debug_config)) = (self.is_dump_enabled, debug_config) {
+                debug_config.dump_yul(
+                    format!("{path}.{}_{name}", index + 1).as_str(),
+                    object.to_string().as_str(),
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Runs the pass over the object code, its runtime code object, and its children.
+    ///
+    fn run_pass(object: &mut Object, pass: fn(&mut Code)) {
+        pass(&mut object.code);
+        if let Some(inner_object) = object.inner_object.as_mut() {
+            Self::run_pass(inner_object, pass);
+        }
+        for child in object.children.values_mut() {
+            Self::run_pass(child, pass);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::interpreter::environment::Environment;
+    use crate::yul::interpreter::Interpreter;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::Optimizer;
+
+    const SOURCE: &str = r#"
+object "Test" {
+    code {
+        function double(x) -> y {
+            y := add(x, x)
+        }
+        function unused(x) -> y {
+            y := helper(x)
+        }
+        function helper(x) -> y {
+            y := mul(x, 3)
+            leave
+        }
+        let size := calldatasize()
+        let a := double(size)
+        if 0 { sstore(1, 1) }
+        if sub(2, 1) { sstore(2, add(a, shl(4, 1))) }
+        mstore(0, sload(2))
+        return(0, 32)
+        sstore(3, 3)
+    }
+    object "Test_deployed" {
+        code {
+            let value := calldataload(0)
+            mstore(0, double(value))
+            return(0, 32)
+            function double(x) -> y {
+                y := add(x, x)
+            }
+        }
+    }
+}
+    "#;
+
+    fn parse(input: &str) -> Object {
+        let mut lexer = Lexer::new(input.to_owned());
+        Object::parse(&mut lexer, None).expect("Always valid")
+    }
+
+    #[test]
+    fn ok_passes_applied() {
+        let mut object = parse(SOURCE);
+        Optimizer::default()
+            .optimize(&mut object, "test.yul", None)
+            .expect("Always valid");
+        let printed = object.to_string();
+
+        assert!(!printed.contains("function unused"));
+        assert!(!printed.contains("function helper"));
+        assert!(!printed.contains("function double"));
+        assert!(!printed.contains("sstore(1, 1)"));
+        assert!(!printed.contains("sstore(3, 3)"));
+        assert!(printed.contains("add(a, 16)"));
+    }
+
+    #[test]
+    fn ok_same_outcome() {
+        let original = parse(SOURCE);
+        let mut optimized = original.clone();
+        Optimizer::default()
+            .optimize(&mut optimized, "test.yul", None)
+            .expect("Always valid");
+        let optimized = parse(optimized.to_string().as_str());
+
+        let environment = Environment {
+            calldata: vec![0x11; 32],
+            ..Environment::default()
+        };
+        let mut expected = Interpreter::new(environment.clone());
+        let mut found = Interpreter::new(environment);
+        assert_eq!(
+            expected.run_deploy(&original).expect("Always valid"),
+            found.run_deploy(&optimized).expect("Always valid"),
+        );
+        assert_eq!(
+            expected.run_runtime(&original).expect("Always valid"),
+            found.run_runtime(&optimized).expect("Always valid"),
+        );
+        assert_eq!(expected.state, found.state);
+    }
+
+    #[test]
+    fn ok_same_outcome_same_named_functions() {
+        let original = parse(
+            r#"
+object "Test" {
+    code {
+        {
+            function increment(x) -> y {
+                y := add(x, 1)
+            }
+            function apply(x) -> y {
+                y := increment(x)
+            }
+            sstore(0, apply(calldatasize()))
+        }
+        {
+            function increment(x) -> y {
+                y := mul(x, 2)
+            }
+            function apply(x) -> y {
+                y := sub(x, 1)
+            }
+            sstore(1, increment(calldatasize()))
+            sstore(2, apply(calldatasize()))
+        }
+        return(0, 0)
+    }
+    object "Test_deployed" {
+        code {
+            return(0, 0)
+        }
+    }
+}
+    "#,
+        );
+        let mut optimized = original.clone();
+        Optimizer::default()
+            .optimize(&mut optimized, "test.yul", None)
+            .expect("Always valid");
+        let optimized = parse(optimized.to_string().as_str());
+
+        let environment = Environment {
+            calldata: vec![0x11; 32],
+            ..Environment::default()
+        };
+        let mut expected = Interpreter::new(environment.clone());
+        let mut found = Interpreter::new(environment);
+        assert_eq!(
+            expected.run_deploy(&original).expect("Always valid"),
+            found.run_deploy(&optimized).expect("Always valid"),
+        );
+        assert_eq!(expected.state, found.state);
+    }
+}
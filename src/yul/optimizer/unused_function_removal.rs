@@ -0,0 +1,76 @@
+//!
+//! The Yul unused function removal pass.
+//!
+
+use std::collections::HashSet;
+
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::mutable::walk_block_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
+use crate::yul::parser::visitor::walk_function_call;
+use crate::yul::parser::visitor::Visitor;
+
+///
+/// The Yul unused function removal pass.
+///
+/// Removes the function definitions which are never called. The pass is repeated until nothing
+/// is removed, so the functions only called from the removed ones are removed as well. The
+/// near call exception handler is always kept, as it is called implicitly.
+///
+#[derive(Debug, Default)]
+pub struct UnusedFunctionRemoval {
+    /// The names of the called functions.
+    called: HashSet<String>,
+    /// The number of removed functions in the current iteration.
+    removed: usize,
+}
+
+impl UnusedFunctionRemoval {
+    /// The pass name.
+    pub const NAME: &'static str = "unused_function_removal";
+
+    ///
+    /// Runs the pass over the code.
+    ///
+    pub fn run(code: &mut Code) {
+        loop {
+            let mut pass = Self::default();
+            pass.visit_code(code);
+            pass.visit_code_mut(code);
+            if pass.removed == 0 {
+                break;
+            }
+        }
+    }
+}
+
+impl Visitor for UnusedFunctionRemoval {
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        if let Name::UserDefined(ref name) = function_call.name {
+            self.called.insert(name.to_owned());
+        }
+        walk_function_call(self, function_call);
+    }
+}
+
+impl VisitorMut for UnusedFunctionRemoval {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        let length = block.statements.len();
+        block.statements.retain(|statement| match statement {
+            Statement::FunctionDefinition(function) => {
+                self.called.contains(function.identifier.as_str())
+                    || function.identifier.contains(
+                        compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_EXCEPTION_HANDLER,
+                    )
+            }
+            _ => true,
+        });
+        self.removed += length - block.statements.len();
+
+        walk_block_mut(self, block);
+    }
+}
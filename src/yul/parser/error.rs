@@ -39,4 +39,36 @@ pub enum Error {
         /// The actual number of arguments.
         found: usize,
     },
+    /// An unknown function attribute annotation.
+    #[error("{location} Invalid function attribute `{attribute}`, expected one of {expected:?}")]
+    InvalidAttribute {
+        /// The invalid annotation location.
+        location: Location,
+        /// The invalid attribute.
+        attribute: String,
+        /// The list of expected attributes.
+        expected: Vec<&'static str>,
+    },
+    /// An object or data segment name declared twice in the same object.
+    #[error("{location} The object or data segment `{identifier}` is already declared")]
+    DuplicateObjectName {
+        /// The duplicate declaration location.
+        location: Location,
+        /// The duplicate name.
+        identifier: String,
+    },
+    /// Function attributes which cannot be specified together.
+    #[error(
+        "{location} Function `{identifier}` has conflicting attributes `{first}` and `{second}`"
+    )]
+    ConflictingAttributes {
+        /// The invalid function location.
+        location: Location,
+        /// The invalid function name.
+        identifier: String,
+        /// The first conflicting attribute.
+        first: String,
+        /// The second conflicting attribute.
+        second: String,
+    },
 }
@@ -2,6 +2,9 @@
 //! The YUL source code identifier.
 //!
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -13,7 +16,7 @@ use crate::yul::parser::r#type::Type;
 ///
 /// The YUL source code identifier.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Identifier {
     /// The location.
     pub location: Location,
@@ -122,3 +125,13 @@ impl Identifier {
         }
     }
 }
+
+impl std::fmt::Display for Identifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)?;
+        if let Some(r#type) = self.r#type.as_ref() {
+            write!(f, ":{type}")?;
+        }
+        Ok(())
+    }
+}
@@ -6,6 +6,8 @@ pub mod error;
 pub mod identifier;
 pub mod statement;
 pub mod r#type;
+pub mod type_checker;
+pub mod visitor;
 
 use crate::yul::lexer::error::Error as LexerError;
 use crate::yul::lexer::token::Token;
@@ -20,3 +22,16 @@ pub fn take_or_next(mut token: Option<Token>, lexer: &mut Lexer) -> Result<Token
         None => lexer.next(),
     }
 }
+
+///
+/// Writes the `element` to the formatter, indenting each of its lines by one level.
+///
+pub fn write_indented(
+    f: &mut std::fmt::Formatter<'_>,
+    element: &impl std::fmt::Display,
+) -> std::fmt::Result {
+    for line in element.to_string().lines() {
+        writeln!(f, "    {line}")?;
+    }
+    Ok(())
+}
@@ -2,6 +2,10 @@
 //! The assignment expression statement.
 //!
 
+use inkwell::types::BasicType;
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -10,13 +14,13 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::expression::Expression;
-use inkwell::types::BasicType;
 
 ///
 /// The Yul assignment expression statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Assignment {
     /// The location.
     pub location: Location,
@@ -126,7 +130,13 @@ where
                         identifier.inner,
                     )
                 })?;
-            context.build_store(pointer, value.to_llvm());
+            let value = Type::cast_llvm(
+                context,
+                value.to_llvm(),
+                pointer.r#type.as_basic_type_enum(),
+                false,
+            );
+            context.build_store(pointer, value);
             return Ok(());
         }
 
@@ -162,9 +172,27 @@ where
                 field_pointer,
                 format!("assignment_binding_{index}_value").as_str(),
             );
+            let value = Type::cast_llvm(
+                context,
+                value,
+                binding_pointer.r#type.as_basic_type_enum(),
+                false,
+            );
             context.build_store(binding_pointer, value);
         }
 
         Ok(())
     }
 }
+
+impl std::fmt::Display for Assignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, binding) in self.bindings.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{binding}")?;
+        }
+        write!(f, " := {}", self.initializer)
+    }
+}
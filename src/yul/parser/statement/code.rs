@@ -2,6 +2,12 @@
 //! The YUL code.
 //!
 
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -10,11 +16,19 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::function_definition::attribute::Attribute;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::visitor::mutable::walk_function_call_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
+use crate::yul::parser::visitor::walk_function_definition;
+use crate::yul::parser::visitor::Visitor;
 
 ///
 /// The YUL code entity, which is the first block of the object.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Code {
     /// The location.
     pub location: Location,
@@ -47,7 +61,73 @@ impl Code {
 
         let block = Block::parse(lexer, None)?;
 
-        Ok(Self { location, block })
+        let mut code = Self { location, block };
+        NearCallRenaming::run(&mut code);
+        Ok(code)
+    }
+}
+
+///
+/// Renames the calls to the functions which got the near call ABI name from the `near_call`
+/// attribute.
+///
+#[derive(Debug, Default)]
+struct NearCallRenaming {
+    /// The names of all defined functions.
+    defined: HashSet<String>,
+    /// The original names of the renamed functions, mapped to the new ones.
+    renamed: HashMap<String, String>,
+}
+
+impl NearCallRenaming {
+    ///
+    /// Runs the renaming over the code.
+    ///
+    fn run(code: &mut Code) {
+        let mut renaming = Self::default();
+        renaming.visit_code(code);
+
+        let defined = std::mem::take(&mut renaming.defined);
+        renaming
+            .renamed
+            .retain(|original, _| !defined.contains(original));
+        if !renaming.renamed.is_empty() {
+            renaming.visit_code_mut(code);
+        }
+    }
+}
+
+impl Visitor for NearCallRenaming {
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition) {
+        self.defined
+            .insert(function_definition.identifier.to_owned());
+        if function_definition
+            .attributes
+            .contains(&Attribute::NearCall)
+        {
+            let original = function_definition
+                .identifier
+                .strip_prefix(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX)
+                .and_then(|identifier| identifier.strip_prefix('_'));
+            if let Some(original) = original {
+                self.renamed.insert(
+                    original.to_owned(),
+                    function_definition.identifier.to_owned(),
+                );
+            }
+        }
+        walk_function_definition(self, function_definition);
+    }
+}
+
+impl VisitorMut for NearCallRenaming {
+    fn visit_function_call_mut(&mut self, function_call: &mut FunctionCall) {
+        if let Name::UserDefined(ref mut name) = function_call.name {
+            if let Some(renamed) = self.renamed.get(name.as_str()) {
+                *name = renamed.to_owned();
+            }
+        }
+        walk_function_call_mut(self, function_call);
     }
 }
 
@@ -62,6 +142,12 @@ where
     }
 }
 
+impl std::fmt::Display for Code {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "code {}", self.block)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::yul::lexer::token::location::Location;
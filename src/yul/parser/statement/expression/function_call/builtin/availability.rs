@@ -0,0 +1,21 @@
+//!
+//! The Yul builtin function code part availability.
+//!
+
+use serde::Serialize;
+
+///
+/// The Yul builtin function code part availability.
+///
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Availability {
+    /// Available in both the deploy and runtime code.
+    Any,
+    /// Available only in the deploy code.
+    Deploy,
+    /// Available only in the runtime code.
+    Runtime,
+    /// Not supported by the zkEVM.
+    Unsupported,
+}
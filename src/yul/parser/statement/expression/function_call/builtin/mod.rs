@@ -0,0 +1,638 @@
+//!
+//! The Yul builtin function registry.
+//!
+
+pub mod availability;
+pub mod side_effects;
+pub mod simulation;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+
+use self::availability::Availability;
+use self::side_effects::SideEffects;
+
+///
+/// The Yul builtin function description.
+///
+/// The registry is the single source of the builtin properties, which is consulted by the parser
+/// to resolve the names and check the arity, by the type checker to infer the number of results,
+/// and by the code generator to check the availability and to pop the arguments.
+///
+/// The `verbatim_<N>i_<M>o` instructions are not in the registry, as their arity is encoded in
+/// their names. The zkEVM simulations called via `verbatim` have their own `simulation` registry.
+///
+#[derive(Debug, Serialize, Clone)]
+pub struct Builtin {
+    /// The parsed function name.
+    #[serde(skip)]
+    pub name: Name,
+    /// The Yul identifier.
+    pub identifier: &'static str,
+    /// The number of input arguments.
+    pub input_size: usize,
+    /// The number of output values.
+    pub output_size: usize,
+    /// Whether the builtin is only allowed in the system mode.
+    pub is_system_mode_only: bool,
+    /// The side effects class.
+    pub side_effects: SideEffects,
+    /// The code part availability.
+    pub availability: Availability,
+}
+
+impl Builtin {
+    ///
+    /// A shortcut constructor for a builtin available everywhere.
+    ///
+    pub const fn new(
+        name: Name,
+        identifier: &'static str,
+        input_size: usize,
+        output_size: usize,
+        side_effects: SideEffects,
+    ) -> Self {
+        Self {
+            name,
+            identifier,
+            input_size,
+            output_size,
+            is_system_mode_only: false,
+            side_effects,
+            availability: Availability::Any,
+        }
+    }
+
+    ///
+    /// Restricts the builtin to the system mode.
+    ///
+    pub const fn system_mode_only(mut self) -> Self {
+        self.is_system_mode_only = true;
+        self
+    }
+
+    ///
+    /// Restricts the builtin to the deploy code.
+    ///
+    pub const fn deploy_code_only(mut self) -> Self {
+        self.availability = Availability::Deploy;
+        self
+    }
+
+    ///
+    /// Marks the builtin as not supported by the zkEVM.
+    ///
+    pub const fn unsupported(mut self) -> Self {
+        self.availability = Availability::Unsupported;
+        self
+    }
+
+    ///
+    /// Returns the registry entry for the parsed function `name`.
+    ///
+    pub fn get(name: &Name) -> Option<&'static Self> {
+        static INDEX: OnceLock<HashMap<Name, &'static Builtin>> = OnceLock::new();
+
+        INDEX
+            .get_or_init(|| {
+                BUILTINS
+                    .iter()
+                    .map(|builtin| (builtin.name.to_owned(), builtin))
+                    .collect()
+            })
+            .get(name)
+            .copied()
+    }
+
+    ///
+    /// Returns the registry entry for the Yul `identifier`.
+    ///
+    pub fn get_by_identifier(identifier: &str) -> Option<&'static Self> {
+        static INDEX: OnceLock<HashMap<&'static str, &'static Builtin>> = OnceLock::new();
+
+        INDEX
+            .get_or_init(|| {
+                BUILTINS
+                    .iter()
+                    .map(|builtin| (builtin.identifier, builtin))
+                    .collect()
+            })
+            .get(identifier)
+            .copied()
+    }
+
+    ///
+    /// Checks whether the builtin is allowed in the current mode and code part.
+    ///
+    pub fn check_availability(
+        &self,
+        is_system_mode: bool,
+        code_type: Option<compiler_llvm_context::CodeType>,
+        location: Location,
+    ) -> anyhow::Result<()> {
+        if self.is_system_mode_only && !is_system_mode {
+            anyhow::bail!(
+                "{} The `{}` instruction is only allowed in the system mode",
+                location,
+                self.identifier
+            );
+        }
+
+        match (self.availability, code_type) {
+            (Availability::Unsupported, _) => {
+                anyhow::bail!(
+                    "{} The `{}` instruction is not supported",
+                    location,
+                    self.identifier.to_uppercase(),
+                )
+            }
+            (Availability::Deploy, Some(compiler_llvm_context::CodeType::Runtime)) => {
+                anyhow::bail!(
+                    "{} The `{}` instruction is not supported in the runtime code",
+                    location,
+                    self.identifier.to_uppercase(),
+                )
+            }
+            (Availability::Runtime, Some(compiler_llvm_context::CodeType::Deploy)) => {
+                anyhow::bail!(
+                    "{} The `{}` instruction is not supported in the deploy code",
+                    location,
+                    self.identifier.to_uppercase(),
+                )
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// The Yul builtin function registry.
+///
+pub static BUILTINS: &[Builtin] = &[
+    Builtin::new(Name::Add, "add", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Sub, "sub", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Mul, "mul", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Div, "div", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Mod, "mod", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Sdiv, "sdiv", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Smod, "smod", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Lt, "lt", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Gt, "gt", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Eq, "eq", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::IsZero, "iszero", 1, 1, SideEffects::Pure),
+    Builtin::new(Name::Slt, "slt", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Sgt, "sgt", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Or, "or", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Xor, "xor", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Not, "not", 1, 1, SideEffects::Pure),
+    Builtin::new(Name::And, "and", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Shl, "shl", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Shr, "shr", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Sar, "sar", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Byte, "byte", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Pop, "pop", 1, 0, SideEffects::Pure),
+    Builtin::new(Name::AddMod, "addmod", 3, 1, SideEffects::Pure),
+    Builtin::new(Name::MulMod, "mulmod", 3, 1, SideEffects::Pure),
+    Builtin::new(Name::Exp, "exp", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::SignExtend, "signextend", 2, 1, SideEffects::Pure),
+    Builtin::new(Name::Keccak256, "keccak256", 2, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::MLoad, "mload", 1, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::MStore, "mstore", 2, 0, SideEffects::WriteMemory),
+    Builtin::new(Name::MStore8, "mstore8", 2, 0, SideEffects::WriteMemory),
+    Builtin::new(Name::MCopy, "mcopy", 3, 0, SideEffects::WriteMemory),
+    Builtin::new(Name::SLoad, "sload", 1, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::SStore, "sstore", 2, 0, SideEffects::WriteState),
+    // zkEVM has no storage cleared at the end of the transaction, and a dedicated region of the
+    // persistent storage would keep the values the code does not reset, so the transient storage
+    // cannot be emulated without silently changing the contract behavior.
+    Builtin::new(Name::TLoad, "tload", 1, 1, SideEffects::ReadOnly).unsupported(),
+    Builtin::new(Name::TStore, "tstore", 2, 0, SideEffects::WriteState).unsupported(),
+    Builtin::new(
+        Name::LoadImmutable,
+        "loadimmutable",
+        1,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::SetImmutable,
+        "setimmutable",
+        3,
+        0,
+        SideEffects::WriteState,
+    )
+    .deploy_code_only(),
+    Builtin::new(
+        Name::CallDataLoad,
+        "calldataload",
+        1,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::CallDataSize,
+        "calldatasize",
+        0,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::CallDataCopy,
+        "calldatacopy",
+        3,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(Name::CodeSize, "codesize", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::CodeCopy, "codecopy", 3, 0, SideEffects::WriteMemory).deploy_code_only(),
+    Builtin::new(
+        Name::ExtCodeSize,
+        "extcodesize",
+        1,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::ExtCodeHash,
+        "extcodehash",
+        1,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::ReturnDataSize,
+        "returndatasize",
+        0,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::ReturnDataCopy,
+        "returndatacopy",
+        3,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(Name::Return, "return", 2, 0, SideEffects::Terminate),
+    Builtin::new(Name::Revert, "revert", 2, 0, SideEffects::Terminate),
+    Builtin::new(Name::Stop, "stop", 0, 0, SideEffects::Terminate),
+    Builtin::new(Name::Invalid, "invalid", 0, 0, SideEffects::Terminate),
+    Builtin::new(Name::Log0, "log0", 2, 0, SideEffects::WriteState),
+    Builtin::new(Name::Log1, "log1", 3, 0, SideEffects::WriteState),
+    Builtin::new(Name::Log2, "log2", 4, 0, SideEffects::WriteState),
+    Builtin::new(Name::Log3, "log3", 5, 0, SideEffects::WriteState),
+    Builtin::new(Name::Log4, "log4", 6, 0, SideEffects::WriteState),
+    Builtin::new(Name::Call, "call", 7, 1, SideEffects::Call),
+    Builtin::new(Name::CallCode, "callcode", 7, 1, SideEffects::Call).unsupported(),
+    Builtin::new(Name::DelegateCall, "delegatecall", 6, 1, SideEffects::Call),
+    Builtin::new(Name::StaticCall, "staticcall", 6, 1, SideEffects::Call),
+    Builtin::new(Name::Create, "create", 3, 1, SideEffects::Call),
+    Builtin::new(Name::Create2, "create2", 4, 1, SideEffects::Call),
+    Builtin::new(Name::ZkCreate, "$zk_create", 3, 1, SideEffects::Call),
+    Builtin::new(Name::ZkCreate2, "$zk_create2", 4, 1, SideEffects::Call),
+    Builtin::new(Name::DataSize, "datasize", 1, 1, SideEffects::Pure),
+    Builtin::new(Name::DataOffset, "dataoffset", 1, 1, SideEffects::Pure),
+    Builtin::new(Name::DataCopy, "datacopy", 3, 0, SideEffects::WriteMemory),
+    Builtin::new(Name::LinkerSymbol, "linkersymbol", 1, 1, SideEffects::Pure),
+    Builtin::new(Name::MemoryGuard, "memoryguard", 1, 1, SideEffects::Pure),
+    Builtin::new(Name::Address, "address", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Caller, "caller", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::CallValue, "callvalue", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Gas, "gas", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Balance, "balance", 1, 1, SideEffects::ReadOnly),
+    Builtin::new(
+        Name::SelfBalance,
+        "selfbalance",
+        0,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(Name::GasLimit, "gaslimit", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::GasPrice, "gasprice", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Origin, "origin", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::ChainId, "chainid", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Timestamp, "timestamp", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Number, "number", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::BlockHash, "blockhash", 1, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Difficulty, "difficulty", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Prevrandao, "prevrandao", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::CoinBase, "coinbase", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::BaseFee, "basefee", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::BlobHash, "blobhash", 1, 1, SideEffects::ReadOnly).unsupported(),
+    Builtin::new(
+        Name::BlobBaseFee,
+        "blobbasefee",
+        0,
+        1,
+        SideEffects::ReadOnly,
+    )
+    .unsupported(),
+    Builtin::new(Name::MSize, "msize", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(Name::Pc, "pc", 0, 1, SideEffects::ReadOnly).unsupported(),
+    Builtin::new(
+        Name::ExtCodeCopy,
+        "extcodecopy",
+        4,
+        0,
+        SideEffects::WriteMemory,
+    )
+    .unsupported(),
+    Builtin::new(
+        Name::SelfDestruct,
+        "selfdestruct",
+        1,
+        0,
+        SideEffects::Terminate,
+    )
+    .unsupported(),
+    Builtin::new(Name::ZkToL1, "$zk_to_l1", 3, 1, SideEffects::WriteState),
+    Builtin::new(
+        Name::ZkCodeSource,
+        "$zk_code_source",
+        0,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::ZkPrecompile,
+        "$zk_precompile",
+        2,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(Name::ZkMeta, "$zk_meta", 0, 1, SideEffects::ReadOnly),
+    Builtin::new(
+        Name::ZkSetContextU128,
+        "$zk_set_context_u128",
+        1,
+        0,
+        SideEffects::WriteState,
+    ),
+    Builtin::new(
+        Name::ZkSetPubdataPrice,
+        "$zk_set_pubdata_price",
+        1,
+        0,
+        SideEffects::WriteState,
+    ),
+    Builtin::new(
+        Name::ZkIncrementTxCounter,
+        "$zk_increment_tx_counter",
+        0,
+        0,
+        SideEffects::WriteState,
+    ),
+    Builtin::new(
+        Name::ZkEventInitialize,
+        "$zk_event_initialize",
+        2,
+        0,
+        SideEffects::WriteState,
+    ),
+    Builtin::new(
+        Name::ZkEventWrite,
+        "$zk_event_write",
+        2,
+        0,
+        SideEffects::WriteState,
+    ),
+    Builtin::new(Name::ZkMimicCall, "$zk_mimic_call", 3, 1, SideEffects::Call),
+    Builtin::new(
+        Name::ZkSystemMimicCall,
+        "$zk_system_mimic_call",
+        5,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkMimicCallByRef,
+        "$zk_mimic_call_byref",
+        2,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkSystemMimicCallByRef,
+        "$zk_system_mimic_call_byref",
+        4,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(Name::ZkRawCall, "$zk_raw_call", 4, 1, SideEffects::Call),
+    Builtin::new(
+        Name::ZkRawCallByRef,
+        "$zk_raw_call_byref",
+        3,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkSystemCall,
+        "$zk_system_call",
+        6,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkSystemCallByRef,
+        "$zk_system_call_byref",
+        5,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkStaticRawCall,
+        "$zk_static_raw_call",
+        4,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkStaticRawCallByRef,
+        "$zk_static_raw_call_byref",
+        3,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkStaticSystemCall,
+        "$zk_static_system_call",
+        6,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkStaticSystemCallByRef,
+        "$zk_static_system_call_byref",
+        5,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkDelegateRawCall,
+        "$zk_delegate_raw_call",
+        4,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkDelegateRawCallByRef,
+        "$zk_delegate_raw_call_byref",
+        3,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkDelegateSystemCall,
+        "$zk_delegate_system_call",
+        6,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkDelegateSystemCallByRef,
+        "$zk_delegate_system_call_byref",
+        5,
+        1,
+        SideEffects::Call,
+    ),
+    Builtin::new(
+        Name::ZkLoadCalldataIntoActivePtr,
+        "$zk_load_calldata_into_active_ptr",
+        0,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(
+        Name::ZkLoadReturndataIntoActivePtr,
+        "$zk_load_returndata_into_active_ptr",
+        0,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(
+        Name::ZkPtrAddIntoActive,
+        "$zk_ptr_add_into_active",
+        1,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(
+        Name::ZkPtrShrinkIntoActive,
+        "$zk_ptr_shrink_into_active",
+        1,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(
+        Name::ZkPtrPackIntoActive,
+        "$zk_ptr_pack_into_active",
+        1,
+        0,
+        SideEffects::WriteMemory,
+    ),
+    Builtin::new(
+        Name::ZkMultiplicationHigh,
+        "$zk_multiplication_high",
+        2,
+        1,
+        SideEffects::Pure,
+    ),
+    Builtin::new(
+        Name::ZkGlobalLoad,
+        "$zk_global_load",
+        1,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::ZkGlobalExtraAbiData,
+        "$zk_global_extra_abi_data",
+        1,
+        1,
+        SideEffects::ReadOnly,
+    ),
+    Builtin::new(
+        Name::ZkGlobalStore,
+        "$zk_global_store",
+        2,
+        0,
+        SideEffects::WriteState,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::token::location::Location;
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::expression::function_call::name::Name;
+    use crate::yul::parser::statement::object::Object;
+
+    use super::simulation::kind::Kind as SimulationKind;
+    use super::simulation::Simulation;
+    use super::simulation::SIMULATIONS;
+    use super::BUILTINS;
+
+    #[test]
+    fn ok_identifiers_round_trip() {
+        for builtin in BUILTINS.iter() {
+            let name = Name::from(builtin.identifier);
+            assert_eq!(name, builtin.name);
+            assert_eq!(name.to_string(), builtin.identifier);
+        }
+    }
+
+    #[test]
+    fn ok_simulation_identifiers() {
+        for simulation in SIMULATIONS.iter() {
+            let entry = Simulation::get(simulation.identifier).expect("Always exists");
+            assert_eq!(entry.kind, simulation.kind);
+        }
+
+        let getter = format!(
+            "{}{}",
+            compiler_llvm_context::GLOBAL_VERBATIM_GETTER_PREFIX,
+            compiler_llvm_context::GLOBAL_CALL_FLAGS
+        );
+        assert_eq!(
+            Simulation::get(getter.as_str()).map(|simulation| simulation.kind),
+            Some(SimulationKind::GlobalGetter)
+        );
+        assert!(Simulation::get("mul_1024").is_none());
+    }
+
+    #[test]
+    fn error_invalid_number_of_arguments() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(0)
+            return(0, 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidNumberOfArguments {
+                location: Location::new(5, 13),
+                identifier: "mstore".to_owned(),
+                expected: 2,
+                found: 1,
+            }
+            .into())
+        );
+    }
+}
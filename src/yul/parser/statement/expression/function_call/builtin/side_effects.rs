@@ -0,0 +1,28 @@
+//!
+//! The Yul builtin function side effects class.
+//!
+
+use serde::Serialize;
+
+///
+/// The Yul builtin function side effects class.
+///
+/// The classes are ordered by their strength, so each class implies the effects of the previous
+/// ones, except for `Pure`, which has no effects at all.
+///
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum SideEffects {
+    /// The result only depends on the arguments.
+    Pure,
+    /// Reads the memory, storage, or execution environment.
+    ReadOnly,
+    /// Writes to the heap memory or the active pointer.
+    WriteMemory,
+    /// Writes to the storage, immutables, globals, or emits events and messages.
+    WriteState,
+    /// Calls or creates another contract.
+    Call,
+    /// Terminates the execution.
+    Terminate,
+}
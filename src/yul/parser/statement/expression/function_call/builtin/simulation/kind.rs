@@ -0,0 +1,78 @@
+//!
+//! The zkEVM verbatim simulation kind.
+//!
+
+///
+/// The zkEVM verbatim simulation kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// The `to_l1` simulation.
+    ToL1,
+    /// The `code_source` simulation.
+    CodeSource,
+    /// The `precompile` simulation.
+    Precompile,
+    /// The `meta` simulation.
+    Meta,
+    /// The `mimic_call` simulation.
+    MimicCall,
+    /// The `mimic_call_byref` simulation.
+    MimicCallByRef,
+    /// The `system_mimic_call` simulation.
+    SystemMimicCall,
+    /// The `system_mimic_call_byref` simulation.
+    SystemMimicCallByRef,
+    /// The `raw_call` simulation.
+    RawCall,
+    /// The `raw_call_byref` simulation.
+    RawCallByRef,
+    /// The `system_call` simulation.
+    SystemCall,
+    /// The `system_call_byref` simulation.
+    SystemCallByRef,
+    /// The `raw_static_call` simulation.
+    RawStaticCall,
+    /// The `raw_static_call_byref` simulation.
+    RawStaticCallByRef,
+    /// The `system_static_call` simulation.
+    SystemStaticCall,
+    /// The `system_static_call_byref` simulation.
+    SystemStaticCallByRef,
+    /// The `raw_delegate_call` simulation.
+    RawDelegateCall,
+    /// The `raw_delegate_call_byref` simulation.
+    RawDelegateCallByRef,
+    /// The `system_delegate_call` simulation.
+    SystemDelegateCall,
+    /// The `system_delegate_call_byref` simulation.
+    SystemDelegateCallByRef,
+    /// The `set_context_u128` simulation.
+    SetContextU128,
+    /// The `set_pubdata_price` simulation.
+    SetPubdataPrice,
+    /// The `increment_tx_counter` simulation.
+    IncrementTxCounter,
+    /// The `event_initialize` simulation.
+    EventInitialize,
+    /// The `event_write` simulation.
+    EventWrite,
+    /// The `calldata_ptr_to_active` simulation.
+    CalldataPtrToActive,
+    /// The `return_data_ptr_to_active` simulation.
+    ReturnDataPtrToActive,
+    /// The `active_ptr_add_assign` simulation.
+    ActivePtrAddAssign,
+    /// The `active_ptr_shrink_assign` simulation.
+    ActivePtrShrinkAssign,
+    /// The `active_ptr_pack_assign` simulation.
+    ActivePtrPackAssign,
+    /// The `mul_high` simulation.
+    MulHigh,
+    /// The `mul_512` simulation.
+    Mul512,
+    /// The `throw` simulation.
+    Throw,
+    /// The global variable getters, whose identifiers start with the common prefix.
+    GlobalGetter,
+}
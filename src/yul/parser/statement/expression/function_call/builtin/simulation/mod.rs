@@ -0,0 +1,140 @@
+//!
+//! The zkEVM verbatim simulation registry.
+//!
+
+pub mod kind;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use self::kind::Kind;
+
+///
+/// The zkEVM verbatim simulation description.
+///
+/// The simulations are called via `verbatim_<N>i_<M>o` with the simulation identifier as the
+/// first argument, so the code generator consults the registry to check the arity against `N` and
+/// `M` and to select the lowering.
+///
+#[derive(Debug, Clone)]
+pub struct Simulation {
+    /// The simulation kind.
+    pub kind: Kind,
+    /// The simulation identifier.
+    pub identifier: &'static str,
+    /// The number of input arguments, excluding the identifier.
+    pub input_size: usize,
+    /// The number of output values.
+    pub output_size: usize,
+}
+
+impl Simulation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub const fn new(
+        kind: Kind,
+        identifier: &'static str,
+        input_size: usize,
+        output_size: usize,
+    ) -> Self {
+        Self {
+            kind,
+            identifier,
+            input_size,
+            output_size,
+        }
+    }
+
+    ///
+    /// Returns the registry entry for the verbatim `identifier`.
+    ///
+    /// The global variable getters share a single entry, as their identifiers only differ in the
+    /// variable name following the common prefix.
+    ///
+    pub fn get(identifier: &str) -> Option<&'static Self> {
+        static INDEX: OnceLock<HashMap<&'static str, &'static Simulation>> = OnceLock::new();
+
+        let identifier =
+            if identifier.starts_with(compiler_llvm_context::GLOBAL_VERBATIM_GETTER_PREFIX) {
+                compiler_llvm_context::GLOBAL_VERBATIM_GETTER_PREFIX
+            } else {
+                identifier
+            };
+
+        INDEX
+            .get_or_init(|| {
+                SIMULATIONS
+                    .iter()
+                    .map(|simulation| (simulation.identifier, simulation))
+                    .collect()
+            })
+            .get(identifier)
+            .copied()
+    }
+}
+
+///
+/// The zkEVM verbatim simulation registry.
+///
+pub static SIMULATIONS: &[Simulation] = &[
+    Simulation::new(Kind::ToL1, "to_l1", 3, 1),
+    Simulation::new(Kind::CodeSource, "code_source", 0, 1),
+    Simulation::new(Kind::Precompile, "precompile", 2, 1),
+    Simulation::new(Kind::Meta, "meta", 0, 1),
+    Simulation::new(Kind::MimicCall, "mimic_call", 3, 1),
+    Simulation::new(Kind::MimicCallByRef, "mimic_call_byref", 2, 1),
+    Simulation::new(Kind::SystemMimicCall, "system_mimic_call", 7, 1),
+    Simulation::new(Kind::SystemMimicCallByRef, "system_mimic_call_byref", 6, 1),
+    Simulation::new(Kind::RawCall, "raw_call", 4, 1),
+    Simulation::new(Kind::RawCallByRef, "raw_call_byref", 3, 1),
+    Simulation::new(Kind::SystemCall, "system_call", 6, 1),
+    Simulation::new(Kind::SystemCallByRef, "system_call_byref", 5, 1),
+    Simulation::new(Kind::RawStaticCall, "raw_static_call", 4, 1),
+    Simulation::new(Kind::RawStaticCallByRef, "raw_static_call_byref", 3, 1),
+    Simulation::new(Kind::SystemStaticCall, "system_static_call", 6, 1),
+    Simulation::new(
+        Kind::SystemStaticCallByRef,
+        "system_static_call_byref",
+        5,
+        1,
+    ),
+    Simulation::new(Kind::RawDelegateCall, "raw_delegate_call", 4, 1),
+    Simulation::new(Kind::RawDelegateCallByRef, "raw_delegate_call_byref", 3, 1),
+    Simulation::new(Kind::SystemDelegateCall, "system_delegate_call", 6, 1),
+    Simulation::new(
+        Kind::SystemDelegateCallByRef,
+        "system_delegate_call_byref",
+        5,
+        1,
+    ),
+    Simulation::new(Kind::SetContextU128, "set_context_u128", 1, 0),
+    Simulation::new(Kind::SetPubdataPrice, "set_pubdata_price", 1, 0),
+    Simulation::new(Kind::IncrementTxCounter, "increment_tx_counter", 0, 0),
+    Simulation::new(Kind::EventInitialize, "event_initialize", 2, 0),
+    Simulation::new(Kind::EventWrite, "event_write", 2, 0),
+    Simulation::new(Kind::CalldataPtrToActive, "calldata_ptr_to_active", 0, 0),
+    Simulation::new(
+        Kind::ReturnDataPtrToActive,
+        "return_data_ptr_to_active",
+        0,
+        0,
+    ),
+    Simulation::new(Kind::ActivePtrAddAssign, "active_ptr_add_assign", 1, 0),
+    Simulation::new(
+        Kind::ActivePtrShrinkAssign,
+        "active_ptr_shrink_assign",
+        1,
+        0,
+    ),
+    Simulation::new(Kind::ActivePtrPackAssign, "active_ptr_pack_assign", 1, 0),
+    Simulation::new(Kind::MulHigh, "mul_high", 2, 1),
+    Simulation::new(Kind::Mul512, "mul_512", 2, 2),
+    Simulation::new(Kind::Throw, "throw", 0, 0),
+    Simulation::new(
+        Kind::GlobalGetter,
+        compiler_llvm_context::GLOBAL_VERBATIM_GETTER_PREFIX,
+        0,
+        1,
+    ),
+];
@@ -0,0 +1,157 @@
+//!
+//! Translates the `datacopy` use cases.
+//!
+
+///
+/// Translates the contract hash copying.
+///
+pub fn contract_hash<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    offset: inkwell::values::IntValue<'ctx>,
+    value: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let offset = context.builder().build_int_add(
+        offset,
+        context.field_const(
+            (compiler_common::BYTE_LENGTH_X32 + compiler_common::BYTE_LENGTH_FIELD) as u64,
+        ),
+        "datacopy_contract_hash_offset",
+    );
+
+    compiler_llvm_context::memory::store(context, offset, value)?;
+
+    Ok(())
+}
+
+///
+/// Translates the data segment copying.
+///
+/// The full words are stored at once, whereas the trailing bytes are stored one by one, so the
+/// memory after the copied range is not overwritten.
+///
+pub fn static_data<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: &[u8],
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let mut chunks = source.chunks_exact(compiler_common::BYTE_LENGTH_FIELD);
+
+    let mut offset = 0;
+    for (index, chunk) in chunks.by_ref().enumerate() {
+        let datacopy_destination = context.builder().build_int_add(
+            destination,
+            context.field_const(offset as u64),
+            format!("datacopy_destination_index_{index}").as_str(),
+        );
+        let datacopy_value = context.field_const_str_hex(hex::encode(chunk).as_str());
+        compiler_llvm_context::memory::store(context, datacopy_destination, datacopy_value)?;
+        offset += chunk.len();
+    }
+
+    for byte in chunks.remainder().iter() {
+        let datacopy_destination = context.builder().build_int_add(
+            destination,
+            context.field_const(offset as u64),
+            format!("datacopy_destination_byte_{offset}").as_str(),
+        );
+        let datacopy_value = context.field_const(*byte as u64);
+        compiler_llvm_context::memory::store_byte(context, datacopy_destination, datacopy_value)?;
+        offset += 1;
+    }
+
+    Ok(())
+}
+
+///
+/// Translates the data segment copying with the `size` only known at runtime.
+///
+/// Each full word is stored at once if it fits into `size`, and otherwise its bytes are stored
+/// one by one until `size` is reached. The bytes beyond the segment are not copied.
+///
+pub fn static_data_sized<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    destination: inkwell::values::IntValue<'ctx>,
+    source: &[u8],
+    size: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<()>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let join_block = context.append_basic_block("datacopy_join");
+
+    let mut offset = 0;
+    for (index, chunk) in source
+        .chunks(compiler_common::BYTE_LENGTH_FIELD)
+        .enumerate()
+    {
+        let next_block = context.append_basic_block(format!("datacopy_next_{index}").as_str());
+
+        if chunk.len() == compiler_common::BYTE_LENGTH_FIELD {
+            let word_block = context.append_basic_block(format!("datacopy_word_{index}").as_str());
+            let bytes_block =
+                context.append_basic_block(format!("datacopy_bytes_{index}").as_str());
+
+            let is_word = context.builder().build_int_compare(
+                inkwell::IntPredicate::UGE,
+                size,
+                context.field_const((offset + chunk.len()) as u64),
+                format!("datacopy_is_word_{index}").as_str(),
+            );
+            context.build_conditional_branch(is_word, word_block, bytes_block);
+
+            context.set_basic_block(word_block);
+            let datacopy_destination = context.builder().build_int_add(
+                destination,
+                context.field_const(offset as u64),
+                format!("datacopy_destination_index_{index}").as_str(),
+            );
+            let datacopy_value = context.field_const_str_hex(hex::encode(chunk).as_str());
+            compiler_llvm_context::memory::store(context, datacopy_destination, datacopy_value)?;
+            context.build_unconditional_branch(next_block);
+
+            context.set_basic_block(bytes_block);
+        }
+
+        for (byte_index, byte) in chunk.iter().enumerate() {
+            let byte_offset = offset + byte_index;
+            let store_block =
+                context.append_basic_block(format!("datacopy_byte_{byte_offset}").as_str());
+
+            let is_in_range = context.builder().build_int_compare(
+                inkwell::IntPredicate::UGT,
+                size,
+                context.field_const(byte_offset as u64),
+                format!("datacopy_is_in_range_{byte_offset}").as_str(),
+            );
+            context.build_conditional_branch(is_in_range, store_block, join_block);
+
+            context.set_basic_block(store_block);
+            let datacopy_destination = context.builder().build_int_add(
+                destination,
+                context.field_const(byte_offset as u64),
+                format!("datacopy_destination_byte_{byte_offset}").as_str(),
+            );
+            let datacopy_value = context.field_const(*byte as u64);
+            compiler_llvm_context::memory::store_byte(
+                context,
+                datacopy_destination,
+                datacopy_value,
+            )?;
+        }
+        context.build_unconditional_branch(next_block);
+
+        context.set_basic_block(next_block);
+        offset += chunk.len();
+    }
+    context.build_unconditional_branch(join_block);
+
+    context.set_basic_block(join_block);
+
+    Ok(())
+}
@@ -2,11 +2,16 @@
 //! The function call subexpression.
 //!
 
+pub mod builtin;
+pub mod datacopy;
 pub mod name;
 pub mod verbatim;
 
+use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
 use num::ToPrimitive;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -15,14 +20,16 @@ use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::expression::Expression;
 
+use self::builtin::Builtin;
 use self::name::Name;
 
 ///
 /// The Yul function call subexpression.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct FunctionCall {
     /// The location.
     pub location: Location,
@@ -30,6 +37,10 @@ pub struct FunctionCall {
     pub name: Name,
     /// The function arguments expression list.
     pub arguments: Vec<Expression>,
+    /// The data segment copied by `datacopy`, resolved by its `dataoffset` source offset through
+    /// the object data segments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_segment: Option<Vec<u8>>,
 }
 
 impl FunctionCall {
@@ -86,10 +97,28 @@ impl FunctionCall {
             }
         }
 
+        let expected_arguments_count = match name {
+            Name::UserDefined(_) => None,
+            Name::Verbatim { input_size, .. } => Some(input_size + 1),
+            ref name => Builtin::get(name).map(|builtin| builtin.input_size),
+        };
+        if let Some(expected) = expected_arguments_count {
+            if arguments.len() != expected {
+                return Err(ParserError::InvalidNumberOfArguments {
+                    location,
+                    identifier: name.to_string(),
+                    expected,
+                    found: arguments.len(),
+                }
+                .into());
+            }
+        }
+
         Ok(Self {
             location,
             name,
             arguments,
+            data_segment: None,
         })
     }
 
@@ -105,6 +134,10 @@ impl FunctionCall {
     {
         let location = self.location;
 
+        if let Some(builtin) = Builtin::get(&self.name) {
+            builtin.check_availability(context.is_system_mode(), context.code_type(), location)?;
+        }
+
         match self.name {
             Name::UserDefined(name)
                 if name
@@ -192,6 +225,15 @@ impl FunctionCall {
                         values.len()
                     );
                 }
+                for (index, value) in values.iter_mut().enumerate() {
+                    let parameter = function
+                        .borrow()
+                        .declaration()
+                        .value
+                        .get_nth_param(index as u32)
+                        .expect("Always exists");
+                    *value = Type::cast_llvm(context, *value, parameter.get_type(), false);
+                }
 
                 let return_value = context.build_invoke(
                     function.borrow().declaration(),
@@ -203,7 +245,7 @@ impl FunctionCall {
             }
 
             Name::Add => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::addition(
                     context,
                     arguments[0].into_int_value(),
@@ -212,7 +254,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Sub => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::subtraction(
                     context,
                     arguments[0].into_int_value(),
@@ -221,7 +263,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Mul => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::multiplication(
                     context,
                     arguments[0].into_int_value(),
@@ -230,7 +272,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Div => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::division(
                     context,
                     arguments[0].into_int_value(),
@@ -239,7 +281,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Mod => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::remainder(
                     context,
                     arguments[0].into_int_value(),
@@ -248,7 +290,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Sdiv => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::division_signed(
                     context,
                     arguments[0].into_int_value(),
@@ -257,7 +299,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Smod => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::arithmetic::remainder_signed(
                     context,
                     arguments[0].into_int_value(),
@@ -267,7 +309,7 @@ impl FunctionCall {
             }
 
             Name::Lt => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::comparison::compare(
                     context,
                     arguments[0].into_int_value(),
@@ -277,7 +319,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Gt => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::comparison::compare(
                     context,
                     arguments[0].into_int_value(),
@@ -287,7 +329,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Eq => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::comparison::compare(
                     context,
                     arguments[0].into_int_value(),
@@ -297,7 +339,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::IsZero => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::comparison::compare(
                     context,
                     arguments[0].into_int_value(),
@@ -307,7 +349,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Slt => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::comparison::compare(
                     context,
                     arguments[0].into_int_value(),
@@ -317,7 +359,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Sgt => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::comparison::compare(
                     context,
                     arguments[0].into_int_value(),
@@ -328,7 +370,7 @@ impl FunctionCall {
             }
 
             Name::Or => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::or(
                     context,
                     arguments[0].into_int_value(),
@@ -337,7 +379,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Xor => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::xor(
                     context,
                     arguments[0].into_int_value(),
@@ -346,7 +388,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Not => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::xor(
                     context,
                     arguments[0].into_int_value(),
@@ -355,7 +397,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::And => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::and(
                     context,
                     arguments[0].into_int_value(),
@@ -364,7 +406,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Shl => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::shift_left(
                     context,
                     arguments[0].into_int_value(),
@@ -373,7 +415,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Shr => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::shift_right(
                     context,
                     arguments[0].into_int_value(),
@@ -382,7 +424,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Sar => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::shift_right_arithmetic(
                     context,
                     arguments[0].into_int_value(),
@@ -391,7 +433,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Byte => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::bitwise::byte(
                     context,
                     arguments[0].into_int_value(),
@@ -400,12 +442,12 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Pop => {
-                let _arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let _arguments = self.pop_arguments_llvm(context)?;
                 Ok(None)
             }
 
             Name::AddMod => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::math::add_mod(
                     context,
                     arguments[0].into_int_value(),
@@ -415,7 +457,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::MulMod => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::math::mul_mod(
                     context,
                     arguments[0].into_int_value(),
@@ -425,7 +467,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::Exp => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::math::exponent(
                     context,
                     arguments[0].into_int_value(),
@@ -434,7 +476,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::SignExtend => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::math::sign_extend(
                     context,
                     arguments[0].into_int_value(),
@@ -444,7 +486,7 @@ impl FunctionCall {
             }
 
             Name::Keccak256 => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 let input_offset = arguments[0].into_int_value();
                 let input_length = arguments[1].into_int_value();
 
@@ -460,12 +502,12 @@ impl FunctionCall {
             }
 
             Name::MLoad => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::memory::load(context, arguments[0].into_int_value())
                     .map(Some)
             }
             Name::MStore => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::memory::store(
                     context,
                     arguments[0].into_int_value(),
@@ -474,7 +516,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::MStore8 => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::memory::store_byte(
                     context,
                     arguments[0].into_int_value(),
@@ -482,14 +524,24 @@ impl FunctionCall {
                 )
                 .map(|_| None)
             }
+            Name::MCopy => {
+                let arguments = self.pop_arguments_llvm(context)?;
+                crate::evmla::assembly::instruction::mcopy::translate(
+                    context,
+                    arguments[0].into_int_value(),
+                    arguments[1].into_int_value(),
+                    arguments[2].into_int_value(),
+                )
+                .map(|_| None)
+            }
 
             Name::SLoad => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::storage::load(context, arguments[0].into_int_value())
                     .map(Some)
             }
             Name::SStore => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::storage::store(
                     context,
                     arguments[0].into_int_value(),
@@ -498,7 +550,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::LoadImmutable => {
-                let mut arguments = self.pop_arguments::<D, 1>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
                 let key = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `load_immutable` literal is missing", location)
                 })?;
@@ -520,7 +572,7 @@ impl FunctionCall {
                 compiler_llvm_context::immutable::load(context, index).map(Some)
             }
             Name::SetImmutable => {
-                let mut arguments = self.pop_arguments::<D, 3>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
                 let key = arguments[1].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `load_immutable` literal is missing", location)
                 })?;
@@ -537,7 +589,7 @@ impl FunctionCall {
             }
 
             Name::CallDataLoad => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
 
                 match context
                     .code_type()
@@ -569,7 +621,7 @@ impl FunctionCall {
                 }
             }
             Name::CallDataCopy => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
 
                 match context
                     .code_type()
@@ -614,17 +666,7 @@ impl FunctionCall {
                 }
             }
             Name::CodeCopy => {
-                if let compiler_llvm_context::CodeType::Runtime = context
-                    .code_type()
-                    .ok_or_else(|| anyhow::anyhow!("The contract code part type is undefined"))?
-                {
-                    anyhow::bail!(
-                        "{} The `CODECOPY` instruction is not supported in the runtime code",
-                        location,
-                    );
-                }
-
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::calldata::copy(
                     context,
                     arguments[0].into_int_value(),
@@ -635,7 +677,7 @@ impl FunctionCall {
             }
             Name::ReturnDataSize => compiler_llvm_context::return_data::size(context).map(Some),
             Name::ReturnDataCopy => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::return_data::copy(
                     context,
                     arguments[0].into_int_value(),
@@ -645,18 +687,18 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::ExtCodeSize => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::ext_code::size(context, arguments[0].into_int_value())
                     .map(Some)
             }
             Name::ExtCodeHash => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::ext_code::hash(context, arguments[0].into_int_value())
                     .map(Some)
             }
 
             Name::Return => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::r#return::r#return(
                     context,
                     arguments[0].into_int_value(),
@@ -665,7 +707,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::Revert => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::r#return::revert(
                     context,
                     arguments[0].into_int_value(),
@@ -677,7 +719,7 @@ impl FunctionCall {
             Name::Invalid => compiler_llvm_context::r#return::invalid(context).map(|_| None),
 
             Name::Log0 => {
-                let arguments = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::event::log(
                     context,
                     arguments[0].into_int_value(),
@@ -687,7 +729,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::Log1 => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::event::log(
                     context,
                     arguments[0].into_int_value(),
@@ -700,7 +742,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::Log2 => {
-                let arguments = self.pop_arguments_llvm::<D, 4>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::event::log(
                     context,
                     arguments[0].into_int_value(),
@@ -713,7 +755,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::Log3 => {
-                let arguments = self.pop_arguments_llvm::<D, 5>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::event::log(
                     context,
                     arguments[0].into_int_value(),
@@ -726,7 +768,7 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::Log4 => {
-                let arguments = self.pop_arguments_llvm::<D, 6>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 compiler_llvm_context::event::log(
                     context,
                     arguments[0].into_int_value(),
@@ -740,7 +782,7 @@ impl FunctionCall {
             }
 
             Name::Call => {
-                let mut arguments = self.pop_arguments::<D, 7>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
 
                 let gas = arguments[0].value.into_int_value();
                 let address = arguments[1].value.into_int_value();
@@ -770,7 +812,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::StaticCall => {
-                let mut arguments = self.pop_arguments::<D, 6>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
 
                 let gas = arguments[0].value.into_int_value();
                 let address = arguments[1].value.into_int_value();
@@ -799,7 +841,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::DelegateCall => {
-                let mut arguments = self.pop_arguments::<D, 6>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
 
                 let gas = arguments[0].value.into_int_value();
                 let address = arguments[1].value.into_int_value();
@@ -829,7 +871,7 @@ impl FunctionCall {
             }
 
             Name::Create | Name::ZkCreate => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
 
                 let value = arguments[0].into_int_value();
                 let input_offset = arguments[1].into_int_value();
@@ -839,7 +881,7 @@ impl FunctionCall {
                     .map(Some)
             }
             Name::Create2 | Name::ZkCreate2 => {
-                let arguments = self.pop_arguments_llvm::<D, 4>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
 
                 let value = arguments[0].into_int_value();
                 let input_offset = arguments[1].into_int_value();
@@ -856,7 +898,7 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::DataOffset => {
-                let mut arguments = self.pop_arguments::<D, 1>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
 
                 let identifier = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `dataoffset` object identifier is missing", location)
@@ -865,7 +907,7 @@ impl FunctionCall {
                 compiler_llvm_context::create::contract_hash(context, identifier).map(Some)
             }
             Name::DataSize => {
-                let mut arguments = self.pop_arguments::<D, 1>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
 
                 let identifier = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `dataoffset` object identifier is missing", location)
@@ -874,21 +916,46 @@ impl FunctionCall {
                 compiler_llvm_context::create::header_size(context, identifier).map(Some)
             }
             Name::DataCopy => {
-                let arguments = self.pop_arguments_llvm::<D, 3>(context)?;
-                let offset = context.builder().build_int_add(
-                    arguments[0].into_int_value(),
-                    context.field_const(
-                        (compiler_common::BYTE_LENGTH_X32 + compiler_common::BYTE_LENGTH_FIELD)
-                            as u64,
+                let mut segment = match self.data_segment.take() {
+                    Some(segment) => segment,
+                    None => {
+                        let arguments = self.pop_arguments(context)?;
+                        return datacopy::contract_hash(
+                            context,
+                            arguments[0].value.into_int_value(),
+                            arguments[1].value.into_int_value(),
+                        )
+                        .map(|_| None);
+                    }
+                };
+
+                // The `dataoffset` of a data segment has no runtime value, so it is not translated.
+                let mut size =
+                    Self::widen_argument(context, self.arguments.pop().expect("Always exists"))?;
+                self.arguments.pop();
+                let destination =
+                    Self::widen_argument(context, self.arguments.pop().expect("Always exists"))?
+                        .value
+                        .into_int_value();
+                match size.constant.take() {
+                    Some(size) => {
+                        if let Some(size) = size.to_usize() {
+                            segment.truncate(size);
+                        }
+                        datacopy::static_data(context, destination, segment.as_slice())
+                    }
+                    None => datacopy::static_data_sized(
+                        context,
+                        destination,
+                        segment.as_slice(),
+                        size.value.into_int_value(),
                     ),
-                    "datacopy_contract_hash_offset",
-                );
-                compiler_llvm_context::memory::store(context, offset, arguments[1].into_int_value())
-                    .map(|_| None)
+                }
+                .map(|_| None)
             }
 
             Name::LinkerSymbol => {
-                let mut arguments = self.pop_arguments::<D, 1>(context)?;
+                let mut arguments = self.pop_arguments(context)?;
                 let path = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} Linker symbol literal is missing", location)
                 })?;
@@ -900,7 +967,7 @@ impl FunctionCall {
                 ))
             }
             Name::MemoryGuard => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 Ok(Some(arguments[0]))
             }
 
@@ -910,7 +977,7 @@ impl FunctionCall {
             Name::CallValue => compiler_llvm_context::ether_gas::value(context).map(Some),
             Name::Gas => compiler_llvm_context::ether_gas::gas(context).map(Some),
             Name::Balance => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
 
                 let address = arguments[0].into_int_value();
                 compiler_llvm_context::ether_gas::balance(context, address).map(Some)
@@ -935,7 +1002,7 @@ impl FunctionCall {
                 compiler_llvm_context::contract_context::block_number(context).map(Some)
             }
             Name::BlockHash => {
-                let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
                 let index = arguments[0].into_int_value();
 
                 compiler_llvm_context::contract_context::block_hash(context, index).map(Some)
@@ -952,28 +1019,20 @@ impl FunctionCall {
                 output_size,
             } => verbatim::verbatim(context, &mut self, input_size, output_size),
 
-            Name::CallCode => {
-                let _arguments = self.pop_arguments_llvm::<D, 7>(context)?;
-                anyhow::bail!("{} The `CALLCODE` instruction is not supported", location)
-            }
-            Name::Pc => anyhow::bail!("{} The `PC` instruction is not supported", location),
-            Name::ExtCodeCopy => {
-                let _arguments = self.pop_arguments_llvm::<D, 4>(context)?;
-                anyhow::bail!(
-                    "{} The `EXTCODECOPY` instruction is not supported",
-                    location
-                )
-            }
-            Name::SelfDestruct => {
-                let _arguments = self.pop_arguments_llvm::<D, 1>(context)?;
-                anyhow::bail!(
-                    "{} The `SELFDESTRUCT` instruction is not supported",
-                    location
-                )
-            }
+            Name::CallCode
+            | Name::Pc
+            | Name::ExtCodeCopy
+            | Name::SelfDestruct
+            | Name::TLoad
+            | Name::TStore
+            | Name::BlobHash
+            | Name::BlobBaseFee => unreachable!("Rejected by the builtin availability check"),
 
             Name::ZkToL1 => {
-                let [is_first, in_0, in_1] = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let is_first = arguments[0];
+                let in_0 = arguments[1];
+                let in_1 = arguments[2];
 
                 compiler_llvm_context::zkevm_general::to_l1(
                     context,
@@ -987,7 +1046,9 @@ impl FunctionCall {
                 compiler_llvm_context::zkevm_general::code_source(context).map(Some)
             }
             Name::ZkPrecompile => {
-                let [in_0, in_1] = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let in_0 = arguments[0];
+                let in_1 = arguments[1];
 
                 compiler_llvm_context::zkevm_general::precompile(
                     context,
@@ -998,7 +1059,8 @@ impl FunctionCall {
             }
             Name::ZkMeta => compiler_llvm_context::zkevm_general::meta(context).map(Some),
             Name::ZkSetContextU128 => {
-                let [value] = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let value = arguments[0];
 
                 compiler_llvm_context::zkevm_general::set_context_value(
                     context,
@@ -1007,7 +1069,8 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::ZkSetPubdataPrice => {
-                let [value] = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let value = arguments[0];
 
                 compiler_llvm_context::zkevm_general::set_pubdata_price(
                     context,
@@ -1019,7 +1082,9 @@ impl FunctionCall {
                 compiler_llvm_context::zkevm_general::increment_tx_counter(context).map(|_| None)
             }
             Name::ZkEventInitialize => {
-                let [operand_1, operand_2] = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let operand_1 = arguments[0];
+                let operand_2 = arguments[1];
 
                 compiler_llvm_context::zkevm_general::event(
                     context,
@@ -1030,7 +1095,9 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::ZkEventWrite => {
-                let [operand_1, operand_2] = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let operand_1 = arguments[0];
+                let operand_2 = arguments[1];
 
                 compiler_llvm_context::zkevm_general::event(
                     context,
@@ -1042,7 +1109,10 @@ impl FunctionCall {
             }
 
             Name::ZkMimicCall => {
-                let [address, abi_data, mimic] = self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let mimic = arguments[2];
 
                 compiler_llvm_context::zkevm_call::mimic(
                     context,
@@ -1055,8 +1125,12 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkSystemMimicCall => {
-                let [address, abi_data, mimic, extra_value_1, extra_value_2] =
-                    self.pop_arguments_llvm::<D, 5>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let mimic = arguments[2];
+                let extra_value_1 = arguments[3];
+                let extra_value_2 = arguments[4];
 
                 compiler_llvm_context::zkevm_call::mimic(
                     context,
@@ -1072,7 +1146,9 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkMimicCallByRef => {
-                let [address, mimic] = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let mimic = arguments[1];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::mimic(
@@ -1086,8 +1162,11 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkSystemMimicCallByRef => {
-                let [address, mimic, extra_value_1, extra_value_2] =
-                    self.pop_arguments_llvm::<D, 4>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let mimic = arguments[1];
+                let extra_value_1 = arguments[2];
+                let extra_value_2 = arguments[3];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::mimic(
@@ -1104,8 +1183,11 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkRawCall => {
-                let [address, abi_data, output_offset, output_length] =
-                    self.pop_arguments_llvm::<D, 4>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let output_offset = arguments[2];
+                let output_length = arguments[3];
 
                 compiler_llvm_context::zkevm_call::raw_far(
                     context,
@@ -1118,8 +1200,10 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkRawCallByRef => {
-                let [address, output_offset, output_length] =
-                    self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let output_offset = arguments[1];
+                let output_length = arguments[2];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::raw_far(
@@ -1133,8 +1217,13 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkSystemCall => {
-                let [address, abi_data, extra_value_1, extra_value_2, extra_value_3, extra_value_4] =
-                    self.pop_arguments_llvm::<D, 6>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let extra_value_1 = arguments[2];
+                let extra_value_2 = arguments[3];
+                let extra_value_3 = arguments[4];
+                let extra_value_4 = arguments[5];
 
                 compiler_llvm_context::zkevm_call::system(
                     context,
@@ -1153,8 +1242,12 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkSystemCallByRef => {
-                let [address, extra_value_1, extra_value_2, extra_value_3, extra_value_4] =
-                    self.pop_arguments_llvm::<D, 5>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let extra_value_1 = arguments[1];
+                let extra_value_2 = arguments[2];
+                let extra_value_3 = arguments[3];
+                let extra_value_4 = arguments[4];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::system(
@@ -1174,8 +1267,11 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkStaticRawCall => {
-                let [address, abi_data, output_offset, output_length] =
-                    self.pop_arguments_llvm::<D, 4>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let output_offset = arguments[2];
+                let output_length = arguments[3];
 
                 compiler_llvm_context::zkevm_call::raw_far(
                     context,
@@ -1188,8 +1284,10 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkStaticRawCallByRef => {
-                let [address, output_offset, output_length] =
-                    self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let output_offset = arguments[1];
+                let output_length = arguments[2];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::raw_far(
@@ -1203,8 +1301,13 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkStaticSystemCall => {
-                let [address, abi_data, extra_value_1, extra_value_2, extra_value_3, extra_value_4] =
-                    self.pop_arguments_llvm::<D, 6>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let extra_value_1 = arguments[2];
+                let extra_value_2 = arguments[3];
+                let extra_value_3 = arguments[4];
+                let extra_value_4 = arguments[5];
 
                 compiler_llvm_context::zkevm_call::system(
                     context,
@@ -1223,8 +1326,12 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkStaticSystemCallByRef => {
-                let [address, extra_value_1, extra_value_2, extra_value_3, extra_value_4] =
-                    self.pop_arguments_llvm::<D, 5>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let extra_value_1 = arguments[1];
+                let extra_value_2 = arguments[2];
+                let extra_value_3 = arguments[3];
+                let extra_value_4 = arguments[4];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::system(
@@ -1244,8 +1351,11 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkDelegateRawCall => {
-                let [address, abi_data, output_offset, output_length] =
-                    self.pop_arguments_llvm::<D, 4>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let output_offset = arguments[2];
+                let output_length = arguments[3];
 
                 compiler_llvm_context::zkevm_call::raw_far(
                     context,
@@ -1258,8 +1368,10 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkDelegateRawCallByRef => {
-                let [address, output_offset, output_length] =
-                    self.pop_arguments_llvm::<D, 3>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let output_offset = arguments[1];
+                let output_length = arguments[2];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::raw_far(
@@ -1273,8 +1385,13 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkDelegateSystemCall => {
-                let [address, abi_data, extra_value_1, extra_value_2, extra_value_3, extra_value_4] =
-                    self.pop_arguments_llvm::<D, 6>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let abi_data = arguments[1];
+                let extra_value_1 = arguments[2];
+                let extra_value_2 = arguments[3];
+                let extra_value_3 = arguments[4];
+                let extra_value_4 = arguments[5];
 
                 compiler_llvm_context::zkevm_call::system(
                     context,
@@ -1293,8 +1410,12 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkDelegateSystemCallByRef => {
-                let [address, extra_value_1, extra_value_2, extra_value_3, extra_value_4] =
-                    self.pop_arguments_llvm::<D, 5>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let address = arguments[0];
+                let extra_value_1 = arguments[1];
+                let extra_value_2 = arguments[2];
+                let extra_value_3 = arguments[3];
+                let extra_value_4 = arguments[4];
                 let abi_data = context.get_global(compiler_llvm_context::GLOBAL_ACTIVE_POINTER)?;
 
                 compiler_llvm_context::zkevm_call::system(
@@ -1321,7 +1442,8 @@ impl FunctionCall {
                 compiler_llvm_context::zkevm_abi::return_data_ptr_to_active(context).map(|_| None)
             }
             Name::ZkPtrAddIntoActive => {
-                let [offset] = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let offset = arguments[0];
 
                 compiler_llvm_context::zkevm_abi::active_ptr_add_assign(
                     context,
@@ -1330,7 +1452,8 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::ZkPtrShrinkIntoActive => {
-                let [offset] = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let offset = arguments[0];
 
                 compiler_llvm_context::zkevm_abi::active_ptr_shrink_assign(
                     context,
@@ -1339,7 +1462,8 @@ impl FunctionCall {
                 .map(|_| None)
             }
             Name::ZkPtrPackIntoActive => {
-                let [data] = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let data = arguments[0];
 
                 compiler_llvm_context::zkevm_abi::active_ptr_pack_assign(
                     context,
@@ -1349,7 +1473,9 @@ impl FunctionCall {
             }
 
             Name::ZkMultiplicationHigh => {
-                let [operand_1, operand_2] = self.pop_arguments_llvm::<D, 2>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let operand_1 = arguments[0];
+                let operand_2 = arguments[1];
 
                 compiler_llvm_context::zkevm_math::multiplication_512(
                     context,
@@ -1360,15 +1486,16 @@ impl FunctionCall {
             }
 
             Name::ZkGlobalLoad => {
-                let [mut key] = self.pop_arguments::<D, 1>(context)?;
-                let key = key.original.take().ok_or_else(|| {
+                let mut arguments = self.pop_arguments(context)?;
+                let key = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `$zk_global_load` literal is missing", location)
                 })?;
 
                 context.get_global(key.as_str()).map(Some)
             }
             Name::ZkGlobalExtraAbiData => {
-                let [index] = self.pop_arguments_llvm::<D, 1>(context)?;
+                let arguments = self.pop_arguments_llvm(context)?;
+                let index = arguments[0];
 
                 compiler_llvm_context::zkevm_abi::get_extra_abi_data(
                     context,
@@ -1377,11 +1504,11 @@ impl FunctionCall {
                 .map(Some)
             }
             Name::ZkGlobalStore => {
-                let [mut key, value] = self.pop_arguments::<D, 2>(context)?;
-                let key = key.original.take().ok_or_else(|| {
+                let mut arguments = self.pop_arguments(context)?;
+                let key = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `$zk_global_store` literal is missing", location)
                 })?;
-                let value = value.value.into_int_value();
+                let value = arguments[1].value.into_int_value();
 
                 context.set_global(key.as_str(), context.field_type(), value);
                 Ok(None)
@@ -1390,40 +1517,81 @@ impl FunctionCall {
     }
 
     ///
-    /// Pops the specified number of arguments, converted into their LLVM values.
+    /// Pops the arguments, converted into their LLVM values.
     ///
-    fn pop_arguments_llvm<'ctx, D, const N: usize>(
+    /// The number of arguments is checked against the builtin registry by the parser.
+    ///
+    fn pop_arguments_llvm<'ctx, D>(
         &mut self,
         context: &mut compiler_llvm_context::Context<'ctx, D>,
-    ) -> anyhow::Result<[inkwell::values::BasicValueEnum<'ctx>; N]>
+    ) -> anyhow::Result<Vec<inkwell::values::BasicValueEnum<'ctx>>>
     where
         D: compiler_llvm_context::Dependency,
     {
-        let mut arguments = Vec::with_capacity(N);
-        for expression in self.arguments.drain(0..N).rev() {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists").value);
-        }
-        arguments.reverse();
+        Ok(self
+            .pop_arguments(context)?
+            .into_iter()
+            .map(|argument| argument.value)
+            .collect())
+    }
 
-        Ok(arguments.try_into().expect("Always successful"))
+    ///
+    /// Translates the builtin argument and extends it to the field type.
+    ///
+    /// The narrow values are sign-extended if their type is `int{N}`, and zero-extended otherwise.
+    ///
+    fn widen_argument<'ctx, D>(
+        context: &mut compiler_llvm_context::Context<'ctx, D>,
+        expression: Expression,
+    ) -> anyhow::Result<compiler_llvm_context::Argument<'ctx>>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        let is_signed = expression
+            .yul_type()
+            .map(Type::is_signed)
+            .unwrap_or_default();
+        let mut argument = expression.into_llvm(context)?.expect("Always exists");
+        argument.value = Type::cast_llvm(
+            context,
+            argument.value,
+            context.field_type().as_basic_type_enum(),
+            is_signed,
+        );
+        Ok(argument)
     }
 
     ///
-    /// Pops the specified number of arguments.
+    /// Pops the arguments.
+    ///
+    /// The number of arguments is checked against the builtin registry by the parser.
     ///
-    fn pop_arguments<'ctx, D, const N: usize>(
+    fn pop_arguments<'ctx, D>(
         &mut self,
         context: &mut compiler_llvm_context::Context<'ctx, D>,
-    ) -> anyhow::Result<[compiler_llvm_context::Argument<'ctx>; N]>
+    ) -> anyhow::Result<Vec<compiler_llvm_context::Argument<'ctx>>>
     where
         D: compiler_llvm_context::Dependency,
     {
-        let mut arguments = Vec::with_capacity(N);
-        for expression in self.arguments.drain(0..N).rev() {
-            arguments.push(expression.into_llvm(context)?.expect("Always exists"));
+        let mut arguments = Vec::with_capacity(self.arguments.len());
+        for expression in self.arguments.drain(..).rev() {
+            arguments.push(Self::widen_argument(context, expression)?);
         }
         arguments.reverse();
 
-        Ok(arguments.try_into().expect("Always successful"))
+        Ok(arguments)
+    }
+}
+
+impl std::fmt::Display for FunctionCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(", self.name)?;
+        for (index, argument) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{argument}")?;
+        }
+        write!(f, ")")
     }
 }
@@ -2,10 +2,15 @@
 //! The function name.
 //!
 
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::yul::parser::statement::expression::function_call::builtin::Builtin;
+
 ///
 /// The function name.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 pub enum Name {
     /// The user-defined function.
     UserDefined(String),
@@ -75,11 +80,17 @@ pub enum Name {
     MStore,
     /// `mem[p] := v & 0xff` (only modifies a single byte)
     MStore8,
+    /// copy `s` bytes from mem at position `f` to mem at position `t`, the ranges may overlap
+    MCopy,
 
     /// `storage[p]`
     SLoad,
     /// `storage[p] := v`
     SStore,
+    /// `transientStorage[p]`
+    TLoad,
+    /// `transientStorage[p] := v`
+    TStore,
     /// `loadimmutable` storage read
     LoadImmutable,
     /// `setimmutable` storage write
@@ -219,6 +230,10 @@ pub enum Name {
 
     /// the base fee
     BaseFee,
+    /// versioned hash of the `i`th blob of the current transaction
+    BlobHash,
+    /// the blob base fee
+    BlobBaseFee,
     /// current position in code
     Pc,
     /// like `codecopy(t, f, s)` but take code at address `a`
@@ -323,151 +338,28 @@ impl From<&str> for Name {
             return verbatim;
         }
 
-        match input {
-            "add" => Self::Add,
-            "sub" => Self::Sub,
-            "mul" => Self::Mul,
-            "div" => Self::Div,
-            "mod" => Self::Mod,
-            "sdiv" => Self::Sdiv,
-            "smod" => Self::Smod,
-
-            "lt" => Self::Lt,
-            "gt" => Self::Gt,
-            "eq" => Self::Eq,
-            "iszero" => Self::IsZero,
-            "slt" => Self::Slt,
-            "sgt" => Self::Sgt,
-
-            "or" => Self::Or,
-            "xor" => Self::Xor,
-            "not" => Self::Not,
-            "and" => Self::And,
-            "shl" => Self::Shl,
-            "shr" => Self::Shr,
-            "sar" => Self::Sar,
-            "byte" => Self::Byte,
-            "pop" => Self::Pop,
-
-            "addmod" => Self::AddMod,
-            "mulmod" => Self::MulMod,
-            "exp" => Self::Exp,
-            "signextend" => Self::SignExtend,
-
-            "keccak256" => Self::Keccak256,
-
-            "mload" => Self::MLoad,
-            "mstore" => Self::MStore,
-            "mstore8" => Self::MStore8,
-
-            "sload" => Self::SLoad,
-            "sstore" => Self::SStore,
-            "loadimmutable" => Self::LoadImmutable,
-            "setimmutable" => Self::SetImmutable,
-
-            "calldataload" => Self::CallDataLoad,
-            "calldatasize" => Self::CallDataSize,
-            "calldatacopy" => Self::CallDataCopy,
-            "codesize" => Self::CodeSize,
-            "codecopy" => Self::CodeCopy,
-            "returndatasize" => Self::ReturnDataSize,
-            "returndatacopy" => Self::ReturnDataCopy,
-            "extcodesize" => Self::ExtCodeSize,
-            "extcodehash" => Self::ExtCodeHash,
-
-            "return" => Self::Return,
-            "revert" => Self::Revert,
-
-            "log0" => Self::Log0,
-            "log1" => Self::Log1,
-            "log2" => Self::Log2,
-            "log3" => Self::Log3,
-            "log4" => Self::Log4,
-
-            "call" => Self::Call,
-            "delegatecall" => Self::DelegateCall,
-            "staticcall" => Self::StaticCall,
-
-            "create" => Self::Create,
-            "create2" => Self::Create2,
-            "$zk_create" => Self::ZkCreate,
-            "$zk_create2" => Self::ZkCreate2,
-            "datasize" => Self::DataSize,
-            "dataoffset" => Self::DataOffset,
-            "datacopy" => Self::DataCopy,
-
-            "stop" => Self::Stop,
-            "invalid" => Self::Invalid,
-
-            "linkersymbol" => Self::LinkerSymbol,
-            "memoryguard" => Self::MemoryGuard,
-
-            "address" => Self::Address,
-            "caller" => Self::Caller,
-
-            "callvalue" => Self::CallValue,
-            "gas" => Self::Gas,
-            "balance" => Self::Balance,
-            "selfbalance" => Self::SelfBalance,
-
-            "gaslimit" => Self::GasLimit,
-            "gasprice" => Self::GasPrice,
-            "origin" => Self::Origin,
-            "chainid" => Self::ChainId,
-            "timestamp" => Self::Timestamp,
-            "number" => Self::Number,
-            "blockhash" => Self::BlockHash,
-            "difficulty" => Self::Difficulty,
-            "prevrandao" => Self::Prevrandao,
-            "coinbase" => Self::CoinBase,
-            "basefee" => Self::BaseFee,
-            "msize" => Self::MSize,
-
-            "callcode" => Self::CallCode,
-            "pc" => Self::Pc,
-            "extcodecopy" => Self::ExtCodeCopy,
-            "selfdestruct" => Self::SelfDestruct,
-
-            "$zk_to_l1" => Self::ZkToL1,
-            "$zk_code_source" => Self::ZkCodeSource,
-            "$zk_precompile" => Self::ZkPrecompile,
-            "$zk_meta" => Self::ZkMeta,
-            "$zk_set_context_u128" => Self::ZkSetContextU128,
-            "$zk_set_pubdata_price" => Self::ZkSetPubdataPrice,
-            "$zk_increment_tx_counter" => Self::ZkIncrementTxCounter,
-            "$zk_event_initialize" => Self::ZkEventInitialize,
-            "$zk_event_write" => Self::ZkEventWrite,
-
-            "$zk_mimic_call" => Self::ZkMimicCall,
-            "$zk_system_mimic_call" => Self::ZkSystemMimicCall,
-            "$zk_mimic_call_byref" => Self::ZkMimicCallByRef,
-            "$zk_system_mimic_call_byref" => Self::ZkSystemMimicCallByRef,
-            "$zk_raw_call" => Self::ZkRawCall,
-            "$zk_raw_call_byref" => Self::ZkRawCallByRef,
-            "$zk_system_call" => Self::ZkSystemCall,
-            "$zk_system_call_byref" => Self::ZkSystemCallByRef,
-            "$zk_static_raw_call" => Self::ZkStaticRawCall,
-            "$zk_static_raw_call_byref" => Self::ZkStaticRawCallByRef,
-            "$zk_static_system_call" => Self::ZkStaticSystemCall,
-            "$zk_static_system_call_byref" => Self::ZkStaticSystemCallByRef,
-            "$zk_delegate_raw_call" => Self::ZkDelegateRawCall,
-            "$zk_delegate_raw_call_byref" => Self::ZkDelegateRawCallByRef,
-            "$zk_delegate_system_call" => Self::ZkDelegateSystemCall,
-            "$zk_delegate_system_call_byref" => Self::ZkDelegateSystemCallByRef,
-
-            "$zk_load_calldata_into_active_ptr" => Self::ZkLoadCalldataIntoActivePtr,
-            "$zk_load_returndata_into_active_ptr" => Self::ZkLoadReturndataIntoActivePtr,
-            "$zk_ptr_add_into_active" => Self::ZkPtrAddIntoActive,
-            "$zk_ptr_shrink_into_active" => Self::ZkPtrShrinkIntoActive,
-            "$zk_ptr_pack_into_active" => Self::ZkPtrPackIntoActive,
-
-            "$zk_multiplication_high" => Self::ZkMultiplicationHigh,
-
-            "$zk_global_load" => Self::ZkGlobalLoad,
-            "$zk_global_extra_abi_data" => Self::ZkGlobalExtraAbiData,
-            "$zk_global_store" => Self::ZkGlobalStore,
+        match Builtin::get_by_identifier(input) {
+            Some(builtin) => builtin.name.to_owned(),
+            None => Self::UserDefined(input.to_owned()),
+        }
+    }
+}
 
-            input => Self::UserDefined(input.to_owned()),
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UserDefined(inner) => write!(f, "{inner}"),
+            Self::Verbatim {
+                input_size,
+                output_size,
+            } => write!(f, "verbatim_{input_size}i_{output_size}o"),
+            name => write!(
+                f,
+                "{}",
+                Builtin::get(name)
+                    .expect("All builtins are registered")
+                    .identifier
+            ),
         }
     }
 }
@@ -2,6 +2,10 @@
 //! Translates the verbatim simulations.
 //!
 
+use inkwell::types::BasicType;
+
+use crate::yul::parser::statement::expression::function_call::builtin::simulation::kind::Kind;
+use crate::yul::parser::statement::expression::function_call::builtin::simulation::Simulation;
 use crate::yul::parser::statement::expression::function_call::FunctionCall;
 
 ///
@@ -23,33 +27,46 @@ where
         );
     }
 
-    if output_size > 1 {
+    let identifier = FunctionCall::widen_argument(context, call.arguments.remove(0))?
+        .original
+        .ok_or_else(|| anyhow::anyhow!("{} Verbatim literal is missing", call.location))?;
+    let simulation = Simulation::get(identifier.as_str()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "{} Found unknown internal function `{}`",
+            call.location,
+            identifier
+        )
+    })?;
+
+    if input_size != simulation.input_size {
         anyhow::bail!(
-            "{} Verbatim instructions with multiple return values are not supported",
-            call.location
+            "{} Internal function `{}` expected {} arguments, found {}",
+            call.location,
+            identifier,
+            simulation.input_size,
+            input_size
+        );
+    }
+    if output_size > 1 && simulation.output_size <= 1 {
+        anyhow::bail!(
+            "{} Internal function `{}` does not return multiple values",
+            call.location,
+            identifier
+        );
+    }
+    if simulation.output_size > 1 && output_size != simulation.output_size {
+        anyhow::bail!(
+            "{} Internal function `{}` expected {} return values, found {}",
+            call.location,
+            identifier,
+            simulation.output_size,
+            output_size
         );
     }
 
-    let mut arguments = call.pop_arguments::<D, 1>(context)?;
-    let identifier = arguments[0]
-        .original
-        .take()
-        .ok_or_else(|| anyhow::anyhow!("{} Verbatim literal is missing", call.location))?;
-    match identifier.as_str() {
-        identifier @ "to_l1" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 3;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+    match simulation.kind {
+        Kind::ToL1 => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_general::to_l1(
                 context,
                 arguments[0].into_int_value(),
@@ -58,35 +75,9 @@ where
             )
             .map(Some)
         }
-        identifier @ "code_source" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            compiler_llvm_context::zkevm_general::code_source(context).map(Some)
-        }
-        identifier @ "precompile" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 2;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::CodeSource => compiler_llvm_context::zkevm_general::code_source(context).map(Some),
+        Kind::Precompile => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_general::precompile(
                 context,
                 arguments[0].into_int_value(),
@@ -94,35 +85,9 @@ where
             )
             .map(Some)
         }
-        identifier @ "meta" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            compiler_llvm_context::zkevm_general::meta(context).map(Some)
-        }
-        identifier @ "mimic_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 3;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::Meta => compiler_llvm_context::zkevm_general::meta(context).map(Some),
+        Kind::MimicCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::mimic(
                 context,
                 context.llvm_runtime().mimic_call,
@@ -133,20 +98,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "mimic_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 2;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::MimicCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::mimic(
                 context,
                 context.llvm_runtime().mimic_call_byref,
@@ -157,20 +110,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_mimic_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 7;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemMimicCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::mimic(
                 context,
                 context.llvm_runtime().mimic_call,
@@ -186,20 +127,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_mimic_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 6;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemMimicCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::mimic(
                 context,
                 context.llvm_runtime().mimic_call_byref,
@@ -215,20 +144,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "raw_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 4;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::RawCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::raw_far(
                 context,
                 context.llvm_runtime().far_call,
@@ -239,20 +156,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "raw_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 3;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::RawCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::raw_far(
                 context,
                 context.llvm_runtime().far_call_byref,
@@ -263,20 +168,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 6;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::system(
                 context,
                 context.llvm_runtime().far_call,
@@ -293,20 +186,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 5;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::system(
                 context,
                 context.llvm_runtime().far_call_byref,
@@ -323,20 +204,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "raw_static_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 4;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::RawStaticCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::raw_far(
                 context,
                 context.llvm_runtime().static_call,
@@ -347,20 +216,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "raw_static_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 3;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::RawStaticCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::raw_far(
                 context,
                 context.llvm_runtime().static_call_byref,
@@ -371,20 +228,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_static_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 6;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemStaticCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::system(
                 context,
                 context.llvm_runtime().static_call,
@@ -396,20 +241,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_static_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 5;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemStaticCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::system(
                 context,
                 context.llvm_runtime().static_call_byref,
@@ -421,20 +254,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "raw_delegate_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 4;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::RawDelegateCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::raw_far(
                 context,
                 context.llvm_runtime().delegate_call,
@@ -445,20 +266,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "raw_delegate_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 3;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::RawDelegateCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::raw_far(
                 context,
                 context.llvm_runtime().delegate_call_byref,
@@ -469,20 +278,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_delegate_call" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 6;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemDelegateCall => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::system(
                 context,
                 context.llvm_runtime().delegate_call,
@@ -494,20 +291,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "system_delegate_call_byref" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 5;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SystemDelegateCallByRef => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_call::system(
                 context,
                 context.llvm_runtime().delegate_call_byref,
@@ -519,75 +304,27 @@ where
             )
             .map(Some)
         }
-        identifier @ "set_context_u128" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 1;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SetContextU128 => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_general::set_context_value(
                 context,
                 arguments[0].into_int_value(),
             )
             .map(Some)
         }
-        identifier @ "set_pubdata_price" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 1;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::SetPubdataPrice => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_general::set_pubdata_price(
                 context,
                 arguments[0].into_int_value(),
             )
             .map(Some)
         }
-        identifier @ "increment_tx_counter" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
+        Kind::IncrementTxCounter => {
             compiler_llvm_context::zkevm_general::increment_tx_counter(context).map(Some)
         }
-        identifier @ "event_initialize" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 2;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::EventInitialize => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_general::event(
                 context,
                 arguments[0].into_int_value(),
@@ -596,20 +333,8 @@ where
             )
             .map(Some)
         }
-        identifier @ "event_write" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 2;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::EventWrite => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_general::event(
                 context,
                 arguments[0].into_int_value(),
@@ -618,110 +343,38 @@ where
             )
             .map(Some)
         }
-        identifier @ "calldata_ptr_to_active" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
+        Kind::CalldataPtrToActive => {
             compiler_llvm_context::zkevm_abi::calldata_ptr_to_active(context).map(Some)
         }
-        identifier @ "return_data_ptr_to_active" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
+        Kind::ReturnDataPtrToActive => {
             compiler_llvm_context::zkevm_abi::return_data_ptr_to_active(context).map(Some)
         }
-        identifier @ "active_ptr_add_assign" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 1;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::ActivePtrAddAssign => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_abi::active_ptr_add_assign(
                 context,
                 arguments[0].into_int_value(),
             )
             .map(Some)
         }
-        identifier @ "active_ptr_shrink_assign" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 1;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::ActivePtrShrinkAssign => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_abi::active_ptr_shrink_assign(
                 context,
                 arguments[0].into_int_value(),
             )
             .map(Some)
         }
-        identifier @ "active_ptr_pack_assign" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 1;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::ActivePtrPackAssign => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_abi::active_ptr_pack_assign(
                 context,
                 arguments[0].into_int_value(),
             )
             .map(Some)
         }
-        identifier @ "mul_high" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 2;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            let arguments = call.pop_arguments_llvm::<D, ARGUMENTS_COUNT>(context)?;
+        Kind::MulHigh => {
+            let arguments = call.pop_arguments_llvm(context)?;
             compiler_llvm_context::zkevm_math::multiplication_512(
                 context,
                 arguments[0].into_int_value(),
@@ -729,36 +382,22 @@ where
             )
             .map(Some)
         }
-        identifier @ "throw" => {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
-            compiler_llvm_context::throw(context).map(|_| None)
+        Kind::Mul512 => {
+            let arguments = call.pop_arguments_llvm(context)?;
+            let low = compiler_llvm_context::arithmetic::multiplication(
+                context,
+                arguments[0].into_int_value(),
+                arguments[1].into_int_value(),
+            )?;
+            let high = compiler_llvm_context::zkevm_math::multiplication_512(
+                context,
+                arguments[0].into_int_value(),
+                arguments[1].into_int_value(),
+            )?;
+            Ok(Some(tuple(context, &[low, high])))
         }
-        identifier
-            if identifier.starts_with(compiler_llvm_context::GLOBAL_VERBATIM_GETTER_PREFIX) =>
-        {
-            /// The number of arguments expected by this verbatim variant.
-            const ARGUMENTS_COUNT: usize = 0;
-            if input_size != ARGUMENTS_COUNT {
-                anyhow::bail!(
-                    "{} Internal function `{}` expected {} arguments, found {}",
-                    call.location,
-                    identifier,
-                    ARGUMENTS_COUNT,
-                    input_size
-                );
-            }
-
+        Kind::Throw => compiler_llvm_context::throw(context).map(|_| None),
+        Kind::GlobalGetter => {
             match identifier.strip_prefix(compiler_llvm_context::GLOBAL_VERBATIM_GETTER_PREFIX) {
                 Some(identifier)
                     if identifier == compiler_llvm_context::GLOBAL_CALLDATA_POINTER =>
@@ -813,10 +452,38 @@ where
                 )),
             }
         }
-        identifier => anyhow::bail!(
-            "{} Found unknown internal function `{}`",
-            call.location,
-            identifier
-        ),
     }
 }
+
+///
+/// Packs the verbatim return values into a structure of field-typed values.
+///
+/// The structure has the same layout as the compound return value of a user-defined function, so
+/// it is unpacked by the variable declaration and assignment statements in the same way.
+///
+fn tuple<'ctx, D>(
+    context: &mut compiler_llvm_context::Context<'ctx, D>,
+    values: &[inkwell::values::BasicValueEnum<'ctx>],
+) -> inkwell::values::BasicValueEnum<'ctx>
+where
+    D: compiler_llvm_context::Dependency,
+{
+    let r#type = context
+        .structure_type(vec![context.field_type().as_basic_type_enum(); values.len()].as_slice());
+    let pointer = context.build_alloca(r#type, "verbatim_tuple_pointer");
+    for (index, value) in values.iter().enumerate() {
+        let value_pointer = context.build_gep(
+            pointer,
+            &[
+                context.field_const(0),
+                context
+                    .integer_type(compiler_common::BIT_LENGTH_X32)
+                    .const_int(index as u64, false),
+            ],
+            context.field_type().as_basic_type_enum(),
+            format!("verbatim_tuple_{index}_gep_pointer").as_str(),
+        );
+        context.build_store(value_pointer, *value);
+    }
+    context.build_load(pointer, "verbatim_tuple_value")
+}
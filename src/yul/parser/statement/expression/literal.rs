@@ -6,6 +6,8 @@ use inkwell::values::BasicValue;
 use num::Num;
 use num::One;
 use num::Zero;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
@@ -22,7 +24,7 @@ use crate::yul::parser::r#type::Type;
 ///
 /// Represents a literal in YUL without differentiating its type.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Literal {
     /// The location.
     pub location: Location,
@@ -234,3 +236,19 @@ impl Literal {
         }
     }
 }
+
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.inner {
+            LexicalLiteral::String(ref inner) if inner.is_hexadecimal => {
+                write!(f, "hex\"{inner}\"")?
+            }
+            LexicalLiteral::String(ref inner) => write!(f, "\"{inner}\"")?,
+            ref inner => write!(f, "{inner}")?,
+        }
+        if let Some(r#type) = self.yul_type.as_ref() {
+            write!(f, ":{type}")?;
+        }
+        Ok(())
+    }
+}
@@ -5,6 +5,9 @@
 pub mod function_call;
 pub mod literal;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -13,6 +16,7 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 
 use self::function_call::FunctionCall;
 use self::literal::Literal;
@@ -20,7 +24,7 @@ use self::literal::Literal;
 ///
 /// The Yul expression statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum Expression {
     /// The function call subexpression.
     FunctionCall(FunctionCall),
@@ -87,6 +91,20 @@ impl Expression {
         }
     }
 
+    ///
+    /// Returns the explicit type of the identifier or literal, if any.
+    ///
+    /// The identifiers passed to builtins are annotated with their narrow types by the type
+    /// checker, whereas the function call results always have the field type.
+    ///
+    pub fn yul_type(&self) -> Option<&Type> {
+        match self {
+            Self::FunctionCall(_) => None,
+            Self::Identifier(inner) => inner.r#type.as_ref(),
+            Self::Literal(inner) => inner.yul_type.as_ref(),
+        }
+    }
+
     ///
     /// Converts the expression into an LLVM value.
     ///
@@ -144,3 +162,13 @@ impl Expression {
         }
     }
 }
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FunctionCall(inner) => write!(f, "{inner}"),
+            Self::Identifier(inner) => write!(f, "{inner}"),
+            Self::Literal(inner) => write!(f, "{inner}"),
+        }
+    }
+}
@@ -2,6 +2,9 @@
 //! The for-loop statement.
 //!
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
@@ -12,7 +15,7 @@ use crate::yul::parser::statement::expression::Expression;
 ///
 /// The Yul for-loop statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct ForLoop {
     /// The location.
     pub location: Location,
@@ -101,3 +104,13 @@ where
         Ok(())
     }
 }
+
+impl std::fmt::Display for ForLoop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "for {} {} {} {}",
+            self.initializer, self.condition, self.finalizer, self.body
+        )
+    }
+}
@@ -0,0 +1,90 @@
+//!
+//! The function definition attribute.
+//!
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The function definition attribute.
+///
+/// Specified with a `/// @zksync <attribute>...` comment right before the function definition.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Attribute {
+    /// The function must never be inlined.
+    NoInline,
+    /// The function must always be inlined.
+    AlwaysInline,
+    /// The function is rarely called.
+    Cold,
+    /// The function must be optimized for size regardless of the optimizer settings.
+    MinSize,
+    /// The function uses the near call ABI.
+    NearCall,
+}
+
+impl Attribute {
+    /// The attribute annotation words, mapped to the attributes.
+    pub const ANNOTATIONS: [(&'static str, Self); 5] = [
+        ("noinline", Self::NoInline),
+        ("alwaysinline", Self::AlwaysInline),
+        ("cold", Self::Cold),
+        ("minsize", Self::MinSize),
+        ("near_call", Self::NearCall),
+    ];
+
+    ///
+    /// Returns the attribute by its annotation word.
+    ///
+    pub fn from_annotation(word: &str) -> Option<Self> {
+        Self::ANNOTATIONS
+            .iter()
+            .find(|(annotation, _)| *annotation == word)
+            .map(|(_, attribute)| *attribute)
+    }
+
+    ///
+    /// Returns the annotation word of the attribute.
+    ///
+    pub fn annotation(&self) -> &'static str {
+        Self::ANNOTATIONS
+            .iter()
+            .find(|(_, attribute)| attribute == self)
+            .map(|(annotation, _)| *annotation)
+            .expect("Always exists")
+    }
+
+    ///
+    /// Returns the LLVM function attribute name.
+    ///
+    /// The near call ABI is not an LLVM attribute, but a function type and naming convention.
+    ///
+    pub fn llvm_name(&self) -> Option<&'static str> {
+        match self {
+            Self::NoInline => Some("noinline"),
+            Self::AlwaysInline => Some("alwaysinline"),
+            Self::Cold => Some("cold"),
+            Self::MinSize => Some("minsize"),
+            Self::NearCall => None,
+        }
+    }
+
+    ///
+    /// Returns the attribute which cannot be specified together with this one.
+    ///
+    pub fn conflicting(&self) -> Option<Self> {
+        match self {
+            Self::NoInline => Some(Self::AlwaysInline),
+            Self::AlwaysInline => Some(Self::NoInline),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.annotation())
+    }
+}
@@ -2,7 +2,13 @@
 //! The function definition statement.
 //!
 
+pub mod attribute;
+
+use std::collections::BTreeSet;
+
 use inkwell::types::BasicType;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -12,9 +18,12 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
 
+use self::attribute::Attribute;
+
 ///
 /// The function definition statement.
 ///
@@ -22,7 +31,12 @@ use crate::yul::parser::statement::expression::function_call::name::Name as Func
 /// 1. The hoisted declaration
 /// 2. The definition, which now has the access to all function signatures
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// The function attributes are taken from the `/// @zksync <attribute>...` comments preceding
+/// the definition. A function with the `near_call` attribute is renamed to follow the near call
+/// ABI naming convention and gets the implicit ABI data first argument, so it is handled exactly
+/// like the functions named and declared that way.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct FunctionDefinition {
     /// The location.
     pub location: Location,
@@ -34,16 +48,23 @@ pub struct FunctionDefinition {
     pub result: Vec<Identifier>,
     /// The function body block.
     pub body: Block,
+    /// The function attributes.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub attributes: BTreeSet<Attribute>,
 }
 
 impl FunctionDefinition {
+    /// The implicit ABI data argument of the functions with the `near_call` attribute.
+    pub const NEAR_CALL_ABI_DATA_ARGUMENT: &'static str = "$abi_data";
+
     ///
     /// The element parser.
     ///
     pub fn parse(lexer: &mut Lexer, initial: Option<Token>) -> Result<Self, Error> {
+        let annotations = lexer.annotations().to_owned();
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
-        let (location, identifier) = match token {
+        let (location, mut identifier) = match token {
             Token {
                 lexeme: Lexeme::Identifier(identifier),
                 location,
@@ -70,6 +91,19 @@ impl FunctionDefinition {
             }
         }
 
+        let attributes = Self::parse_attributes(location, identifier.inner.as_str(), annotations)?;
+        let is_near_call_renamed = attributes.contains(&Attribute::NearCall)
+            && !identifier
+                .inner
+                .starts_with(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX);
+        if is_near_call_renamed {
+            identifier.inner = format!(
+                "{}_{}",
+                compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX,
+                identifier.inner
+            );
+        }
+
         match lexer.next()? {
             Token {
                 lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
@@ -86,21 +120,24 @@ impl FunctionDefinition {
         }
 
         let (mut arguments, next) = Identifier::parse_typed_list(lexer, None)?;
+        if is_near_call_renamed {
+            arguments.insert(
+                0,
+                Identifier::new(location, Self::NEAR_CALL_ABI_DATA_ARGUMENT.to_owned()),
+            );
+        }
         if identifier
             .inner
             .contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX)
+            && arguments.is_empty()
         {
-            if arguments.is_empty() {
-                return Err(ParserError::InvalidNumberOfArguments {
-                    location,
-                    identifier: identifier.inner,
-                    expected: 1,
-                    found: arguments.len(),
-                }
-                .into());
+            return Err(ParserError::InvalidNumberOfArguments {
+                location,
+                identifier: identifier.inner,
+                expected: 1,
+                found: arguments.len(),
             }
-
-            arguments.remove(0);
+            .into());
         }
         if identifier
             .inner
@@ -161,8 +198,70 @@ impl FunctionDefinition {
             arguments,
             result,
             body,
+            attributes,
         })
     }
+
+    ///
+    /// Returns the arguments passed to the LLVM function.
+    ///
+    /// The first argument of a near call ABI function is the ABI data, which is consumed by the
+    /// near call itself, so it is kept in the AST, but not passed to the function body.
+    ///
+    pub fn llvm_arguments(&self) -> &[Identifier] {
+        if self
+            .identifier
+            .contains(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX)
+        {
+            &self.arguments[1..]
+        } else {
+            self.arguments.as_slice()
+        }
+    }
+
+    ///
+    /// Parses the attributes from the annotations preceding the definition.
+    ///
+    fn parse_attributes(
+        location: Location,
+        identifier: &str,
+        annotations: Vec<(Location, String)>,
+    ) -> Result<BTreeSet<Attribute>, Error> {
+        let mut attributes = BTreeSet::new();
+        for (annotation_location, annotation) in annotations.into_iter() {
+            let attribute = match Attribute::from_annotation(annotation.as_str()) {
+                Some(attribute) => attribute,
+                None => {
+                    return Err(ParserError::InvalidAttribute {
+                        location: annotation_location,
+                        attribute: annotation,
+                        expected: Attribute::ANNOTATIONS
+                            .iter()
+                            .map(|(annotation, _)| *annotation)
+                            .collect(),
+                    }
+                    .into());
+                }
+            };
+
+            if let Some(conflicting) = attribute
+                .conflicting()
+                .filter(|conflicting| attributes.contains(conflicting))
+            {
+                return Err(ParserError::ConflictingAttributes {
+                    location,
+                    identifier: identifier.to_owned(),
+                    first: conflicting.to_string(),
+                    second: attribute.to_string(),
+                }
+                .into());
+            }
+
+            attributes.insert(attribute);
+        }
+
+        Ok(attributes)
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for FunctionDefinition
@@ -171,7 +270,7 @@ where
 {
     fn declare(&mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         let argument_types: Vec<_> = self
-            .arguments
+            .llvm_arguments()
             .iter()
             .map(|argument| {
                 let yul_type = argument.r#type.to_owned().unwrap_or_default();
@@ -196,20 +295,56 @@ where
             .borrow_mut()
             .set_yul_data(compiler_llvm_context::FunctionYulData::default());
 
+        let value = function.borrow().declaration().value;
+        let llvm = value.get_type().get_context();
+        for attribute in self.attributes.iter() {
+            let name = match attribute.llvm_name() {
+                Some(name) => name,
+                None => continue,
+            };
+            if let Some(conflicting) = attribute
+                .conflicting()
+                .and_then(|conflicting| conflicting.llvm_name())
+            {
+                value.remove_enum_attribute(
+                    inkwell::attributes::AttributeLoc::Function,
+                    inkwell::attributes::Attribute::get_named_enum_kind_id(conflicting),
+                );
+            }
+            value.add_attribute(
+                inkwell::attributes::AttributeLoc::Function,
+                llvm.create_enum_attribute(
+                    inkwell::attributes::Attribute::get_named_enum_kind_id(name),
+                    0,
+                ),
+            );
+        }
+
         Ok(())
     }
 
     fn into_llvm(mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
         context.set_current_function(self.identifier.as_str())?;
         let r#return = context.current_function().borrow().r#return();
+        let arguments = self.llvm_arguments().to_vec();
 
         context.set_basic_block(context.current_function().borrow().entry_block());
+        let mut narrow_results = Vec::new();
         match r#return {
             compiler_llvm_context::FunctionReturn::None => {}
             compiler_llvm_context::FunctionReturn::Primitive { pointer } => {
                 let identifier = self.result.pop().expect("Always exists");
-                let r#type = identifier.r#type.unwrap_or_default();
-                context.build_store(pointer, r#type.into_llvm(context).const_zero());
+                let yul_type = identifier.r#type.unwrap_or_default();
+                let is_signed = yul_type.is_signed();
+                let r#type = yul_type.into_llvm(context);
+                let pointer = if r#type != context.field_type() {
+                    let narrow_pointer = context.build_alloca(r#type, identifier.inner.as_str());
+                    narrow_results.push((narrow_pointer, pointer, is_signed));
+                    narrow_pointer
+                } else {
+                    pointer
+                };
+                context.build_store(pointer, r#type.const_zero());
                 context
                     .current_function()
                     .borrow_mut()
@@ -217,7 +352,9 @@ where
             }
             compiler_llvm_context::FunctionReturn::Compound { pointer, .. } => {
                 for (index, identifier) in self.result.into_iter().enumerate() {
-                    let r#type = identifier.r#type.unwrap_or_default().into_llvm(context);
+                    let yul_type = identifier.r#type.unwrap_or_default();
+                    let is_signed = yul_type.is_signed();
+                    let r#type = yul_type.into_llvm(context);
                     let pointer = context.build_gep(
                         pointer,
                         &[
@@ -229,6 +366,14 @@ where
                         context.field_type(),
                         format!("return_{index}_gep_pointer").as_str(),
                     );
+                    let pointer = if r#type != context.field_type() {
+                        let narrow_pointer =
+                            context.build_alloca(r#type, identifier.inner.as_str());
+                        narrow_results.push((narrow_pointer, pointer, is_signed));
+                        narrow_pointer
+                    } else {
+                        pointer
+                    };
                     context.build_store(pointer, r#type.const_zero());
                     context
                         .current_function()
@@ -238,15 +383,14 @@ where
             }
         };
 
-        let argument_types: Vec<_> = self
-            .arguments
+        let argument_types: Vec<_> = arguments
             .iter()
             .map(|argument| {
                 let yul_type = argument.r#type.to_owned().unwrap_or_default();
                 yul_type.into_llvm(context)
             })
             .collect();
-        for (mut index, argument) in self.arguments.iter().enumerate() {
+        for (mut index, argument) in arguments.iter().enumerate() {
             let pointer = context.build_alloca(argument_types[index], argument.inner.as_str());
             context
                 .current_function()
@@ -281,13 +425,27 @@ where
                 .build_unconditional_branch(context.current_function().borrow().return_block()),
         }
 
+        context.set_basic_block(context.current_function().borrow().return_block());
+        for (index, (narrow_pointer, pointer, is_signed)) in narrow_results.into_iter().enumerate()
+        {
+            let value = context.build_load(
+                narrow_pointer,
+                format!("return_{index}_narrow_value").as_str(),
+            );
+            let value = Type::cast_llvm(
+                context,
+                value,
+                pointer.r#type.as_basic_type_enum(),
+                is_signed,
+            );
+            context.build_store(pointer, value);
+        }
+
         match context.current_function().borrow().r#return() {
             compiler_llvm_context::FunctionReturn::None => {
-                context.set_basic_block(context.current_function().borrow().return_block());
                 context.build_return(None);
             }
             compiler_llvm_context::FunctionReturn::Primitive { pointer } => {
-                context.set_basic_block(context.current_function().borrow().return_block());
                 let return_value = context.build_load(pointer, "return_value");
                 context.build_return(Some(&return_value));
             }
@@ -298,11 +456,9 @@ where
                     .name()
                     .starts_with(compiler_llvm_context::Function::ZKSYNC_NEAR_CALL_ABI_PREFIX) =>
             {
-                context.set_basic_block(context.current_function().borrow().return_block());
                 context.build_return(Some(&pointer.value));
             }
             compiler_llvm_context::FunctionReturn::Compound { pointer, .. } => {
-                context.set_basic_block(context.current_function().borrow().return_block());
                 let return_value = context.build_load(pointer, "return_value");
                 context.build_return(Some(&return_value));
             }
@@ -312,12 +468,45 @@ where
     }
 }
 
+impl std::fmt::Display for FunctionDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let annotations: Vec<&str> = self.attributes.iter().map(Attribute::annotation).collect();
+        if !annotations.is_empty() {
+            writeln!(f, "/// @zksync {}", annotations.join(" "))?;
+        }
+        write!(f, "function {}(", self.identifier)?;
+        for (index, argument) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{argument}")?;
+        }
+        write!(f, ")")?;
+        for (index, result) in self.result.iter().enumerate() {
+            if index == 0 {
+                write!(f, " -> ")?;
+            } else {
+                write!(f, ", ")?;
+            }
+            write!(f, "{result}")?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::yul::lexer::error::Error as LexerError;
     use crate::yul::lexer::token::location::Location;
     use crate::yul::lexer::Lexer;
     use crate::yul::parser::error::Error;
     use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::statement::Statement;
+
+    use super::attribute::Attribute;
+    use super::FunctionDefinition;
 
     #[test]
     fn error_invalid_token_identifier() {
@@ -571,4 +760,180 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn ok_attributes() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, test(gas(), 1))
+                return(0, 32)
+            }
+
+            /// The helper.
+            /// @zksync noinline cold
+            function helper(value) -> result {
+                result := value
+            }
+
+            /// @zksync near_call
+            function test(value) -> result {
+                result := helper(value)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Always valid");
+        let printed = object.to_string();
+        let statements = &object
+            .inner_object
+            .as_ref()
+            .expect("Always exists")
+            .code
+            .block
+            .statements;
+        let helper = match statements.get(1) {
+            Some(Statement::FunctionDefinition(function)) => function,
+            _ => panic!("Expected a function definition"),
+        };
+        assert_eq!(
+            helper.attributes,
+            BTreeSet::from([Attribute::NoInline, Attribute::Cold])
+        );
+        let test = match statements.get(2) {
+            Some(Statement::FunctionDefinition(function)) => function,
+            _ => panic!("Expected a function definition"),
+        };
+        assert_eq!(test.identifier, "ZKSYNC_NEAR_CALL_test");
+        assert_eq!(
+            test.arguments[0].inner,
+            FunctionDefinition::NEAR_CALL_ABI_DATA_ARGUMENT
+        );
+        assert_eq!(test.llvm_arguments().len(), 1);
+        assert_eq!(test.llvm_arguments()[0].inner, "value");
+
+        assert!(printed.contains("ZKSYNC_NEAR_CALL_test(gas(), 1)"));
+        assert!(printed.contains("/// @zksync noinline cold\n"));
+    }
+
+    #[test]
+    fn error_invalid_attribute() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+
+            /// @zksync inline
+            function test() -> result {
+                result := 42
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidAttribute {
+                location: Location::new(14, 13),
+                attribute: "inline".to_owned(),
+                expected: vec!["noinline", "alwaysinline", "cold", "minsize", "near_call"],
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_conflicting_attributes() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+
+            /// @zksync noinline
+            /// @zksync alwaysinline
+            function test() -> result {
+                result := 42
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::ConflictingAttributes {
+                location: Location::new(16, 22),
+                identifier: "test".to_owned(),
+                first: "noinline".to_owned(),
+                second: "alwaysinline".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_misplaced_annotation() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            /// @zksync noinline
+            {
+                return(0, 0)
+            }
+
+            function test() -> result {
+                result := 42
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(LexerError::MisplacedAnnotation {
+                location: Location::new(10, 13),
+            }
+            .into())
+        );
+    }
 }
@@ -13,6 +13,9 @@ pub mod object;
 pub mod switch;
 pub mod variable_declaration;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -35,7 +38,7 @@ use self::variable_declaration::VariableDeclaration;
 ///
 /// The Yul block statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum Statement {
     /// The object element.
     Object(Object),
@@ -161,3 +164,23 @@ impl Statement {
         }
     }
 }
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Object(inner) => write!(f, "{inner}"),
+            Self::Code(inner) => write!(f, "{inner}"),
+            Self::Block(inner) => write!(f, "{inner}"),
+            Self::Expression(inner) => write!(f, "{inner}"),
+            Self::FunctionDefinition(inner) => write!(f, "{inner}"),
+            Self::VariableDeclaration(inner) => write!(f, "{inner}"),
+            Self::Assignment(inner) => write!(f, "{inner}"),
+            Self::IfConditional(inner) => write!(f, "{inner}"),
+            Self::Switch(inner) => write!(f, "{inner}"),
+            Self::ForLoop(inner) => write!(f, "{inner}"),
+            Self::Continue(_) => write!(f, "continue"),
+            Self::Break(_) => write!(f, "break"),
+            Self::Leave(_) => write!(f, "leave"),
+        }
+    }
+}
@@ -2,11 +2,17 @@
 //! The YUL object.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
-use crate::yul::lexer::token::lexeme::literal::Literal;
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::string::String as StringLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -14,11 +20,17 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::type_checker::TypeChecker;
+use crate::yul::parser::visitor::mutable::walk_expression_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
 
 ///
 /// The upper-level YUL object, representing the deploy code.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Object {
     /// The location.
     pub location: Location,
@@ -26,15 +38,43 @@ pub struct Object {
     pub identifier: String,
     /// The code.
     pub code: Code,
-    /// The optional inner object, representing the runtime code.
+    /// The optional inner object named `{identifier}_deployed`, representing the runtime code.
     pub inner_object: Option<Box<Self>>,
-    /// The factory dependency objects, which are represented by nested Yul object. The nested
-    /// objects are duplicates of the upper-level objects describing the dependencies, so only
-    /// their identifiers are preserved. The identifiers are used to address upper-level objects.
+    /// The factory dependency identifiers, collected from the nested Yul objects of this object
+    /// and its runtime code object. The identifiers are used to address the dependency contracts.
     pub factory_dependencies: HashSet<String>,
+    /// The nested child objects, excluding the runtime code object. In the `solc` output they are
+    /// duplicates of the upper-level objects, whereas in hand-written Yul they may only exist here,
+    /// so they are taken out and compiled as separate contracts.
+    pub children: BTreeMap<String, Self>,
+    /// The named data segments, e.g. the `.metadata` section appended by `solc`.
+    pub data: BTreeMap<String, Vec<u8>>,
 }
 
 impl Object {
+    ///
+    /// Reads the object identifier from the beginning of the source code, without parsing the
+    /// object itself.
+    ///
+    /// Used to address the contracts whose Yul code cannot be parsed.
+    ///
+    pub fn parse_identifier(source_code: &str) -> Option<String> {
+        let mut lexer = Lexer::new(source_code.to_owned());
+        match (lexer.next(), lexer.next()) {
+            (
+                Ok(Token {
+                    lexeme: Lexeme::Keyword(Keyword::Object),
+                    ..
+                }),
+                Ok(Token {
+                    lexeme: Lexeme::Literal(LexicalLiteral::String(literal)),
+                    ..
+                }),
+            ) => Some(literal.inner),
+            _ => None,
+        }
+    }
+
     ///
     /// The element parser.
     ///
@@ -59,7 +99,7 @@ impl Object {
 
         let identifier = match lexer.next()? {
             Token {
-                lexeme: Lexeme::Literal(Literal::String(literal)),
+                lexeme: Lexeme::Literal(LexicalLiteral::String(literal)),
                 ..
             } => literal.inner,
             token => {
@@ -91,32 +131,10 @@ impl Object {
         let code = Code::parse(lexer, None)?;
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
+        let mut children = BTreeMap::new();
+        let mut data = BTreeMap::new();
 
-        if !is_runtime_code {
-            inner_object = match lexer.peek()? {
-                Token {
-                    lexeme: Lexeme::Keyword(Keyword::Object),
-                    ..
-                } => {
-                    let mut object = Self::parse(lexer, None)?;
-                    factory_dependencies.extend(object.factory_dependencies.drain());
-                    Some(Box::new(object))
-                }
-                _ => None,
-            };
-
-            if let Token {
-                lexeme: Lexeme::Identifier(identifier),
-                ..
-            } = lexer.peek()?
-            {
-                if identifier.inner.as_str() == "data" {
-                    let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
-                }
-            };
-        }
+        let runtime_code_identifier = format!("{identifier}_deployed");
 
         loop {
             match lexer.next()? {
@@ -128,20 +146,46 @@ impl Object {
                     lexeme: Lexeme::Keyword(Keyword::Object),
                     ..
                 } => {
-                    let dependency = Self::parse(lexer, Some(token))?;
-                    factory_dependencies.insert(dependency.identifier);
+                    let mut child = Self::parse(lexer, Some(token))?;
+                    if Self::is_declared(
+                        child.identifier.as_str(),
+                        inner_object.as_deref(),
+                        &children,
+                        &data,
+                    ) {
+                        return Err(ParserError::DuplicateObjectName {
+                            location: child.location,
+                            identifier: child.identifier,
+                        }
+                        .into());
+                    }
+                    if !is_runtime_code && child.identifier == runtime_code_identifier {
+                        factory_dependencies.extend(child.factory_dependencies.drain());
+                        inner_object = Some(Box::new(child));
+                        continue;
+                    }
+                    factory_dependencies.insert(child.identifier.clone());
+                    children.insert(child.identifier.clone(), child);
                 }
                 Token {
                     lexeme: Lexeme::Identifier(identifier),
+                    location,
                     ..
                 } if identifier.inner.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
+                    let (name, segment) = Self::parse_data(lexer)?;
+                    if Self::is_declared(name.as_str(), inner_object.as_deref(), &children, &data) {
+                        return Err(ParserError::DuplicateObjectName {
+                            location,
+                            identifier: name,
+                        }
+                        .into());
+                    }
+                    data.insert(name, segment);
                 }
                 token => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
-                        expected: vec!["object", "}"],
+                        expected: vec!["object", "data", "}"],
                         found: token.lexeme.to_string(),
                     }
                     .into());
@@ -155,8 +199,209 @@ impl Object {
             code,
             inner_object,
             factory_dependencies,
+            children,
+            data,
         })
     }
+
+    ///
+    /// Takes the nested child objects out of the object and its runtime code object.
+    ///
+    /// The children of the returned objects are left in place.
+    ///
+    pub fn take_children(&mut self) -> Vec<Self> {
+        let mut children: Vec<Self> = std::mem::take(&mut self.children).into_values().collect();
+        if let Some(inner_object) = self.inner_object.as_mut() {
+            children.extend(inner_object.take_children());
+        }
+        children
+    }
+
+    ///
+    /// Whether the `name` is already declared as the runtime code object, a child object, or a
+    /// data segment.
+    ///
+    fn is_declared(
+        name: &str,
+        inner_object: Option<&Self>,
+        children: &BTreeMap<String, Self>,
+        data: &BTreeMap<String, Vec<u8>>,
+    ) -> bool {
+        inner_object
+            .map(|object| object.identifier == name)
+            .unwrap_or_default()
+            || children.contains_key(name)
+            || data.contains_key(name)
+    }
+
+    ///
+    /// Parses a data segment, whose `data` keyword has already been consumed.
+    ///
+    /// Returns the segment name and its contents.
+    ///
+    fn parse_data(lexer: &mut Lexer) -> Result<(String, Vec<u8>), Error> {
+        let name = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(LexicalLiteral::String(literal)),
+                ..
+            } => literal.inner,
+            token => {
+                return Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec!["{string}"],
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
+
+        let segment = match lexer.next()? {
+            Token {
+                lexeme:
+                    Lexeme::Literal(LexicalLiteral::String(StringLiteral {
+                        inner,
+                        is_hexadecimal: true,
+                    })),
+                location,
+                ..
+            } => hex::decode(inner.as_str()).map_err(|_| ParserError::InvalidToken {
+                location,
+                expected: vec!["{hex string}"],
+                found: format!("hex\"{inner}\""),
+            })?,
+            Token {
+                lexeme:
+                    Lexeme::Literal(LexicalLiteral::String(StringLiteral {
+                        inner,
+                        is_hexadecimal: false,
+                    })),
+                ..
+            } => inner.into_bytes(),
+            token => {
+                return Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec!["{string}"],
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
+
+        Ok((name, segment))
+    }
+}
+
+///
+/// Resolves the `dataoffset` and `datasize` calls referencing the data segments.
+///
+/// The `datacopy` calls whose source offset is the `dataoffset` of a data segment get the
+/// segment contents to copy, and `datasize` is replaced with the segment length. Calls
+/// referencing objects are left intact.
+///
+/// The segment contents are only known to `datacopy` if its source offset is the `dataoffset`
+/// call itself, so the other `dataoffset` uses with data segments are reported as errors.
+///
+struct DataSubstitution<'a> {
+    /// The object data segments.
+    data: &'a BTreeMap<String, Vec<u8>>,
+    /// The errors found so far.
+    errors: Vec<String>,
+}
+
+impl<'a> DataSubstitution<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(data: &'a BTreeMap<String, Vec<u8>>) -> Self {
+        Self {
+            data,
+            errors: Vec::new(),
+        }
+    }
+
+    ///
+    /// Substitutes the data segments in the code section, returning all errors at once.
+    ///
+    pub fn substitute(mut self, code: &mut Code) -> anyhow::Result<()> {
+        self.visit_code_mut(code);
+
+        if !self.errors.is_empty() {
+            anyhow::bail!("{}", self.errors.join("\n"));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the data segment contents if the expression is a `dataoffset` or `datasize` call
+    /// referencing a data segment.
+    ///
+    fn segment(&self, expression: &Expression) -> Option<(&'a str, &'a [u8])> {
+        let call = match expression {
+            Expression::FunctionCall(call)
+                if matches!(call.name, FunctionName::DataOffset | FunctionName::DataSize) =>
+            {
+                call
+            }
+            _ => return None,
+        };
+        match call.arguments.first() {
+            Some(Expression::Literal(Literal {
+                inner: LexicalLiteral::String(StringLiteral { inner, .. }),
+                ..
+            })) => self
+                .data
+                .get_key_value(inner.as_str())
+                .map(|(name, segment)| (name.as_str(), segment.as_slice())),
+            _ => None,
+        }
+    }
+}
+
+impl VisitorMut for DataSubstitution<'_> {
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        if let Expression::FunctionCall(call) = expression {
+            if call.name == FunctionName::DataCopy {
+                if let Some((_, segment)) = call
+                    .arguments
+                    .get(1)
+                    .and_then(|source| self.segment(source))
+                {
+                    call.data_segment = Some(segment.to_vec());
+                    for index in [0, 2] {
+                        if let Some(argument) = call.arguments.get_mut(index) {
+                            self.visit_expression_mut(argument);
+                        }
+                    }
+                    return;
+                }
+            }
+        }
+
+        walk_expression_mut(self, expression);
+
+        let (name, segment) = match self.segment(expression) {
+            Some(segment) => segment,
+            None => return,
+        };
+        let location = expression.location();
+        match expression {
+            Expression::FunctionCall(call) if call.name == FunctionName::DataOffset => {
+                self.errors.push(format!(
+                    "{location} `dataoffset` of the data segment `{name}` is only supported as the `datacopy` source offset",
+                ));
+            }
+            _ => {
+                *expression = Expression::Literal(Literal {
+                    location,
+                    inner: LexicalLiteral::Integer(IntegerLiteral::new_decimal(
+                        segment.len().to_string(),
+                    )),
+                    yul_type: None,
+                });
+            }
+        }
+    }
 }
 
 impl<D> compiler_llvm_context::WriteLLVM<D> for Object
@@ -199,7 +444,13 @@ where
         Ok(())
     }
 
-    fn into_llvm(self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+    fn into_llvm(mut self, context: &mut compiler_llvm_context::Context<D>) -> anyhow::Result<()> {
+        TypeChecker::default().check(&mut self.code)?;
+
+        if !self.data.is_empty() {
+            DataSubstitution::new(&self.data).substitute(&mut self.code)?;
+        }
+
         if self.identifier.ends_with("_deployed") {
             compiler_llvm_context::RuntimeCodeFunction::new(self.code).into_llvm(context)?;
         } else {
@@ -221,6 +472,23 @@ where
     }
 }
 
+impl std::fmt::Display for Object {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "object \"{}\" {{", self.identifier)?;
+        crate::yul::parser::write_indented(f, &self.code)?;
+        if let Some(inner_object) = self.inner_object.as_ref() {
+            crate::yul::parser::write_indented(f, inner_object)?;
+        }
+        for child in self.children.values() {
+            crate::yul::parser::write_indented(f, child)?;
+        }
+        for (name, segment) in self.data.iter() {
+            writeln!(f, "    data \"{name}\" hex\"{}\"", hex::encode(segment))?;
+        }
+        write!(f, "}}")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::yul::lexer::token::location::Location;
@@ -349,10 +617,353 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(8, 5),
-                expected: vec!["object", "}"],
+                expected: vec!["object", "data", "}"],
                 found: "class".to_owned(),
             }
             .into())
         );
     }
+
+    #[test]
+    fn ok_data_segments() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            datacopy(0, dataoffset("Table"), datasize("Table"))
+            return(0, datasize("Table"))
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        data ".metadata" hex"a164736f6c63"
+    }
+    data "Table" hex"0102030405"
+    data "Greeting" "hello"
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Test failure");
+
+        assert_eq!(object.data.len(), 2);
+        assert_eq!(object.data.get("Table"), Some(&vec![1, 2, 3, 4, 5]));
+        assert_eq!(object.data.get("Greeting"), Some(&b"hello".to_vec()));
+
+        let inner_object = object.inner_object.expect("Test failure");
+        assert_eq!(
+            inner_object.data.get(".metadata"),
+            Some(&vec![0xa1, 0x64, 0x73, 0x6f, 0x6c, 0x63])
+        );
+    }
+
+    #[test]
+    fn ok_children() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        object "First" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+        }
+    }
+    object "Second" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        object "Second_deployed" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let mut object = Object::parse(&mut lexer, None).expect("Test failure");
+
+        assert!(object.factory_dependencies.contains("First"));
+        assert!(object.factory_dependencies.contains("Second"));
+
+        let mut children: Vec<String> = object
+            .take_children()
+            .into_iter()
+            .map(|child| child.identifier)
+            .collect();
+        children.sort();
+        assert_eq!(children, vec!["First".to_owned(), "Second".to_owned()]);
+        assert!(object.take_children().is_empty());
+    }
+
+    #[test]
+    fn ok_display_round_trip() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            mstore(64, memoryguard(0x80))
+            datacopy(0, dataoffset("Table"), datasize("Table"))
+            let x:uint256, y := f(1, "abc")
+            x := add(x, hex"ff")
+            if iszero(x) { revert(0, 0) }
+            switch y
+            case 0 { leave_like() }
+            case "one" { }
+            default { pop(verbatim_1i_1o(hex"01", y)) }
+            for { let i := 0 } lt(i, 10) { i := add(i, 1) } {
+                if eq(i, 5) { continue }
+                if eq(i, 7) { break }
+            }
+            function f(a, b:bool) -> c, d {
+                c := a
+                d := true
+                leave
+            }
+            function leave_like() { }
+            /// @zksync noinline cold
+            function g(v) -> r { r := v }
+            /// @zksync near_call
+            function h(abi, v) -> r { r := g(v) }
+            function ZKSYNC_NEAR_CALL_k(abi, v) { sstore(0, v) }
+            ZKSYNC_NEAR_CALL_k(gas(), h(gas(), 1))
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        object "Child" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+        }
+        data ".metadata" hex"a164"
+    }
+    data "Table" "hello"
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Test failure");
+        let printed = object.to_string();
+
+        let mut lexer = Lexer::new(printed.clone());
+        let reparsed = Object::parse(&mut lexer, None).expect("Test failure");
+
+        assert_eq!(reparsed.to_string(), printed);
+        assert_eq!(without_locations(&reparsed), without_locations(&object));
+        assert_eq!(reparsed.factory_dependencies, object.factory_dependencies);
+    }
+
+    ///
+    /// Serializes the object without the source locations, so the ASTs parsed from differently
+    /// formatted sources can be compared.
+    ///
+    fn without_locations(object: &Object) -> serde_json::Value {
+        fn strip(value: &mut serde_json::Value) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    map.remove("location");
+                    map.values_mut().for_each(strip);
+                }
+                serde_json::Value::Array(array) => array.iter_mut().for_each(strip),
+                _ => {}
+            }
+        }
+
+        let mut value = serde_json::to_value(object).expect("Always valid");
+        strip(&mut value);
+        value
+    }
+
+    #[test]
+    fn ok_serde_round_trip() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            let x:uint256 := add(1, 0x02)
+            switch x
+            case 3 { sstore(0, "three") }
+            default { revert(0, 0) }
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        data ".metadata" hex"a164"
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Test failure");
+
+        let json = serde_json::to_string(&object).expect("Test failure");
+        let deserialized: Object = serde_json::from_str(json.as_str()).expect("Test failure");
+
+        assert_eq!(deserialized, object);
+    }
+
+    #[test]
+    fn ok_child_before_runtime_object() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Child" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Test failure");
+
+        assert_eq!(
+            object
+                .inner_object
+                .as_ref()
+                .map(|object| object.identifier.as_str()),
+            Some("Test_deployed")
+        );
+        assert_eq!(
+            object.children.keys().collect::<Vec<&String>>(),
+            vec!["Child"]
+        );
+        assert!(object.factory_dependencies.contains("Child"));
+    }
+
+    #[test]
+    fn error_invalid_token_data_segment() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    data "Table" 42
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::InvalidToken {
+                location: Location::new(8, 18),
+                expected: vec!["{string}"],
+                found: "42".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_duplicate_child_object() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Child" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+    object "Child" {
+        code {
+            {
+                revert(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::DuplicateObjectName {
+                location: Location::new(15, 5),
+                identifier: "Child".to_owned(),
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_duplicate_data_segment() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    data "Table" hex"01"
+    data "Table" hex"02"
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let result = Object::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::DuplicateObjectName {
+                location: Location::new(9, 5),
+                identifier: "Table".to_owned(),
+            }
+            .into())
+        );
+    }
 }
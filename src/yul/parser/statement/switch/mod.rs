@@ -4,6 +4,9 @@
 
 pub mod case;
 
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -11,6 +14,7 @@ use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::block::Block;
 use crate::yul::parser::statement::expression::Expression;
 
@@ -19,7 +23,7 @@ use self::case::Case;
 ///
 /// The Yul switch statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Switch {
     /// The location.
     pub location: Location,
@@ -129,6 +133,12 @@ where
         let mut branches = Vec::with_capacity(self.cases.len());
         for (index, case) in self.cases.into_iter().enumerate() {
             let constant = case.literal.into_llvm(context)?.to_llvm();
+            let constant = match scrutinee.as_ref() {
+                Some(scrutinee) => {
+                    Type::cast_llvm(context, constant, scrutinee.value.get_type(), false)
+                }
+                None => constant,
+            };
 
             let expression_block = context
                 .append_basic_block(format!("switch_case_branch_{}_block", index + 1).as_str());
@@ -163,6 +173,19 @@ where
     }
 }
 
+impl std::fmt::Display for Switch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "switch {}", self.expression)?;
+        for case in self.cases.iter() {
+            write!(f, "\n{case}")?;
+        }
+        if let Some(default) = self.default.as_ref() {
+            write!(f, "\ndefault {default}")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::yul::lexer::token::location::Location;
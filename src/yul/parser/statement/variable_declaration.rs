@@ -4,6 +4,8 @@
 
 use inkwell::types::BasicType;
 use inkwell::values::BasicValue;
+use serde::Deserialize;
+use serde::Serialize;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
@@ -13,13 +15,14 @@ use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 use crate::yul::parser::error::Error as ParserError;
 use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::r#type::Type;
 use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
 use crate::yul::parser::statement::expression::Expression;
 
 ///
 /// The Yul variable declaration statement.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct VariableDeclaration {
     /// The location.
     pub location: Location,
@@ -112,7 +115,12 @@ where
                                 .insert_constant(identifier.inner, constant);
                         }
 
-                        value.to_llvm()
+                        Type::cast_llvm(
+                            context,
+                            value.to_llvm(),
+                            r#type.as_basic_type_enum(),
+                            false,
+                        )
                     }
                     None => r#type.const_zero().as_basic_value_enum(),
                 }
@@ -150,27 +158,18 @@ where
             None => return Ok(()),
         };
 
-        let llvm_type = context.structure_type(
-            self.bindings
-                .iter()
-                .map(|binding| {
-                    binding
-                        .r#type
-                        .to_owned()
-                        .unwrap_or_default()
-                        .into_llvm(context)
-                        .as_basic_type_enum()
-                })
-                .collect::<Vec<inkwell::types::BasicTypeEnum<'ctx>>>()
-                .as_slice(),
-        );
-        if expression.value.get_type() != llvm_type.as_basic_type_enum() {
-            anyhow::bail!(
+        let llvm_type = match expression.value {
+            inkwell::values::BasicValueEnum::StructValue(value)
+                if value.get_type().count_fields() as usize == self.bindings.len() =>
+            {
+                value.get_type()
+            }
+            _ => anyhow::bail!(
                 "{} Assignment to {:?} received an invalid number of arguments",
                 location,
                 self.bindings
-            );
-        }
+            ),
+        };
         let pointer = context.build_alloca(llvm_type, "bindings_pointer");
         context.build_store(pointer, expression.to_llvm());
 
@@ -183,7 +182,7 @@ where
                         .integer_type(compiler_common::BIT_LENGTH_X32)
                         .const_int(index as u64, false),
                 ],
-                binding.r#type.unwrap_or_default().into_llvm(context),
+                context.field_type().as_basic_type_enum(),
                 format!("binding_{index}_gep_pointer").as_str(),
             );
 
@@ -199,6 +198,7 @@ where
                         binding.inner
                     )
                 })?;
+            let value = Type::cast_llvm(context, value, pointer.r#type.as_basic_type_enum(), false);
             context.build_store(pointer, value);
         }
 
@@ -206,12 +206,31 @@ where
     }
 }
 
+impl std::fmt::Display for VariableDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "let ")?;
+        for (index, binding) in self.bindings.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{binding}")?;
+        }
+        if let Some(expression) = self.expression.as_ref() {
+            write!(f, " := {expression}")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::yul::lexer::token::location::Location;
     use crate::yul::lexer::Lexer;
     use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::expression::function_call::name::Name;
+    use crate::yul::parser::statement::expression::Expression;
     use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::statement::Statement;
 
     #[test]
     fn error_reserved_identifier() {
@@ -244,4 +263,59 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn ok_verbatim_multiple_return_values() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                let low, high := verbatim_2i_2o("mul_512", 3, 4)
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Always valid");
+        let block = match object
+            .inner_object
+            .expect("Always exists")
+            .code
+            .block
+            .statements
+            .into_iter()
+            .next()
+        {
+            Some(Statement::Block(block)) => block,
+            _ => panic!("Expected a block"),
+        };
+        match block.statements.first() {
+            Some(Statement::VariableDeclaration(declaration)) => {
+                assert_eq!(declaration.bindings.len(), 2);
+                match declaration.expression.as_ref() {
+                    Some(Expression::FunctionCall(call)) => {
+                        assert_eq!(
+                            call.name,
+                            Name::Verbatim {
+                                input_size: 2,
+                                output_size: 2,
+                            }
+                        );
+                        assert_eq!(call.arguments.len(), 3);
+                    }
+                    _ => panic!("Expected a function call"),
+                }
+            }
+            _ => panic!("Expected a variable declaration"),
+        }
+    }
 }
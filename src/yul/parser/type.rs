@@ -2,6 +2,10 @@
 //! The YUL source code type.
 //!
 
+use inkwell::values::BasicValue;
+use serde::Deserialize;
+use serde::Serialize;
+
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -12,9 +16,10 @@ use crate::yul::parser::error::Error as ParserError;
 ///
 /// The YUL source code type.
 ///
-/// The type is not currently in use, so all values have the `uint256` type by default.
+/// The untyped values have the `uint256` type by default. The annotations are checked by the
+/// type checker and translated to LLVM integers of the corresponding width.
 ///
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub enum Type {
     /// The `bool` type.
     Bool,
@@ -82,4 +87,70 @@ impl Type {
             Self::Custom(_) => context.field_type(),
         }
     }
+
+    ///
+    /// Whether the type is a signed integer, whose values must be sign-extended.
+    ///
+    pub fn is_signed(&self) -> bool {
+        matches!(self, Self::Int(_))
+    }
+
+    ///
+    /// Truncates or extends the integer `value` to the integer `type`.
+    ///
+    /// The values crossing the function call boundary are always passed with the field type, so
+    /// they must be converted to and from the narrow types of the bindings they are assigned to.
+    /// The value is sign-extended if `is_signed` is set, that is, if it has an `int{N}` type,
+    /// and zero-extended otherwise.
+    /// The non-integer values and the values of the matching width are returned as is.
+    ///
+    pub fn cast_llvm<'ctx, D>(
+        context: &compiler_llvm_context::Context<'ctx, D>,
+        value: inkwell::values::BasicValueEnum<'ctx>,
+        r#type: inkwell::types::BasicTypeEnum<'ctx>,
+        is_signed: bool,
+    ) -> inkwell::values::BasicValueEnum<'ctx>
+    where
+        D: compiler_llvm_context::Dependency,
+    {
+        let (value, r#type) = match (value, r#type) {
+            (
+                inkwell::values::BasicValueEnum::IntValue(value),
+                inkwell::types::BasicTypeEnum::IntType(r#type),
+            ) => (value, r#type),
+            _ => return value,
+        };
+
+        let value_bitlength = value.get_type().get_bit_width();
+        let type_bitlength = r#type.get_bit_width();
+        if value_bitlength > type_bitlength {
+            context
+                .builder()
+                .build_int_truncate(value, r#type, "yul_type_truncated")
+                .as_basic_value_enum()
+        } else if value_bitlength < type_bitlength && is_signed {
+            context
+                .builder()
+                .build_int_s_extend(value, r#type, "yul_type_sign_extended")
+                .as_basic_value_enum()
+        } else if value_bitlength < type_bitlength {
+            context
+                .builder()
+                .build_int_z_extend(value, r#type, "yul_type_extended")
+                .as_basic_value_enum()
+        } else {
+            value.as_basic_value_enum()
+        }
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool => write!(f, "bool"),
+            Self::Int(bitlength) => write!(f, "int{bitlength}"),
+            Self::UInt(bitlength) => write!(f, "uint{bitlength}"),
+            Self::Custom(inner) => write!(f, "{inner}"),
+        }
+    }
 }
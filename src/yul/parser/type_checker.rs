@@ -0,0 +1,551 @@
+//!
+//! The Yul type checker.
+//!
+
+use std::collections::HashMap;
+
+use num::Num;
+use num::Zero;
+
+use crate::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use crate::yul::parser::r#type::Type;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::builtin::Builtin;
+use crate::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::parser::visitor::mutable::walk_block_mut;
+use crate::yul::parser::visitor::mutable::walk_statement_mut;
+use crate::yul::parser::visitor::mutable::VisitorMut;
+
+///
+/// The Yul type checker.
+///
+/// Checks the explicit type annotations of variables, function signatures, and literals, and
+/// reports all width mismatches at once. The untyped values have the `uint256` type by default.
+///
+/// The untyped literals assigned to typed bindings or passed as typed arguments are annotated
+/// with the expected type, so the code generator can emit the narrow LLVM constants.
+///
+#[derive(Debug, Default)]
+pub struct TypeChecker {
+    /// The variable types, grouped by scope.
+    variables: Vec<HashMap<String, Type>>,
+    /// The function argument and result types, grouped by scope.
+    functions: Vec<HashMap<String, (Vec<Type>, Vec<Type>)>>,
+    /// The errors found so far.
+    errors: Vec<String>,
+}
+
+impl TypeChecker {
+    ///
+    /// Checks the code section, returning all type errors at once.
+    ///
+    pub fn check(mut self, code: &mut Code) -> anyhow::Result<()> {
+        self.visit_code_mut(code);
+
+        if !self.errors.is_empty() {
+            anyhow::bail!("{}", self.errors.join("\n"));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks the expression and returns the types of its values.
+    ///
+    /// Returns `None` if the types cannot be inferred, e.g. if the expression refers to an
+    /// undeclared variable or function, which is reported later by the code generator.
+    ///
+    fn check_expression(
+        &mut self,
+        expression: &mut Expression,
+        expected: Option<&[Type]>,
+    ) -> Option<Vec<Type>> {
+        match expression {
+            Expression::Literal(literal) => {
+                let expected = match expected {
+                    Some([r#type]) => Some(r#type),
+                    _ => None,
+                };
+                Some(vec![self.check_literal(literal, expected)])
+            }
+            Expression::Identifier(identifier) => self
+                .variables
+                .iter()
+                .rev()
+                .find_map(|scope| scope.get(identifier.inner.as_str()))
+                .map(|r#type| vec![r#type.to_owned()]),
+            Expression::FunctionCall(call) => {
+                let is_builtin = !matches!(
+                    call.name,
+                    FunctionName::UserDefined(_) | FunctionName::Verbatim { .. }
+                );
+                let (argument_types, result_types) = match call.name {
+                    FunctionName::UserDefined(ref name) => {
+                        match self
+                            .functions
+                            .iter()
+                            .rev()
+                            .find_map(|scope| scope.get(name.as_str()))
+                        {
+                            Some((arguments, results)) => {
+                                (Some(arguments.to_owned()), Some(results.to_owned()))
+                            }
+                            None => (None, None),
+                        }
+                    }
+                    FunctionName::Verbatim { output_size, .. } => (
+                        Some(vec![Type::default(); call.arguments.len()]),
+                        Some(vec![Type::default(); output_size]),
+                    ),
+                    ref name => (
+                        None,
+                        Builtin::get(name)
+                            .map(|builtin| vec![Type::default(); builtin.output_size]),
+                    ),
+                };
+
+                let mut operand_types = Vec::with_capacity(call.arguments.len());
+                for (index, argument) in call.arguments.iter_mut().enumerate() {
+                    let expected = argument_types
+                        .as_ref()
+                        .and_then(|types| types.get(index))
+                        .cloned();
+                    let argument_location = argument.location();
+                    let found = self
+                        .check_expression(argument, expected.as_ref().map(std::slice::from_ref));
+                    if is_builtin {
+                        let found = match found.as_deref() {
+                            Some([found]) => Some(found.to_owned()),
+                            _ => None,
+                        };
+                        if let (Expression::Identifier(identifier), Some(found)) =
+                            (argument, found.as_ref())
+                        {
+                            if found != &Type::default() {
+                                identifier.r#type = Some(found.to_owned());
+                            }
+                        }
+                        operand_types.push(found);
+                        continue;
+                    }
+                    if let (Some(expected), Some([found])) = (expected, found.as_deref()) {
+                        if &expected != found {
+                            self.errors.push(format!(
+                                "{} Argument {} of `{}` expects type `{}`, found `{}`",
+                                argument_location,
+                                index + 1,
+                                call.name,
+                                expected,
+                                found,
+                            ));
+                        }
+                    }
+                }
+
+                if is_builtin {
+                    return Self::builtin_result_types(result_types, operand_types);
+                }
+                result_types
+            }
+        }
+    }
+
+    ///
+    /// Returns the types of the builtin results.
+    ///
+    /// The narrow operands are extended to `uint256` by the code generator, so the builtins accept
+    /// operands of any type. If all operands have the same narrow type, the single result has
+    /// this type as well, so the narrow arithmetic can be assigned back to narrow bindings, where
+    /// the result computed in `uint256` is truncated.
+    ///
+    fn builtin_result_types(
+        result_types: Option<Vec<Type>>,
+        operand_types: Vec<Option<Type>>,
+    ) -> Option<Vec<Type>> {
+        let mut result_types = result_types?;
+        if result_types.len() != 1 {
+            return Some(result_types);
+        }
+
+        let mut operand_types = operand_types.into_iter();
+        let first = match operand_types.next() {
+            Some(Some(first)) if first != Type::default() => first,
+            _ => return Some(result_types),
+        };
+        if operand_types.all(|r#type| r#type.as_ref() == Some(&first)) {
+            result_types[0] = first;
+        }
+        Some(result_types)
+    }
+
+    ///
+    /// Checks the literal against its explicit or `expected` type, and returns the resulting one.
+    ///
+    fn check_literal(&mut self, literal: &mut Literal, expected: Option<&Type>) -> Type {
+        let r#type = match (literal.yul_type.as_ref(), expected) {
+            (Some(r#type), _) => r#type.to_owned(),
+            (None, Some(r#type)) if r#type != &Type::default() => {
+                literal.yul_type = Some(r#type.to_owned());
+                r#type.to_owned()
+            }
+            (None, _) => return Type::default(),
+        };
+
+        let fits = match (&literal.inner, &r#type) {
+            (LexicalLiteral::Boolean(_), _) => true,
+            (LexicalLiteral::Integer(integer), r#type) => {
+                let value = match integer {
+                    IntegerLiteral::Decimal { inner } => {
+                        num::BigUint::from_str_radix(inner.as_str(), compiler_common::BASE_DECIMAL)
+                    }
+                    IntegerLiteral::Hexadecimal { inner } => num::BigUint::from_str_radix(
+                        &inner["0x".len()..],
+                        compiler_common::BASE_HEXADECIMAL,
+                    ),
+                }
+                .unwrap_or_else(|_| num::BigUint::zero());
+                match r#type {
+                    Type::Bool => value.bits() <= 1,
+                    Type::Int(bitlength) | Type::UInt(bitlength) => {
+                        value.bits() <= *bitlength as u64
+                    }
+                    Type::Custom(_) => true,
+                }
+            }
+            (LexicalLiteral::String(_), r#type) => matches!(
+                r#type,
+                Type::Int(compiler_common::BIT_LENGTH_FIELD)
+                    | Type::UInt(compiler_common::BIT_LENGTH_FIELD)
+                    | Type::Custom(_)
+            ),
+        };
+        if !fits {
+            self.errors.push(format!(
+                "{} Literal `{}` does not fit type `{}`",
+                literal.location, literal.inner, r#type,
+            ));
+        }
+
+        r#type
+    }
+
+    ///
+    /// Checks that the `found` types match the `expected` binding types.
+    ///
+    fn check_bindings(
+        &mut self,
+        location: impl std::fmt::Display,
+        expected: &[Type],
+        found: Option<Vec<Type>>,
+    ) {
+        let found = match found {
+            Some(found) => found,
+            None => return,
+        };
+
+        if expected.len() != found.len() {
+            self.errors.push(format!(
+                "{} Expected {} values, found {}",
+                location,
+                expected.len(),
+                found.len(),
+            ));
+            return;
+        }
+
+        for (expected, found) in expected.iter().zip(found.iter()) {
+            if expected != found {
+                self.errors.push(format!(
+                    "{location} Type mismatch: expected `{expected}`, found `{found}`",
+                ));
+            }
+        }
+    }
+
+    ///
+    /// Declares the variable in the innermost scope.
+    ///
+    fn declare_variable(&mut self, name: String, r#type: Type) {
+        if let Some(scope) = self.variables.last_mut() {
+            scope.insert(name, r#type);
+        }
+    }
+}
+
+impl VisitorMut for TypeChecker {
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        let functions = block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDefinition(function) => Some((
+                    function.identifier.to_owned(),
+                    (
+                        function
+                            .arguments
+                            .iter()
+                            .map(|argument| argument.r#type.to_owned().unwrap_or_default())
+                            .collect(),
+                        function
+                            .result
+                            .iter()
+                            .map(|result| result.r#type.to_owned().unwrap_or_default())
+                            .collect(),
+                    ),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        self.functions.push(functions);
+        self.variables.push(HashMap::new());
+        walk_block_mut(self, block);
+        self.variables.pop();
+        self.functions.pop();
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        match statement {
+            Statement::Expression(expression) => {
+                self.check_expression(expression, None);
+            }
+            statement => walk_statement_mut(self, statement),
+        }
+    }
+
+    fn visit_function_definition_mut(&mut self, function_definition: &mut FunctionDefinition) {
+        let mut scope = HashMap::new();
+        for identifier in function_definition
+            .arguments
+            .iter()
+            .chain(function_definition.result.iter())
+        {
+            scope.insert(
+                identifier.inner.to_owned(),
+                identifier.r#type.to_owned().unwrap_or_default(),
+            );
+        }
+
+        let outer = std::mem::replace(&mut self.variables, vec![scope]);
+        self.visit_block_mut(&mut function_definition.body);
+        self.variables = outer;
+    }
+
+    fn visit_variable_declaration_mut(&mut self, variable_declaration: &mut VariableDeclaration) {
+        let expected: Vec<Type> = variable_declaration
+            .bindings
+            .iter()
+            .map(|binding| binding.r#type.to_owned().unwrap_or_default())
+            .collect();
+
+        if let Some(expression) = variable_declaration.expression.as_mut() {
+            let found = self.check_expression(expression, Some(expected.as_slice()));
+            self.check_bindings(variable_declaration.location, expected.as_slice(), found);
+        }
+
+        for (binding, r#type) in variable_declaration.bindings.iter().zip(expected) {
+            self.declare_variable(binding.inner.to_owned(), r#type);
+        }
+    }
+
+    fn visit_assignment_mut(&mut self, assignment: &mut Assignment) {
+        let expected: Option<Vec<Type>> = assignment
+            .bindings
+            .iter()
+            .map(|binding| {
+                self.variables
+                    .iter()
+                    .rev()
+                    .find_map(|scope| scope.get(binding.inner.as_str()))
+                    .cloned()
+            })
+            .collect();
+
+        let found = self.check_expression(&mut assignment.initializer, expected.as_deref());
+        if let Some(expected) = expected {
+            self.check_bindings(assignment.location, expected.as_slice(), found);
+        }
+    }
+
+    fn visit_if_conditional_mut(&mut self, if_conditional: &mut IfConditional) {
+        self.check_expression(&mut if_conditional.condition, None);
+        self.visit_block_mut(&mut if_conditional.block);
+    }
+
+    fn visit_switch_mut(&mut self, switch: &mut Switch) {
+        let scrutinee = match self.check_expression(&mut switch.expression, None) {
+            Some(types) if types.len() == 1 => types.into_iter().next(),
+            _ => None,
+        };
+
+        for case in switch.cases.iter_mut() {
+            let r#type = self.check_literal(&mut case.literal, scrutinee.as_ref());
+            if let Some(scrutinee) = scrutinee.as_ref() {
+                if scrutinee != &r#type {
+                    self.errors.push(format!(
+                        "{} Type mismatch: expected `{}`, found `{}`",
+                        case.literal.location, scrutinee, r#type,
+                    ));
+                }
+            }
+            self.visit_block_mut(&mut case.block);
+        }
+        if let Some(default) = switch.default.as_mut() {
+            self.visit_block_mut(default);
+        }
+    }
+
+    fn visit_for_loop_mut(&mut self, for_loop: &mut ForLoop) {
+        self.functions.push(HashMap::new());
+        self.variables.push(HashMap::new());
+        for statement in for_loop.initializer.statements.iter_mut() {
+            self.visit_statement_mut(statement);
+        }
+        self.check_expression(&mut for_loop.condition, None);
+        self.visit_block_mut(&mut for_loop.finalizer);
+        self.visit_block_mut(&mut for_loop.body);
+        self.variables.pop();
+        self.functions.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::statement::expression::Expression;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+    use crate::yul::parser::statement::Statement;
+
+    use super::TypeChecker;
+
+    fn parse(input: &str) -> Object {
+        let mut lexer = Lexer::new(input.to_owned());
+        Object::parse(&mut lexer, None).expect("Always valid")
+    }
+
+    #[test]
+    fn ok_typed_literals_annotated() {
+        let mut object = parse(
+            r#"
+object "Test" {
+    code {
+        function f(a:uint32) -> b:uint32 {
+            b := a
+        }
+        let x:uint32 := f(42)
+        let y:bool := true
+        x := 7
+        return(0, 0)
+    }
+}
+    "#,
+        );
+
+        TypeChecker::default()
+            .check(&mut object.code)
+            .expect("Always valid");
+
+        match object.code.block.statements.get(1) {
+            Some(Statement::VariableDeclaration(VariableDeclaration {
+                expression: Some(Expression::FunctionCall(call)),
+                ..
+            })) => match call.arguments.first() {
+                Some(Expression::Literal(literal)) => {
+                    assert_eq!(
+                        literal
+                            .yul_type
+                            .as_ref()
+                            .map(ToString::to_string)
+                            .as_deref(),
+                        Some("uint32")
+                    );
+                }
+                _ => panic!("Expected a literal argument"),
+            },
+            _ => panic!("Expected a variable declaration"),
+        }
+    }
+
+    #[test]
+    fn error_width_mismatch() {
+        let mut object = parse(
+            r#"
+object "Test" {
+    code {
+        function f(a:uint32) -> b:uint64 {
+            b := a
+        }
+        let x:uint8 := 256
+        let y := f(x)
+        mstore(0, x)
+        return(0, 0)
+    }
+}
+    "#,
+        );
+
+        let error = TypeChecker::default()
+            .check(&mut object.code)
+            .expect_err("Always invalid")
+            .to_string();
+        assert_eq!(error.lines().count(), 4, "{error}");
+        assert!(error.contains("expected `uint64`, found `uint32`"));
+        assert!(error.contains("Literal `256` does not fit type `uint8`"));
+        assert!(error.contains("Argument 1 of `f` expects type `uint32`, found `uint8`"));
+        assert!(error.contains("expected `uint256`, found `uint64`"));
+    }
+
+    #[test]
+    fn ok_builtin_narrow_operands() {
+        let mut object = parse(
+            r#"
+object "Test" {
+    code {
+        let x:int32 := 5:int32
+        let y:int32 := 7:int32
+        let z:int32 := add(x, y)
+        let w := add(x, 1)
+        mstore(0, z)
+        return(0, 32)
+    }
+}
+    "#,
+        );
+
+        TypeChecker::default()
+            .check(&mut object.code)
+            .expect("Always valid");
+
+        match object.code.block.statements.get(2) {
+            Some(Statement::VariableDeclaration(VariableDeclaration {
+                expression: Some(Expression::FunctionCall(call)),
+                ..
+            })) => match call.arguments.first() {
+                Some(Expression::Identifier(identifier)) => {
+                    assert_eq!(
+                        identifier
+                            .r#type
+                            .as_ref()
+                            .map(ToString::to_string)
+                            .as_deref(),
+                        Some("int32")
+                    );
+                }
+                _ => panic!("Expected an identifier argument"),
+            },
+            _ => panic!("Expected a variable declaration"),
+        }
+    }
+}
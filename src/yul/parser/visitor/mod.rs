@@ -0,0 +1,392 @@
+//!
+//! The Yul AST visitor.
+//!
+
+pub mod mutable;
+
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+
+///
+/// The Yul AST visitor.
+///
+/// Each method walks the children of its node by default, so an implementor only overrides the
+/// methods for the nodes it is interested in, calling the corresponding `walk_*` function to
+/// continue the traversal.
+///
+pub trait Visitor {
+    ///
+    /// Visits the object, including its runtime code object and children.
+    ///
+    fn visit_object(&mut self, object: &Object) {
+        walk_object(self, object);
+    }
+
+    ///
+    /// Visits the code section.
+    ///
+    fn visit_code(&mut self, code: &Code) {
+        walk_code(self, code);
+    }
+
+    ///
+    /// Visits the block.
+    ///
+    fn visit_block(&mut self, block: &Block) {
+        walk_block(self, block);
+    }
+
+    ///
+    /// Visits the statement.
+    ///
+    fn visit_statement(&mut self, statement: &Statement) {
+        walk_statement(self, statement);
+    }
+
+    ///
+    /// Visits the expression.
+    ///
+    fn visit_expression(&mut self, expression: &Expression) {
+        walk_expression(self, expression);
+    }
+
+    ///
+    /// Visits the function call.
+    ///
+    fn visit_function_call(&mut self, function_call: &FunctionCall) {
+        walk_function_call(self, function_call);
+    }
+
+    ///
+    /// Visits the identifier.
+    ///
+    fn visit_identifier(&mut self, _identifier: &Identifier) {}
+
+    ///
+    /// Visits the literal.
+    ///
+    fn visit_literal(&mut self, _literal: &Literal) {}
+
+    ///
+    /// Visits the function definition.
+    ///
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition) {
+        walk_function_definition(self, function_definition);
+    }
+
+    ///
+    /// Visits the variable declaration.
+    ///
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        walk_variable_declaration(self, variable_declaration);
+    }
+
+    ///
+    /// Visits the assignment.
+    ///
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        walk_assignment(self, assignment);
+    }
+
+    ///
+    /// Visits the `if` statement.
+    ///
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional) {
+        walk_if_conditional(self, if_conditional);
+    }
+
+    ///
+    /// Visits the `switch` statement.
+    ///
+    fn visit_switch(&mut self, switch: &Switch) {
+        walk_switch(self, switch);
+    }
+
+    ///
+    /// Visits the `switch` case.
+    ///
+    fn visit_case(&mut self, case: &Case) {
+        walk_case(self, case);
+    }
+
+    ///
+    /// Visits the `for` loop.
+    ///
+    fn visit_for_loop(&mut self, for_loop: &ForLoop) {
+        walk_for_loop(self, for_loop);
+    }
+}
+
+///
+/// Walks the children of the object, including its runtime code object and children.
+///
+pub fn walk_object<V>(visitor: &mut V, object: &Object)
+where
+    V: Visitor + ?Sized,
+{
+    visitor.visit_code(&object.code);
+    if let Some(inner_object) = object.inner_object.as_ref() {
+        visitor.visit_object(inner_object);
+    }
+    for child in object.children.values() {
+        visitor.visit_object(child);
+    }
+}
+
+///
+/// Walks the children of the code section.
+///
+pub fn walk_code<V>(visitor: &mut V, code: &Code)
+where
+    V: Visitor + ?Sized,
+{
+    visitor.visit_block(&code.block);
+}
+
+///
+/// Walks the children of the block.
+///
+pub fn walk_block<V>(visitor: &mut V, block: &Block)
+where
+    V: Visitor + ?Sized,
+{
+    for statement in block.statements.iter() {
+        visitor.visit_statement(statement);
+    }
+}
+
+///
+/// Walks the children of the statement.
+///
+pub fn walk_statement<V>(visitor: &mut V, statement: &Statement)
+where
+    V: Visitor + ?Sized,
+{
+    match statement {
+        Statement::Object(inner) => visitor.visit_object(inner),
+        Statement::Code(inner) => visitor.visit_code(inner),
+        Statement::Block(inner) => visitor.visit_block(inner),
+        Statement::Expression(inner) => visitor.visit_expression(inner),
+        Statement::FunctionDefinition(inner) => visitor.visit_function_definition(inner),
+        Statement::VariableDeclaration(inner) => visitor.visit_variable_declaration(inner),
+        Statement::Assignment(inner) => visitor.visit_assignment(inner),
+        Statement::IfConditional(inner) => visitor.visit_if_conditional(inner),
+        Statement::Switch(inner) => visitor.visit_switch(inner),
+        Statement::ForLoop(inner) => visitor.visit_for_loop(inner),
+        Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+    }
+}
+
+///
+/// Walks the children of the expression.
+///
+pub fn walk_expression<V>(visitor: &mut V, expression: &Expression)
+where
+    V: Visitor + ?Sized,
+{
+    match expression {
+        Expression::FunctionCall(inner) => visitor.visit_function_call(inner),
+        Expression::Identifier(inner) => visitor.visit_identifier(inner),
+        Expression::Literal(inner) => visitor.visit_literal(inner),
+    }
+}
+
+///
+/// Walks the children of the function call.
+///
+pub fn walk_function_call<V>(visitor: &mut V, function_call: &FunctionCall)
+where
+    V: Visitor + ?Sized,
+{
+    for argument in function_call.arguments.iter() {
+        visitor.visit_expression(argument);
+    }
+}
+
+///
+/// Walks the children of the function definition.
+///
+pub fn walk_function_definition<V>(visitor: &mut V, function_definition: &FunctionDefinition)
+where
+    V: Visitor + ?Sized,
+{
+    for argument in function_definition.arguments.iter() {
+        visitor.visit_identifier(argument);
+    }
+    for result in function_definition.result.iter() {
+        visitor.visit_identifier(result);
+    }
+    visitor.visit_block(&function_definition.body);
+}
+
+///
+/// Walks the children of the variable declaration.
+///
+pub fn walk_variable_declaration<V>(visitor: &mut V, variable_declaration: &VariableDeclaration)
+where
+    V: Visitor + ?Sized,
+{
+    for binding in variable_declaration.bindings.iter() {
+        visitor.visit_identifier(binding);
+    }
+    if let Some(expression) = variable_declaration.expression.as_ref() {
+        visitor.visit_expression(expression);
+    }
+}
+
+///
+/// Walks the children of the assignment.
+///
+pub fn walk_assignment<V>(visitor: &mut V, assignment: &Assignment)
+where
+    V: Visitor + ?Sized,
+{
+    for binding in assignment.bindings.iter() {
+        visitor.visit_identifier(binding);
+    }
+    visitor.visit_expression(&assignment.initializer);
+}
+
+///
+/// Walks the children of the `if` statement.
+///
+pub fn walk_if_conditional<V>(visitor: &mut V, if_conditional: &IfConditional)
+where
+    V: Visitor + ?Sized,
+{
+    visitor.visit_expression(&if_conditional.condition);
+    visitor.visit_block(&if_conditional.block);
+}
+
+///
+/// Walks the children of the `switch` statement.
+///
+pub fn walk_switch<V>(visitor: &mut V, switch: &Switch)
+where
+    V: Visitor + ?Sized,
+{
+    visitor.visit_expression(&switch.expression);
+    for case in switch.cases.iter() {
+        visitor.visit_case(case);
+    }
+    if let Some(default) = switch.default.as_ref() {
+        visitor.visit_block(default);
+    }
+}
+
+///
+/// Walks the children of the `switch` case.
+///
+pub fn walk_case<V>(visitor: &mut V, case: &Case)
+where
+    V: Visitor + ?Sized,
+{
+    visitor.visit_literal(&case.literal);
+    visitor.visit_block(&case.block);
+}
+
+///
+/// Walks the children of the `for` loop.
+///
+pub fn walk_for_loop<V>(visitor: &mut V, for_loop: &ForLoop)
+where
+    V: Visitor + ?Sized,
+{
+    visitor.visit_block(&for_loop.initializer);
+    visitor.visit_expression(&for_loop.condition);
+    visitor.visit_block(&for_loop.finalizer);
+    visitor.visit_block(&for_loop.body);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::yul::lexer::Lexer;
+    use crate::yul::parser::identifier::Identifier;
+    use crate::yul::parser::statement::expression::function_call::FunctionCall;
+    use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::visitor::mutable::VisitorMut;
+    use crate::yul::parser::visitor::walk_function_call;
+    use crate::yul::parser::visitor::Visitor;
+
+    const SOURCE: &str = r#"
+object "Test" {
+    code {
+        {
+            let x := add(1, 2)
+            if x { sstore(0, x) }
+            for { let i := 0 } lt(i, x) { i := add(i, 1) } { }
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+    #[derive(Default)]
+    struct CallCollector {
+        calls: Vec<String>,
+    }
+
+    impl Visitor for CallCollector {
+        fn visit_function_call(&mut self, function_call: &FunctionCall) {
+            self.calls.push(function_call.name.to_string());
+            walk_function_call(self, function_call);
+        }
+    }
+
+    struct Renamer;
+
+    impl VisitorMut for Renamer {
+        fn visit_identifier_mut(&mut self, identifier: &mut Identifier) {
+            identifier.inner = format!("renamed_{}", identifier.inner);
+        }
+    }
+
+    #[test]
+    fn ok_visitor_collects_calls() {
+        let mut lexer = Lexer::new(SOURCE.to_owned());
+        let object = Object::parse(&mut lexer, None).expect("Test failure");
+
+        let mut collector = CallCollector::default();
+        collector.visit_object(&object);
+
+        assert_eq!(
+            collector.calls,
+            vec!["add", "sstore", "lt", "add", "return"]
+                .into_iter()
+                .map(|name| name.to_owned())
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn ok_visitor_mut_renames_identifiers() {
+        let mut lexer = Lexer::new(SOURCE.to_owned());
+        let mut object = Object::parse(&mut lexer, None).expect("Test failure");
+
+        Renamer.visit_object_mut(&mut object);
+
+        let printed = object.to_string();
+        assert!(printed.contains("let renamed_x := add(1, 2)"));
+        assert!(printed.contains("renamed_i := add(renamed_i, 1)"));
+        assert!(!printed.contains(" x "));
+    }
+}
@@ -0,0 +1,314 @@
+//!
+//! The Yul AST mutable visitor.
+//!
+
+use crate::yul::parser::identifier::Identifier;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::case::Case;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+
+///
+/// The Yul AST mutable visitor.
+///
+/// Each method walks the children of its node by default, so an implementor only overrides the
+/// methods for the nodes it transforms, calling the corresponding `walk_*_mut` function to
+/// continue the traversal.
+///
+pub trait VisitorMut {
+    ///
+    /// Visits the object, including its runtime code object and children.
+    ///
+    fn visit_object_mut(&mut self, object: &mut Object) {
+        walk_object_mut(self, object);
+    }
+
+    ///
+    /// Visits the code section.
+    ///
+    fn visit_code_mut(&mut self, code: &mut Code) {
+        walk_code_mut(self, code);
+    }
+
+    ///
+    /// Visits the block.
+    ///
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        walk_block_mut(self, block);
+    }
+
+    ///
+    /// Visits the statement.
+    ///
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        walk_statement_mut(self, statement);
+    }
+
+    ///
+    /// Visits the expression.
+    ///
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+    }
+
+    ///
+    /// Visits the function call.
+    ///
+    fn visit_function_call_mut(&mut self, function_call: &mut FunctionCall) {
+        walk_function_call_mut(self, function_call);
+    }
+
+    ///
+    /// Visits the identifier.
+    ///
+    fn visit_identifier_mut(&mut self, _identifier: &mut Identifier) {}
+
+    ///
+    /// Visits the literal.
+    ///
+    fn visit_literal_mut(&mut self, _literal: &mut Literal) {}
+
+    ///
+    /// Visits the function definition.
+    ///
+    fn visit_function_definition_mut(&mut self, function_definition: &mut FunctionDefinition) {
+        walk_function_definition_mut(self, function_definition);
+    }
+
+    ///
+    /// Visits the variable declaration.
+    ///
+    fn visit_variable_declaration_mut(&mut self, variable_declaration: &mut VariableDeclaration) {
+        walk_variable_declaration_mut(self, variable_declaration);
+    }
+
+    ///
+    /// Visits the assignment.
+    ///
+    fn visit_assignment_mut(&mut self, assignment: &mut Assignment) {
+        walk_assignment_mut(self, assignment);
+    }
+
+    ///
+    /// Visits the `if` statement.
+    ///
+    fn visit_if_conditional_mut(&mut self, if_conditional: &mut IfConditional) {
+        walk_if_conditional_mut(self, if_conditional);
+    }
+
+    ///
+    /// Visits the `switch` statement.
+    ///
+    fn visit_switch_mut(&mut self, switch: &mut Switch) {
+        walk_switch_mut(self, switch);
+    }
+
+    ///
+    /// Visits the `switch` case.
+    ///
+    fn visit_case_mut(&mut self, case: &mut Case) {
+        walk_case_mut(self, case);
+    }
+
+    ///
+    /// Visits the `for` loop.
+    ///
+    fn visit_for_loop_mut(&mut self, for_loop: &mut ForLoop) {
+        walk_for_loop_mut(self, for_loop);
+    }
+}
+
+///
+/// Walks the children of the object, including its runtime code object and children.
+///
+pub fn walk_object_mut<V>(visitor: &mut V, object: &mut Object)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_code_mut(&mut object.code);
+    if let Some(inner_object) = object.inner_object.as_mut() {
+        visitor.visit_object_mut(inner_object);
+    }
+    for child in object.children.values_mut() {
+        visitor.visit_object_mut(child);
+    }
+}
+
+///
+/// Walks the children of the code section.
+///
+pub fn walk_code_mut<V>(visitor: &mut V, code: &mut Code)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_block_mut(&mut code.block);
+}
+
+///
+/// Walks the children of the block.
+///
+pub fn walk_block_mut<V>(visitor: &mut V, block: &mut Block)
+where
+    V: VisitorMut + ?Sized,
+{
+    for statement in block.statements.iter_mut() {
+        visitor.visit_statement_mut(statement);
+    }
+}
+
+///
+/// Walks the children of the statement.
+///
+pub fn walk_statement_mut<V>(visitor: &mut V, statement: &mut Statement)
+where
+    V: VisitorMut + ?Sized,
+{
+    match statement {
+        Statement::Object(inner) => visitor.visit_object_mut(inner),
+        Statement::Code(inner) => visitor.visit_code_mut(inner),
+        Statement::Block(inner) => visitor.visit_block_mut(inner),
+        Statement::Expression(inner) => visitor.visit_expression_mut(inner),
+        Statement::FunctionDefinition(inner) => visitor.visit_function_definition_mut(inner),
+        Statement::VariableDeclaration(inner) => visitor.visit_variable_declaration_mut(inner),
+        Statement::Assignment(inner) => visitor.visit_assignment_mut(inner),
+        Statement::IfConditional(inner) => visitor.visit_if_conditional_mut(inner),
+        Statement::Switch(inner) => visitor.visit_switch_mut(inner),
+        Statement::ForLoop(inner) => visitor.visit_for_loop_mut(inner),
+        Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+    }
+}
+
+///
+/// Walks the children of the expression.
+///
+pub fn walk_expression_mut<V>(visitor: &mut V, expression: &mut Expression)
+where
+    V: VisitorMut + ?Sized,
+{
+    match expression {
+        Expression::FunctionCall(inner) => visitor.visit_function_call_mut(inner),
+        Expression::Identifier(inner) => visitor.visit_identifier_mut(inner),
+        Expression::Literal(inner) => visitor.visit_literal_mut(inner),
+    }
+}
+
+///
+/// Walks the children of the function call.
+///
+pub fn walk_function_call_mut<V>(visitor: &mut V, function_call: &mut FunctionCall)
+where
+    V: VisitorMut + ?Sized,
+{
+    for argument in function_call.arguments.iter_mut() {
+        visitor.visit_expression_mut(argument);
+    }
+}
+
+///
+/// Walks the children of the function definition.
+///
+pub fn walk_function_definition_mut<V>(
+    visitor: &mut V,
+    function_definition: &mut FunctionDefinition,
+) where
+    V: VisitorMut + ?Sized,
+{
+    for argument in function_definition.arguments.iter_mut() {
+        visitor.visit_identifier_mut(argument);
+    }
+    for result in function_definition.result.iter_mut() {
+        visitor.visit_identifier_mut(result);
+    }
+    visitor.visit_block_mut(&mut function_definition.body);
+}
+
+///
+/// Walks the children of the variable declaration.
+///
+pub fn walk_variable_declaration_mut<V>(
+    visitor: &mut V,
+    variable_declaration: &mut VariableDeclaration,
+) where
+    V: VisitorMut + ?Sized,
+{
+    for binding in variable_declaration.bindings.iter_mut() {
+        visitor.visit_identifier_mut(binding);
+    }
+    if let Some(expression) = variable_declaration.expression.as_mut() {
+        visitor.visit_expression_mut(expression);
+    }
+}
+
+///
+/// Walks the children of the assignment.
+///
+pub fn walk_assignment_mut<V>(visitor: &mut V, assignment: &mut Assignment)
+where
+    V: VisitorMut + ?Sized,
+{
+    for binding in assignment.bindings.iter_mut() {
+        visitor.visit_identifier_mut(binding);
+    }
+    visitor.visit_expression_mut(&mut assignment.initializer);
+}
+
+///
+/// Walks the children of the `if` statement.
+///
+pub fn walk_if_conditional_mut<V>(visitor: &mut V, if_conditional: &mut IfConditional)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_expression_mut(&mut if_conditional.condition);
+    visitor.visit_block_mut(&mut if_conditional.block);
+}
+
+///
+/// Walks the children of the `switch` statement.
+///
+pub fn walk_switch_mut<V>(visitor: &mut V, switch: &mut Switch)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_expression_mut(&mut switch.expression);
+    for case in switch.cases.iter_mut() {
+        visitor.visit_case_mut(case);
+    }
+    if let Some(default) = switch.default.as_mut() {
+        visitor.visit_block_mut(default);
+    }
+}
+
+///
+/// Walks the children of the `switch` case.
+///
+pub fn walk_case_mut<V>(visitor: &mut V, case: &mut Case)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_literal_mut(&mut case.literal);
+    visitor.visit_block_mut(&mut case.block);
+}
+
+///
+/// Walks the children of the `for` loop.
+///
+pub fn walk_for_loop_mut<V>(visitor: &mut V, for_loop: &mut ForLoop)
+where
+    V: VisitorMut + ?Sized,
+{
+    visitor.visit_block_mut(&mut for_loop.initializer);
+    visitor.visit_expression_mut(&mut for_loop.condition);
+    visitor.visit_block_mut(&mut for_loop.finalizer);
+    visitor.visit_block_mut(&mut for_loop.body);
+}
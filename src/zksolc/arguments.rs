@@ -21,6 +21,10 @@ pub struct Arguments {
     #[structopt(long = "version")]
     pub version: bool,
 
+    /// Print the Yul builtin functions with their properties as JSON and exit.
+    #[structopt(long = "list-builtins")]
+    pub list_builtins: bool,
+
     /// Specify the input file paths.
     /// Multiple Solidity files can be passed in the default Solidity mode.
     /// Yul and LLVM IR modes currently support only a single file.
@@ -57,7 +61,7 @@ pub struct Arguments {
     pub optimization: Option<char>,
 
     /// Disable the `solc` optimizer.
-    /// Use it if your project uses the `MSIZE` instruction, or in other cases.
+    /// Use it if your project uses the `MSIZE` instruction, which is reported with a warning, or in other cases.
     /// Beware that it will prevent libraries from being inlined.
     #[structopt(long = "disable-solc-optimizer")]
     pub disable_solc_optimizer: bool,
@@ -104,9 +108,31 @@ pub struct Arguments {
     /// Forcibly switch to the EVM legacy assembly pipeline.
     /// It is useful for older revisions of `solc` 0.8, where Yul was considered highly experimental
     /// and contained more bugs than today.
+    /// Without this option, only the contracts the Yul pipeline fails for are recompiled with the
+    /// EVM legacy assembly pipeline.
     #[structopt(long = "force-evmla")]
     pub force_evmla: bool,
 
+    /// Report the dead blocks of the EVM legacy assembly as errors instead of warnings.
+    /// The blocks are dead if they are unreachable, or truncated after an invalid stack state.
+    /// Only for the EVM legacy assembly pipeline.
+    #[structopt(long = "strict-evmla")]
+    pub is_strict_evmla: bool,
+
+    /// Set the limit of visited blocks, including their copies, in the EVM legacy assembly
+    /// analysis of a contract.
+    /// Each distinct stack state reaching a block produces a copy of it, so the limit stops the
+    /// analysis of contracts exploding into too many blocks. Only for the EVM legacy assembly
+    /// pipeline.
+    #[structopt(long = "evmla-block-limit")]
+    pub evmla_block_limit: Option<usize>,
+
+    /// Set the EVM target version of the `solc` output, e.g. `paris` or `shanghai`.
+    /// Only for the Solidity and combined JSON modes. In the standard JSON mode, use the
+    /// `settings.evmVersion` field instead.
+    #[structopt(long = "evm-version")]
+    pub evm_version: Option<String>,
+
     /// Enable the system contract compilation mode.
     /// In this mode zkEVM extensions are enabled. For example, calls to addresses `0xFFFF` and below
     /// are substituted by special zkEVM instructions.
@@ -128,6 +154,25 @@ pub struct Arguments {
     #[structopt(long = "bin")]
     pub output_binary: bool,
 
+    /// Emit additional per-contract outputs.
+    /// Available arguments: `yul-ast`.
+    /// `yul-ast` outputs the JSON representation of the Yul AST compiled by `zksolc`.
+    /// `yul-ast` is only supported by the Yul pipeline, that is, with `solc` v0.8 or newer and
+    /// without `--force-evmla`.
+    #[structopt(long = "emit")]
+    pub emit: Vec<String>,
+
+    /// Run the Yul pre-optimization passes before the LLVM IR generation.
+    /// The passes are constant folding, dead code elimination, unused function removal,
+    /// and inlining of tiny functions.
+    #[structopt(long = "optimize-yul")]
+    pub optimize_yul: bool,
+
+    /// Dump the Yul code after each pre-optimization pass to the debug output directory.
+    /// Only for testing and debugging.
+    #[structopt(long = "dump-yul-passes")]
+    pub dump_yul_passes: bool,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[structopt(long = "debug-output-dir")]
@@ -151,6 +196,9 @@ impl Default for Arguments {
 }
 
 impl Arguments {
+    /// The `--emit` argument for the Yul AST output.
+    pub const EMIT_YUL_AST: &'static str = "yul-ast";
+
     ///
     /// A shortcut constructor.
     ///
@@ -171,6 +219,111 @@ impl Arguments {
             }
         }
 
+        for emit in self.emit.iter() {
+            if emit.as_str() != Self::EMIT_YUL_AST {
+                anyhow::bail!("Invalid `--emit` argument: `{}`", emit);
+            }
+            if self.llvm_ir || self.zkasm || self.standard_json || self.combined_json.is_some() {
+                anyhow::bail!(
+                    "The `--emit {}` option is only supported in the Solidity and Yul modes",
+                    emit
+                );
+            }
+            if self.force_evmla {
+                anyhow::bail!(
+                    "The `--emit {}` option requires the Yul pipeline and is invalid with `--force-evmla`",
+                    emit
+                );
+            }
+        }
+
+        if self.evmla_block_limit.is_some() && (self.yul || self.llvm_ir || self.zkasm) {
+            anyhow::bail!("The `--evmla-block-limit` option is invalid in IR modes");
+        }
+
+        if self.evm_version.is_some() && (self.yul || self.llvm_ir || self.zkasm) {
+            anyhow::bail!("The `--evm-version` option is invalid in IR modes");
+        }
+        if self.evm_version.is_some() && self.standard_json {
+            anyhow::bail!("The `--evm-version` option is invalid in the standard JSON mode. Use the `settings.evmVersion` field instead");
+        }
+
+        if self.optimize_yul && (self.llvm_ir || self.zkasm || self.standard_json) {
+            anyhow::bail!(
+                "The `--optimize-yul` option is only supported in the Solidity and Yul modes"
+            );
+        }
+        if self.dump_yul_passes {
+            if !self.optimize_yul {
+                anyhow::bail!("The `--dump-yul-passes` option requires `--optimize-yul`");
+            }
+            if self.debug_output_directory.is_none() {
+                anyhow::bail!("The `--dump-yul-passes` option requires `--debug-output-dir`");
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use structopt::StructOpt;
+
+    use super::Arguments;
+
+    #[test]
+    fn error_emit_yul_ast_force_evmla() {
+        let arguments =
+            Arguments::from_iter(["zksolc", "--emit", "yul-ast", "--force-evmla", "test.sol"]);
+
+        assert!(arguments
+            .validate()
+            .expect_err("Always invalid")
+            .to_string()
+            .contains("requires the Yul pipeline"));
+    }
+
+    #[test]
+    fn error_evm_version_yul() {
+        let arguments =
+            Arguments::from_iter(["zksolc", "--yul", "--evm-version", "shanghai", "test.yul"]);
+
+        assert!(arguments
+            .validate()
+            .expect_err("Always invalid")
+            .to_string()
+            .contains("The `--evm-version` option is invalid in IR modes"));
+    }
+
+    #[test]
+    fn error_evm_version_standard_json() {
+        let arguments =
+            Arguments::from_iter(["zksolc", "--standard-json", "--evm-version", "shanghai"]);
+
+        assert!(arguments
+            .validate()
+            .expect_err("Always invalid")
+            .to_string()
+            .contains("Use the `settings.evmVersion` field instead"));
+    }
+
+    #[test]
+    fn ok_evm_version() {
+        let arguments = Arguments::from_iter(["zksolc", "--evm-version", "shanghai", "test.sol"]);
+
+        arguments.validate().expect("Always valid");
+    }
+
+    #[test]
+    fn error_evmla_block_limit_yul() {
+        let arguments =
+            Arguments::from_iter(["zksolc", "--yul", "--evmla-block-limit", "1024", "test.yul"]);
+
+        assert!(arguments
+            .validate()
+            .expect_err("Always invalid")
+            .to_string()
+            .contains("The `--evmla-block-limit` option is invalid in IR modes"));
+    }
+}
@@ -45,6 +45,14 @@ fn main_inner() -> anyhow::Result<()> {
         return Ok(());
     }
 
+    if arguments.list_builtins {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(compiler_solidity::YUL_BUILTINS).expect("Always valid")
+        );
+        return Ok(());
+    }
+
     let debug_config = match arguments.debug_output_directory {
         Some(debug_output_directory) => {
             std::fs::create_dir_all(debug_output_directory.as_path())?;
@@ -87,12 +95,36 @@ fn main_inner() -> anyhow::Result<()> {
         None => true,
     };
 
+    let emit_yul_ast = arguments
+        .emit
+        .iter()
+        .any(|emit| emit.as_str() == Arguments::EMIT_YUL_AST);
+
+    let evm_version = match arguments.evm_version {
+        Some(evm_version) => Some(
+            compiler_solidity::SolcStandardJsonInputSettingsEVMVersion::from_str(
+                evm_version.as_str(),
+            )?,
+        ),
+        None => None,
+    };
+
+    let yul_optimizer = if arguments.optimize_yul {
+        Some(compiler_solidity::YulOptimizer::new(
+            arguments.dump_yul_passes,
+        ))
+    } else {
+        None
+    };
+
     let build = if arguments.yul {
         compiler_solidity::yul(
             arguments.input_files.as_slice(),
             optimizer_settings,
             arguments.is_system_mode,
             include_metadata_hash,
+            emit_yul_ast,
+            yul_optimizer,
             debug_config,
         )
     } else if arguments.llvm_ir {
@@ -113,6 +145,8 @@ fn main_inner() -> anyhow::Result<()> {
         compiler_solidity::standard_json(
             &mut solc,
             arguments.force_evmla,
+            arguments.is_strict_evmla,
+            arguments.evmla_block_limit,
             arguments.is_system_mode,
             arguments.base_path,
             arguments.include_paths,
@@ -128,9 +162,13 @@ fn main_inner() -> anyhow::Result<()> {
             &mut solc,
             !arguments.disable_solc_optimizer,
             optimizer_settings,
+            evm_version,
             arguments.force_evmla,
+            arguments.is_strict_evmla,
+            arguments.evmla_block_limit,
             arguments.is_system_mode,
             include_metadata_hash,
+            yul_optimizer,
             arguments.base_path,
             arguments.include_paths,
             arguments.allow_paths,
@@ -146,9 +184,14 @@ fn main_inner() -> anyhow::Result<()> {
             &mut solc,
             !arguments.disable_solc_optimizer,
             optimizer_settings,
+            evm_version,
             arguments.force_evmla,
+            arguments.is_strict_evmla,
+            arguments.evmla_block_limit,
             arguments.is_system_mode,
             include_metadata_hash,
+            emit_yul_ast,
+            yul_optimizer,
             arguments.base_path,
             arguments.include_paths,
             arguments.allow_paths,
@@ -169,7 +212,7 @@ fn main_inner() -> anyhow::Result<()> {
         eprintln!(
             "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
         );
-    } else if arguments.output_assembly || arguments.output_binary {
+    } else if arguments.output_assembly || arguments.output_binary || emit_yul_ast {
         for (path, contract) in build.contracts.into_iter() {
             if arguments.output_assembly {
                 println!(
@@ -184,9 +227,14 @@ fn main_inner() -> anyhow::Result<()> {
                     hex::encode(contract.build.bytecode)
                 );
             }
+            if let Some(yul_ast) = contract.yul_ast {
+                println!("Contract `{path}` Yul AST:\n\n{yul_ast}");
+            }
         }
     } else {
-        eprintln!("Compiler run successful. No output requested. Use --asm and --bin flags.");
+        eprintln!(
+            "Compiler run successful. No output requested. Use --asm, --bin, and --emit flags."
+        );
     }
 
     Ok(())